@@ -75,6 +75,14 @@ fn main() {
         .clang_arg(format!(
             "-DwxdUSE_RICHTEXT={}",
             if cfg!(feature = "richtext") { 1 } else { 0 }
+        ))
+        .clang_arg(format!(
+            "-DwxdUSE_SINGLE_INSTANCE={}",
+            if cfg!(feature = "single-instance") {
+                1
+            } else {
+                0
+            }
         ));
 
     bindings_builder = bindings_builder.clang_arg(format!("--target={target}"));
@@ -386,6 +394,10 @@ fn link_macos_libraries() {
     if cfg!(feature = "aui") {
         println!("cargo:rustc-link-lib=static=wx_osx_cocoau_aui-3.3");
     }
+    if cfg!(feature = "single-instance") {
+        // Sockets backing wxServer/wxClient, used for argument forwarding
+        println!("cargo:rustc-link-lib=static=wx_baseu_net-3.3");
+    }
     if cfg!(feature = "media-ctrl") {
         println!("cargo:rustc-link-lib=static=wx_osx_cocoau_media-3.3");
     }
@@ -520,6 +532,10 @@ fn link_windows_libraries(target_env: &str) {
     if cfg!(feature = "aui") {
         println!("cargo:rustc-link-lib=static=wxmsw33u{debug_suffix}_aui");
     }
+    if cfg!(feature = "single-instance") {
+        // Sockets and DDE backing wxServer/wxClient, used for argument forwarding
+        println!("cargo:rustc-link-lib=static=wxbase33u{debug_suffix}_net");
+    }
     if cfg!(feature = "richtext") {
         println!("cargo:rustc-link-lib=static=wxmsw33u{debug_suffix}_richtext");
         println!("cargo:rustc-link-lib=static=wxmsw33u{debug_suffix}_html");
@@ -735,6 +751,10 @@ fn link_linux_libraries() {
     if cfg!(feature = "aui") {
         println!("cargo:rustc-link-lib=static=wx_gtk3u_aui-3.3");
     }
+    if cfg!(feature = "single-instance") {
+        // Sockets backing wxServer/wxClient, used for argument forwarding
+        println!("cargo:rustc-link-lib=static=wx_baseu_net-3.3");
+    }
     if cfg!(feature = "richtext") {
         println!("cargo:rustc-link-lib=static=wx_gtk3u_richtext-3.3");
         println!("cargo:rustc-link-lib=static=wx_gtk3u_html-3.3");
@@ -951,6 +971,14 @@ fn build_wxdragon_wrapper(
         .arg(format!(
             "-DwxdUSE_RICHTEXT={}",
             if cfg!(feature = "richtext") { 1 } else { 0 }
+        ))
+        .arg(format!(
+            "-DwxdUSE_SINGLE_INSTANCE={}",
+            if cfg!(feature = "single-instance") {
+                1
+            } else {
+                0
+            }
         ));
 
     // Platform-specific CMake configuration
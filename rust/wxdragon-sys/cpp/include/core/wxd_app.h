@@ -3,14 +3,55 @@
 
 #include "../wxd_types.h" // Adjust path as necessary if wxd_types.h is at the root of include/
 
+// --- App Lifecycle Hooks ---
+typedef enum {
+    WXD_APP_HOOK_EXIT = 0,               // wxApp::OnExit
+    WXD_APP_HOOK_ACTIVATE = 1,           // wxEVT_ACTIVATE_APP; arg is 1 when activated, 0 when deactivated
+    WXD_APP_HOOK_UNHANDLED_EXCEPTION = 2, // Exception escaped an event handler; info holds its message. Return non-zero to keep running
+    WXD_APP_HOOK_QUERY_END_SESSION = 3,  // wxEVT_QUERY_END_SESSION; arg is 1 if the request can be vetoed. Return non-zero to veto
+    WXD_APP_HOOK_END_SESSION = 4         // wxEVT_END_SESSION
+} wxd_AppHook;
+
+typedef int (*wxd_AppHookCallback)(void* userData, wxd_AppHook hook, int arg, const char* info);
+
 // --- App Functions ---
 WXD_EXPORTED wxd_App_t* wxd_GetApp();
 WXD_EXPORTED int wxd_Main(int argc, char** argv, wxd_OnInitCallback on_init, void* userData);
+
+// Runs the application like wxd_Main, but reports initialization failure separately from
+// the main loop's exit code. Returns false if wxWidgets or the OnInit callback failed.
+WXD_EXPORTED bool wxd_App_Run(int argc, char** argv, wxd_OnInitCallback on_init, void* userData, int* exitCode);
+
+// Names applied to the application object at startup. Must be called before wxd_App_Run; NULL leaves a name unset.
+WXD_EXPORTED void wxd_App_SetStartupNames(const char* appName, const char* vendorName, const char* displayName, const char* vendorDisplayName);
+
+// Registers the lifecycle hook callback for the next run. Pass NULL to clear it.
+WXD_EXPORTED void wxd_App_SetHookCallback(wxd_AppHookCallback callback, void* userData);
+
+// Gets the application name (as used for config paths)
+WXD_EXPORTED int wxd_App_GetAppName(char* buffer, int bufLen);
+
+// Gets the vendor name
+WXD_EXPORTED int wxd_App_GetVendorName(char* buffer, int bufLen);
 WXD_EXPORTED void wxd_App_SetTopWindow(wxd_App_t* app, wxd_Window_t* window);
 
 // Process callback queue
 WXD_EXPORTED void wxd_App_ProcessCallbacks();
 
+// Wakes up the event loop so idle processing (and thus the callback queue) runs soon.
+// Safe to call from any thread.
+WXD_EXPORTED void wxd_App_WakeUpIdle();
+
+// Shows a modal error dialog with `details` (e.g. a backtrace) in an expandable section.
+// Does nothing if the application object does not exist.
+WXD_EXPORTED void wxd_App_ShowErrorDialog(const char* message, const char* details);
+
+// Whether the main loop exits when the last top-level window is deleted (the default)
+WXD_EXPORTED void wxd_App_SetExitOnFrameDelete(bool flag);
+
+// Destroy all top-level windows and delete them immediately. Returns the number destroyed.
+WXD_EXPORTED int wxd_App_DestroyTopLevelWindows();
+
 // Utility to free strings allocated by wxDragon C API
 WXD_EXPORTED void wxd_free_string(char* str);
 
@@ -0,0 +1,62 @@
+#ifndef WXD_CMDPROC_H
+#define WXD_CMDPROC_H
+
+#include "../wxd_types.h"
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+// Performs or reverts a command; returns false if it failed
+typedef bool (*wxd_CommandCallback)(void* userData);
+
+// Called when the processor deletes a command, e.g. when history is trimmed or cleared
+typedef void (*wxd_CommandDropCallback)(void* userData);
+
+// Create a command processor. maxCommands < 0 keeps an unlimited history.
+WXD_EXPORTED wxd_CommandProcessor_t* wxd_CommandProcessor_Create(int maxCommands);
+
+// Destroy the processor and every command in its history
+WXD_EXPORTED void wxd_CommandProcessor_Destroy(wxd_CommandProcessor_t* self);
+
+// Add a command that has already been performed to the history, discarding anything that
+// could be redone. Redo calls doIt, undo calls undoIt; drop is called when the command is deleted.
+WXD_EXPORTED void wxd_CommandProcessor_Store(wxd_CommandProcessor_t* self, const char* name, bool canUndo,
+                                            wxd_CommandCallback doIt, wxd_CommandCallback undoIt,
+                                            wxd_CommandDropCallback drop, void* userData);
+
+WXD_EXPORTED bool wxd_CommandProcessor_Undo(wxd_CommandProcessor_t* self);
+WXD_EXPORTED bool wxd_CommandProcessor_Redo(wxd_CommandProcessor_t* self);
+WXD_EXPORTED bool wxd_CommandProcessor_CanUndo(wxd_CommandProcessor_t* self);
+WXD_EXPORTED bool wxd_CommandProcessor_CanRedo(wxd_CommandProcessor_t* self);
+
+// User data of the command Undo would revert / Redo would perform, or NULL if there is none
+WXD_EXPORTED void* wxd_CommandProcessor_GetUndoCommandData(wxd_CommandProcessor_t* self);
+WXD_EXPORTED void* wxd_CommandProcessor_GetRedoCommandData(wxd_CommandProcessor_t* self);
+
+// Rename the command Undo would revert, e.g. after another command was merged into it
+WXD_EXPORTED void wxd_CommandProcessor_SetUndoCommandName(wxd_CommandProcessor_t* self, const char* name);
+
+// Delete all commands
+WXD_EXPORTED void wxd_CommandProcessor_ClearCommands(wxd_CommandProcessor_t* self);
+
+WXD_EXPORTED void wxd_CommandProcessor_SetMaxCommands(wxd_CommandProcessor_t* self, int maxCommands);
+WXD_EXPORTED int wxd_CommandProcessor_GetMaxCommands(wxd_CommandProcessor_t* self);
+WXD_EXPORTED int wxd_CommandProcessor_GetCount(wxd_CommandProcessor_t* self);
+
+// Save point tracking
+WXD_EXPORTED void wxd_CommandProcessor_MarkAsSaved(wxd_CommandProcessor_t* self);
+WXD_EXPORTED bool wxd_CommandProcessor_IsDirty(wxd_CommandProcessor_t* self);
+
+// Keep the wxID_UNDO/wxID_REDO items in the window's menu bar labelled ("Undo Rename Layer")
+// and enabled according to the history. The window is forgotten when it is destroyed.
+WXD_EXPORTED void wxd_CommandProcessor_AttachWindow(wxd_CommandProcessor_t* self, wxd_Window_t* window);
+
+// Refresh the labels and state of the attached windows' undo/redo menu items
+WXD_EXPORTED void wxd_CommandProcessor_UpdateMenus(wxd_CommandProcessor_t* self);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif // WXD_CMDPROC_H
@@ -0,0 +1,63 @@
+#ifndef WXD_CONFIG_H
+#define WXD_CONFIG_H
+
+#include "../wxd_types.h"
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+// Create the platform's default config (registry on Windows, a file in the user config dir elsewhere)
+WXD_EXPORTED wxd_Config_t* wxd_Config_Create(const char* appName, const char* vendorName);
+
+// Create a wxFileConfig stored in the given file
+WXD_EXPORTED wxd_Config_t* wxd_Config_CreateFile(const char* path);
+
+WXD_EXPORTED void wxd_Config_Destroy(wxd_Config_t* self);
+
+// The application's global config, created on demand if requested. Owned by wxWidgets.
+WXD_EXPORTED wxd_Config_t* wxd_Config_GetGlobal(bool createOnDemand);
+
+// Make config the global one; wxWidgets takes ownership and deletes the previous one
+WXD_EXPORTED void wxd_Config_SetGlobal(wxd_Config_t* config);
+
+// Current path ("/" is the root); keys may be relative to it or absolute
+WXD_EXPORTED void wxd_Config_SetPath(wxd_Config_t* self, const char* path);
+WXD_EXPORTED char* wxd_Config_GetPath(wxd_Config_t* self); // Caller must free with wxd_free_string
+
+WXD_EXPORTED bool wxd_Config_HasEntry(wxd_Config_t* self, const char* key);
+WXD_EXPORTED bool wxd_Config_HasGroup(wxd_Config_t* self, const char* key);
+
+// Readers return false (NULL for strings and fonts) if the entry is missing or cannot be converted
+WXD_EXPORTED char* wxd_Config_ReadString(wxd_Config_t* self, const char* key); // Caller must free with wxd_free_string
+WXD_EXPORTED bool wxd_Config_ReadLong(wxd_Config_t* self, const char* key, int64_t* value);
+WXD_EXPORTED bool wxd_Config_ReadDouble(wxd_Config_t* self, const char* key, double* value);
+WXD_EXPORTED bool wxd_Config_ReadBool(wxd_Config_t* self, const char* key, bool* value);
+WXD_EXPORTED bool wxd_Config_ReadColour(wxd_Config_t* self, const char* key, wxd_Colour_t* value);
+WXD_EXPORTED wxd_Font_t* wxd_Config_ReadFont(wxd_Config_t* self, const char* key); // Caller owns the font
+
+WXD_EXPORTED bool wxd_Config_WriteString(wxd_Config_t* self, const char* key, const char* value);
+WXD_EXPORTED bool wxd_Config_WriteLong(wxd_Config_t* self, const char* key, int64_t value);
+WXD_EXPORTED bool wxd_Config_WriteDouble(wxd_Config_t* self, const char* key, double value);
+WXD_EXPORTED bool wxd_Config_WriteBool(wxd_Config_t* self, const char* key, bool value);
+WXD_EXPORTED bool wxd_Config_WriteColour(wxd_Config_t* self, const char* key, wxd_Colour_t value);
+WXD_EXPORTED bool wxd_Config_WriteFont(wxd_Config_t* self, const char* key, const wxd_Font_t* value);
+
+// Names of the entries/groups directly in the current path, appended to out
+WXD_EXPORTED int wxd_Config_GetEntries(wxd_Config_t* self, wxd_ArrayString_t* out);
+WXD_EXPORTED int wxd_Config_GetGroups(wxd_Config_t* self, wxd_ArrayString_t* out);
+
+WXD_EXPORTED bool wxd_Config_RenameEntry(wxd_Config_t* self, const char* oldName, const char* newName);
+WXD_EXPORTED bool wxd_Config_RenameGroup(wxd_Config_t* self, const char* oldName, const char* newName);
+WXD_EXPORTED bool wxd_Config_DeleteEntry(wxd_Config_t* self, const char* key, bool deleteGroupIfEmpty);
+WXD_EXPORTED bool wxd_Config_DeleteGroup(wxd_Config_t* self, const char* key);
+WXD_EXPORTED bool wxd_Config_DeleteAll(wxd_Config_t* self);
+
+// Write pending changes to permanent storage
+WXD_EXPORTED bool wxd_Config_Flush(wxd_Config_t* self);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif // WXD_CONFIG_H
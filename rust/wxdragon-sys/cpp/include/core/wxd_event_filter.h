@@ -0,0 +1,30 @@
+#ifndef WXD_EVENT_FILTER_H
+#define WXD_EVENT_FILTER_H
+
+#include "../wxd_types.h"
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+// Result of a filter callback, matching wxEventFilter's Event_Skip/Event_Ignore/Event_Processed
+typedef enum {
+    WXD_EVENT_FILTER_SKIP = -1,
+    WXD_EVENT_FILTER_IGNORE = 0,
+    WXD_EVENT_FILTER_PROCESSED = 1
+} wxd_EventFilterResult;
+
+// Called for every event before it is dispatched to any handler
+typedef int (*wxd_EventFilterCallback)(void* userData, wxd_Event_t* event);
+
+// Register a global event filter. Filters added later are called first.
+WXD_EXPORTED wxd_EventFilter_t* wxd_EventFilter_Add(wxd_EventFilterCallback callback, void* userData);
+
+// Unregister and destroy the filter. The callback is not called afterwards.
+WXD_EXPORTED void wxd_EventFilter_Remove(wxd_EventFilter_t* self);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif // WXD_EVENT_FILTER_H
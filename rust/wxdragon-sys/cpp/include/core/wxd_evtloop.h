@@ -0,0 +1,51 @@
+#ifndef WXD_EVTLOOP_H
+#define WXD_EVTLOOP_H
+
+#include "../wxd_types.h"
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+// Predicate polled by wxd_EventLoop_RunUntil; returning true ends the nested loop
+typedef bool (*wxd_EventLoopPredicate)(void* userData);
+
+// Check whether an event loop is currently running
+WXD_EXPORTED bool wxd_EventLoop_IsRunning();
+
+// Check whether the innermost running event loop is the application's main loop
+WXD_EXPORTED bool wxd_EventLoop_IsMain();
+
+// Ask the innermost running event loop to exit with the given code
+WXD_EXPORTED bool wxd_EventLoop_Exit(int exitCode);
+
+// Ask the main event loop to exit with the given code once nested loops have returned
+WXD_EXPORTED bool wxd_EventLoop_ExitMain(int exitCode);
+
+// Check whether there are native events waiting to be dispatched
+WXD_EXPORTED bool wxd_EventLoop_Pending();
+
+// Dispatch a single native event, blocking for up to timeoutMs (-1 blocks indefinitely).
+// Returns false if no event was dispatched.
+WXD_EXPORTED bool wxd_EventLoop_Dispatch(int timeoutMs);
+
+// Process pending events of the active loop. When safe is true, user input to all
+// windows except enableWindow (may be NULL) is disabled while yielding.
+WXD_EXPORTED bool wxd_EventLoop_Yield(bool onlyIfNeeded, bool safe, wxd_Window_t* enableWindow);
+
+// Check whether a yield is currently in progress
+WXD_EXPORTED bool wxd_EventLoop_IsYielding();
+
+// Process events queued with QueueEvent/CallAfter on all handlers
+WXD_EXPORTED void wxd_EventLoop_ProcessPendingEvents();
+
+// Run a nested event loop until predicate returns true or the loop is exited.
+// The predicate is checked after every batch of events and at least every pollIntervalMs.
+// Returns the loop's exit code (0 when the predicate ended it).
+WXD_EXPORTED int wxd_EventLoop_RunUntil(wxd_EventLoopPredicate predicate, void* userData, int pollIntervalMs);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif // WXD_EVTLOOP_H
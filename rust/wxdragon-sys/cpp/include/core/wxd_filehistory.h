@@ -0,0 +1,36 @@
+#ifndef WXD_FILEHISTORY_H
+#define WXD_FILEHISTORY_H
+
+#include "../wxd_types.h"
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+// Create a file history keeping up to maxFiles entries. Menu items use ids idBase, idBase + 1, ...
+WXD_EXPORTED wxd_FileHistory_t* wxd_FileHistory_Create(int maxFiles, wxd_Id idBase);
+WXD_EXPORTED void wxd_FileHistory_Destroy(wxd_FileHistory_t* self);
+
+// Add a file to the top of the history, moving it there if already present
+WXD_EXPORTED void wxd_FileHistory_AddFile(wxd_FileHistory_t* self, const char* path);
+WXD_EXPORTED void wxd_FileHistory_RemoveFile(wxd_FileHistory_t* self, int index);
+WXD_EXPORTED void wxd_FileHistory_Clear(wxd_FileHistory_t* self);
+
+WXD_EXPORTED int wxd_FileHistory_GetCount(wxd_FileHistory_t* self);
+WXD_EXPORTED int wxd_FileHistory_GetMaxFiles(wxd_FileHistory_t* self);
+WXD_EXPORTED wxd_Id wxd_FileHistory_GetBaseId(wxd_FileHistory_t* self);
+WXD_EXPORTED char* wxd_FileHistory_GetFile(wxd_FileHistory_t* self, int index); // Caller must free with wxd_free_string
+
+// Show the history in menu, appending the current entries. The menu is forgotten when it is destroyed.
+WXD_EXPORTED void wxd_FileHistory_UseMenu(wxd_FileHistory_t* self, wxd_Menu_t* menu);
+WXD_EXPORTED void wxd_FileHistory_RemoveMenu(wxd_FileHistory_t* self, wxd_Menu_t* menu);
+
+// Load/save the entries under the given group of the application's global config
+WXD_EXPORTED bool wxd_FileHistory_Load(wxd_FileHistory_t* self, const char* group);
+WXD_EXPORTED bool wxd_FileHistory_Save(wxd_FileHistory_t* self, const char* group);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif // WXD_FILEHISTORY_H
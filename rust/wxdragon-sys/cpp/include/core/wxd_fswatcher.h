@@ -0,0 +1,58 @@
+#ifndef WXD_FSWATCHER_H
+#define WXD_FSWATCHER_H
+
+#include "../wxd_types.h"
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+// Kinds of changes (values of wxFSWFlags), combined as a filter when adding paths
+typedef enum {
+    WXD_FSW_EVENT_CREATE = 0x01,
+    WXD_FSW_EVENT_DELETE = 0x02,
+    WXD_FSW_EVENT_RENAME = 0x04,
+    WXD_FSW_EVENT_MODIFY = 0x08,
+    WXD_FSW_EVENT_ACCESS = 0x10,
+    WXD_FSW_EVENT_ATTRIB = 0x20,
+    WXD_FSW_EVENT_UNMOUNT = 0x40,
+    WXD_FSW_EVENT_WARNING = 0x80,
+    WXD_FSW_EVENT_ERROR = 0x100,
+    WXD_FSW_EVENT_ALL = 0x1FF
+} wxd_FswEventType;
+
+// Values of wxFSWWarningType
+typedef enum {
+    WXD_FSW_WARNING_NONE = 0,
+    WXD_FSW_WARNING_GENERAL,
+    WXD_FSW_WARNING_OVERFLOW
+} wxd_FswWarningType;
+
+// A file system watcher sending WXD_EVENT_TYPE_FSWATCHER events to itself.
+// Paths added before the event loop runs are watched once it does.
+WXD_EXPORTED wxd_FileSystemWatcher_t* wxd_FileSystemWatcher_Create(void);
+WXD_EXPORTED void wxd_FileSystemWatcher_Destroy(wxd_FileSystemWatcher_t* self);
+WXD_EXPORTED wxd_EvtHandler_t* wxd_FileSystemWatcher_GetEvtHandler(wxd_FileSystemWatcher_t* self);
+
+// Watch a file or the entries of a directory (not its subdirectories), or a whole tree.
+// events is a combination of wxd_FswEventType; filespec (e.g. "*.png") may be null or empty.
+WXD_EXPORTED bool wxd_FileSystemWatcher_Add(wxd_FileSystemWatcher_t* self, const char* path, int events, const char* filespec);
+WXD_EXPORTED bool wxd_FileSystemWatcher_AddTree(wxd_FileSystemWatcher_t* self, const char* path, int events, const char* filespec);
+WXD_EXPORTED bool wxd_FileSystemWatcher_Remove(wxd_FileSystemWatcher_t* self, const char* path);
+WXD_EXPORTED bool wxd_FileSystemWatcher_RemoveTree(wxd_FileSystemWatcher_t* self, const char* path);
+WXD_EXPORTED bool wxd_FileSystemWatcher_RemoveAll(wxd_FileSystemWatcher_t* self);
+WXD_EXPORTED void wxd_FileSystemWatcher_GetWatchedPaths(wxd_FileSystemWatcher_t* self, wxd_ArrayString_t* out);
+
+// Accessors for WXD_EVENT_TYPE_FSWATCHER events
+WXD_EXPORTED int wxd_FileSystemWatcherEvent_GetChangeType(wxd_Event_t* event); // a single wxd_FswEventType
+WXD_EXPORTED char* wxd_FileSystemWatcherEvent_GetPath(wxd_Event_t* event); // Caller must free with wxd_free_string
+// Path after a rename
+WXD_EXPORTED char* wxd_FileSystemWatcherEvent_GetNewPath(wxd_Event_t* event); // Caller must free with wxd_free_string
+WXD_EXPORTED wxd_FswWarningType wxd_FileSystemWatcherEvent_GetWarningType(wxd_Event_t* event);
+WXD_EXPORTED char* wxd_FileSystemWatcherEvent_GetErrorDescription(wxd_Event_t* event); // Caller must free with wxd_free_string
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif // WXD_FSWATCHER_H
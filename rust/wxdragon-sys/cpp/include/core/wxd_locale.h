@@ -0,0 +1,56 @@
+#ifndef WXD_LOCALE_H
+#define WXD_LOCALE_H
+
+#include "../wxd_types.h"
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+// Locale-dependent date and time formats
+typedef enum {
+    WXD_LOCALE_DATE_SHORT = 0,
+    WXD_LOCALE_DATE_LONG,
+    WXD_LOCALE_TIME,
+    WXD_LOCALE_DATE_TIME
+} wxd_LocaleDateFormat;
+
+// --- wxUILocale ---
+// Use the user's preferred locale for formatting and native controls
+WXD_EXPORTED bool wxd_UILocale_UseDefault(void);
+WXD_EXPORTED char* wxd_UILocale_GetName(void); // Caller must free with wxd_free_string
+// Canonical name of the user's preferred language, e.g. "fr_FR"
+WXD_EXPORTED char* wxd_UILocale_GetSystemLanguage(void); // Caller must free with wxd_free_string
+WXD_EXPORTED wxd_LayoutDirection wxd_UILocale_GetLayoutDirection(void);
+WXD_EXPORTED char* wxd_UILocale_GetDecimalSeparator(void); // Caller must free with wxd_free_string
+WXD_EXPORTED char* wxd_UILocale_GetThousandsSeparator(void); // Caller must free with wxd_free_string
+
+// Number and date formatting in the current locale. precision < 0 keeps all significant digits.
+WXD_EXPORTED char* wxd_UILocale_FormatNumber(double value, int precision, bool groupThousands); // Caller must free with wxd_free_string
+WXD_EXPORTED char* wxd_UILocale_FormatInteger(int64_t value, bool groupThousands); // Caller must free with wxd_free_string
+WXD_EXPORTED bool wxd_UILocale_ParseNumber(const char* text, double* value);
+WXD_EXPORTED char* wxd_UILocale_FormatDateTime(const wxd_DateTime_t* dt, wxd_LocaleDateFormat format); // Caller must free with wxd_free_string
+
+// --- wxTranslations ---
+// Language used for messages; an empty string selects the user's language
+WXD_EXPORTED void wxd_Translations_SetLanguage(const char* language);
+// Directory searched for <lang>/<domain>.mo and <lang>/LC_MESSAGES/<domain>.mo
+WXD_EXPORTED void wxd_Translations_AddCatalogLookupPath(const char* prefix);
+// Make an in-memory .mo catalog available for the given domain and language. Returns false if the data is not a catalog.
+WXD_EXPORTED bool wxd_Translations_AddCatalogData(const char* domain, const char* language, const unsigned char* data, size_t len);
+// Load the catalog of the domain for the current language, from files or in-memory data
+WXD_EXPORTED bool wxd_Translations_AddCatalog(const char* domain);
+// Load wxWidgets' own catalog, translating stock labels and dialog buttons
+WXD_EXPORTED bool wxd_Translations_AddStdCatalog(void);
+WXD_EXPORTED bool wxd_Translations_IsLoaded(const char* domain);
+WXD_EXPORTED void wxd_Translations_GetAvailableLanguages(const char* domain, wxd_ArrayString_t* out);
+
+// Translate using the loaded catalogs; an empty or null domain searches all of them
+WXD_EXPORTED char* wxd_Translations_Translate(const char* text, const char* domain); // Caller must free with wxd_free_string
+WXD_EXPORTED char* wxd_Translations_TranslatePlural(const char* singular, const char* plural, unsigned int n, const char* domain); // Caller must free with wxd_free_string
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif // WXD_LOCALE_H
@@ -0,0 +1,57 @@
+#ifndef WXD_LOG_H
+#define WXD_LOG_H
+
+#include "../wxd_types.h"
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+// Log levels (values of wxLogLevel)
+typedef enum {
+    WXD_LOG_FATAL_ERROR = 0,
+    WXD_LOG_ERROR,
+    WXD_LOG_WARNING,
+    WXD_LOG_MESSAGE,
+    WXD_LOG_STATUS,
+    WXD_LOG_INFO,
+    WXD_LOG_DEBUG,
+    WXD_LOG_TRACE,
+    WXD_LOG_PROGRESS
+} wxd_LogLevel;
+
+// Called for every message reaching a callback target. component, file may be null.
+typedef void (*wxd_LogCallback)(void* userData, wxd_LogLevel level, const char* message,
+                                const char* component, const char* file, int line);
+
+// Replace the active log target; the previous targets are deleted
+WXD_EXPORTED void wxd_Log_SetDefaultTarget(void); // wx's default, created on demand
+WXD_EXPORTED void wxd_Log_SetStderrTarget(void);
+WXD_EXPORTED void wxd_Log_SetCallbackTarget(wxd_LogCallback callback, void* userData);
+// Append messages to a wxTextCtrl; messages are dropped once it is destroyed. Returns false if window is not a text control.
+WXD_EXPORTED bool wxd_Log_SetTextCtrlTarget(wxd_Window_t* textCtrl);
+// Show messages in a separate frame (wxLogWindow); parent may be null
+WXD_EXPORTED void wxd_Log_SetWindowTarget(wxd_Window_t* parent, const char* title, bool show);
+// Show or hide the frame of the current log window target, if any
+WXD_EXPORTED bool wxd_Log_ShowWindow(bool show);
+
+// Log a message through the active target
+WXD_EXPORTED void wxd_Log_Message(wxd_LogLevel level, const char* message);
+WXD_EXPORTED void wxd_Log_Flush(void);
+
+// Messages above level are discarded
+WXD_EXPORTED void wxd_Log_SetLogLevel(wxd_LogLevel level);
+// Whether the default targets show WXD_LOG_INFO messages
+WXD_EXPORTED void wxd_Log_SetVerbose(bool verbose);
+// Enable or disable all logging on this thread, returning the previous state
+WXD_EXPORTED bool wxd_Log_EnableLogging(bool enable);
+
+// --- wxLogNull ---
+WXD_EXPORTED wxd_LogNull_t* wxd_LogNull_Create(void);
+WXD_EXPORTED void wxd_LogNull_Destroy(wxd_LogNull_t* self);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif // WXD_LOG_H
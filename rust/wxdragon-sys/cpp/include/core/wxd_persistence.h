@@ -0,0 +1,37 @@
+#ifndef WXD_PERSISTENCE_H
+#define WXD_PERSISTENCE_H
+
+#include "../wxd_types.h"
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+// Which state of a window is saved and restored
+typedef enum {
+    WXD_PERSIST_TOPLEVEL = 0, // Position, size, maximized/iconized state
+    WXD_PERSIST_BOOK,         // Selected page
+    WXD_PERSIST_TREEBOOK,     // Selected page and expanded branches
+    WXD_PERSIST_SPLITTER,     // Sash position
+    WXD_PERSIST_LISTCTRL,     // Column widths and order
+    WXD_PERSIST_DATAVIEW      // Column widths, visibility and sort order
+} wxd_PersistentKind;
+
+// Name the window, restore its saved state and save it again when the window is destroyed.
+// Returns true if saved state was found and applied.
+WXD_EXPORTED bool wxd_Persistence_RegisterAndRestore(wxd_Window_t* window, wxd_PersistentKind kind, const char* name);
+
+// Save the state of a registered object (a window, or an AUI manager) now
+WXD_EXPORTED void wxd_Persistence_Save(void* object);
+// Stop tracking a registered object without saving it
+WXD_EXPORTED void wxd_Persistence_Unregister(void* object);
+
+// Ignore saved state / stop saving state for the rest of the session
+WXD_EXPORTED void wxd_Persistence_DisableRestoring(void);
+WXD_EXPORTED void wxd_Persistence_DisableSaving(void);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif // WXD_PERSISTENCE_H
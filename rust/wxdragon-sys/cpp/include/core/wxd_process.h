@@ -0,0 +1,59 @@
+#ifndef WXD_PROCESS_H
+#define WXD_PROCESS_H
+
+#include "../wxd_types.h"
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+// Signals for wxd_Process_Kill (values of wxSignal)
+typedef enum {
+    WXD_SIGNAL_NONE = 0,
+    WXD_SIGNAL_HUP = 1,
+    WXD_SIGNAL_INT = 2,
+    WXD_SIGNAL_QUIT = 3,
+    WXD_SIGNAL_KILL = 9,
+    WXD_SIGNAL_TERM = 15
+} wxd_Signal;
+
+// Results of wxd_Process_Kill (values of wxKillError)
+typedef enum {
+    WXD_KILL_OK = 0,
+    WXD_KILL_BAD_SIGNAL,
+    WXD_KILL_ACCESS_DENIED,
+    WXD_KILL_NO_PROCESS,
+    WXD_KILL_ERROR
+} wxd_KillError;
+
+// Start argv[0] with the given arguments, with redirected standard streams.
+// cwd may be null to inherit it. env_values[i] == null removes env_keys[i];
+// clear_env starts from an empty environment instead of the current one.
+// Output lines are sent to the process as WXD_EVENT_TYPE_PROCESS_OUTPUT and
+// WXD_EVENT_TYPE_PROCESS_ERROR_OUTPUT command events, followed by WXD_EVENT_TYPE_END_PROCESS.
+// Returns null if the process could not be started.
+WXD_EXPORTED wxd_Process_t* wxd_Process_Start(const char* const* argv, int argc, const char* cwd,
+                                              const char* const* env_keys, const char* const* env_values,
+                                              int env_count, bool clear_env);
+// The caller no longer uses the process; it is deleted once it has terminated
+WXD_EXPORTED void wxd_Process_Release(wxd_Process_t* self);
+WXD_EXPORTED wxd_EvtHandler_t* wxd_Process_GetEvtHandler(wxd_Process_t* self);
+
+WXD_EXPORTED long wxd_Process_GetPid(wxd_Process_t* self);
+WXD_EXPORTED bool wxd_Process_IsRunning(wxd_Process_t* self);
+// Write to the child's standard input; false once it is closed or the child has exited
+WXD_EXPORTED bool wxd_Process_WriteStdin(wxd_Process_t* self, const unsigned char* data, size_t len);
+WXD_EXPORTED void wxd_Process_CloseStdin(wxd_Process_t* self);
+// Send a signal to the child, and to its own children if requested. On Windows only
+// KILL and TERM do more than check that the process exists.
+WXD_EXPORTED wxd_KillError wxd_Process_Kill(wxd_Process_t* self, wxd_Signal signal, bool withChildren);
+
+// Accessors for WXD_EVENT_TYPE_END_PROCESS events
+WXD_EXPORTED int wxd_ProcessEvent_GetExitCode(wxd_Event_t* event);
+WXD_EXPORTED int wxd_ProcessEvent_GetPid(wxd_Event_t* event);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif // WXD_PROCESS_H
@@ -0,0 +1,45 @@
+#ifndef WXD_SINGLE_INSTANCE_H
+#define WXD_SINGLE_INSTANCE_H
+
+#include "../wxd_types.h"
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+// --- wxSingleInstanceChecker ---
+
+// Create a checker for the given name (the current user id is appended). path may be NULL
+// to use the default lock file location. Returns NULL on failure.
+WXD_EXPORTED wxd_SingleInstanceChecker_t* wxd_SingleInstanceChecker_Create(const char* name, const char* path);
+
+// Destroy the checker, releasing the instance lock
+WXD_EXPORTED void wxd_SingleInstanceChecker_Destroy(wxd_SingleInstanceChecker_t* self);
+
+// Check whether another instance holding the same lock is running
+WXD_EXPORTED bool wxd_SingleInstanceChecker_IsAnotherRunning(wxd_SingleInstanceChecker_t* self);
+
+// Get the real user id of the process on Unix (0 elsewhere), for checking who owns
+// the directory holding the IPC socket
+WXD_EXPORTED unsigned int wxd_SingleInstance_GetUserUid(void);
+
+// --- Local IPC (wxServer / wxClient) ---
+
+// Called on the main thread with the raw bytes of each executed message
+typedef void (*wxd_IpcMessageCallback)(void* userData, const char* data, int len);
+
+// Start a server for the given service (a socket path on Unix, a DDE service name on Windows)
+// and topic. Returns NULL if the service could not be created.
+WXD_EXPORTED wxd_IpcServer_t* wxd_IpcServer_Create(const char* service, const char* topic, wxd_IpcMessageCallback callback, void* userData);
+
+// Stop the server. The callback is not called afterwards.
+WXD_EXPORTED void wxd_IpcServer_Destroy(wxd_IpcServer_t* self);
+
+// Connect to a server, execute one message and disconnect. Returns false if no server answered.
+WXD_EXPORTED bool wxd_IpcClient_Execute(const char* service, const char* topic, const char* data, int len);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif // WXD_SINGLE_INSTANCE_H
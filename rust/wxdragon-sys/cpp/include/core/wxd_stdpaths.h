@@ -0,0 +1,44 @@
+#ifndef WXD_STDPATHS_H
+#define WXD_STDPATHS_H
+
+#include "../wxd_types.h"
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+// Locations known to wxStandardPaths
+typedef enum {
+    WXD_STDPATH_USER_CONFIG_DIR = 0,
+    WXD_STDPATH_USER_DATA_DIR,
+    WXD_STDPATH_USER_LOCAL_DATA_DIR,
+    WXD_STDPATH_CONFIG_DIR,
+    WXD_STDPATH_DATA_DIR,
+    WXD_STDPATH_LOCAL_DATA_DIR,
+    WXD_STDPATH_RESOURCES_DIR,
+    WXD_STDPATH_PLUGINS_DIR,
+    WXD_STDPATH_APP_DOCUMENTS_DIR,
+    WXD_STDPATH_TEMP_DIR,
+    WXD_STDPATH_EXECUTABLE_PATH,
+    WXD_STDPATH_DOCUMENTS_DIR,
+    WXD_STDPATH_DESKTOP_DIR,
+    WXD_STDPATH_DOWNLOADS_DIR,
+    WXD_STDPATH_MUSIC_DIR,
+    WXD_STDPATH_PICTURES_DIR,
+    WXD_STDPATH_VIDEOS_DIR,
+    WXD_STDPATH_CACHE_DIR
+} wxd_StandardPath;
+
+WXD_EXPORTED char* wxd_StandardPaths_Get(wxd_StandardPath which); // Caller must free with wxd_free_string
+
+// Full path of the file a default wxFileConfig named basename uses
+WXD_EXPORTED char* wxd_StandardPaths_GetLocalConfigFile(const char* basename); // Caller must free with wxd_free_string
+
+// Use XDG directories (~/.config/app etc.) instead of dot files on Unix; no effect elsewhere
+WXD_EXPORTED void wxd_StandardPaths_UseXdgLayout(bool xdg);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif // WXD_STDPATHS_H
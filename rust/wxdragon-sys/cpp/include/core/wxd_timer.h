@@ -19,10 +19,17 @@ typedef enum {
     WXD_TIMER_STATUS_RUNNING = 1  // Timer is running
 } WXDTimerStatus;
 
-// Create a new wxTimer
+// Called on the main thread each time a callback timer fires
+typedef void (*wxd_TimerCallback)(void* userData);
+
+// Create a new wxTimer associated with an event handler
 WXD_EXPORTED wxd_Timer_t* wxd_Timer_Create(wxd_EvtHandler_t* owner);
 
-// Destroy/delete a wxTimer
+// Create a new wxTimer without an owner that calls `callback` instead of sending events
+WXD_EXPORTED wxd_Timer_t* wxd_Timer_CreateWithCallback(wxd_TimerCallback callback, void* userData);
+
+// Destroy/delete a wxTimer. A callback timer is never called again after this, even when
+// destroyed from within its own callback.
 WXD_EXPORTED void wxd_Timer_Destroy(wxd_Timer_t* self);
 
 // Start the timer
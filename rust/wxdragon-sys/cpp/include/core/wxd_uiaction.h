@@ -0,0 +1,41 @@
+#ifndef WXD_UIACTION_H
+#define WXD_UIACTION_H
+
+#include "../wxd_types.h"
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+// Create a simulator generating native input events. Returns NULL if wxUIActionSimulator
+// is not available in this build.
+WXD_EXPORTED wxd_UIActionSimulator_t* wxd_UIActionSimulator_Create();
+
+WXD_EXPORTED void wxd_UIActionSimulator_Destroy(wxd_UIActionSimulator_t* self);
+
+// Mouse actions. Coordinates are in screen pixels; buttons are wxMOUSE_BTN_* values.
+WXD_EXPORTED bool wxd_UIActionSimulator_MouseMove(wxd_UIActionSimulator_t* self, int x, int y);
+WXD_EXPORTED bool wxd_UIActionSimulator_MouseDown(wxd_UIActionSimulator_t* self, int button);
+WXD_EXPORTED bool wxd_UIActionSimulator_MouseUp(wxd_UIActionSimulator_t* self, int button);
+WXD_EXPORTED bool wxd_UIActionSimulator_MouseClick(wxd_UIActionSimulator_t* self, int button);
+WXD_EXPORTED bool wxd_UIActionSimulator_MouseDblClick(wxd_UIActionSimulator_t* self, int button);
+WXD_EXPORTED bool wxd_UIActionSimulator_MouseDragDrop(wxd_UIActionSimulator_t* self, int x1, int y1, int x2, int y2, int button);
+
+// Keyboard actions. keyCode is a wxKeyCode value, modifiers a combination of wxMOD_* flags.
+WXD_EXPORTED bool wxd_UIActionSimulator_KeyDown(wxd_UIActionSimulator_t* self, int keyCode, int modifiers);
+WXD_EXPORTED bool wxd_UIActionSimulator_KeyUp(wxd_UIActionSimulator_t* self, int keyCode, int modifiers);
+WXD_EXPORTED bool wxd_UIActionSimulator_Char(wxd_UIActionSimulator_t* self, int keyCode, int modifiers);
+WXD_EXPORTED bool wxd_UIActionSimulator_Text(wxd_UIActionSimulator_t* self, const char* text);
+
+// Select the item with the given text in a wxChoice, wxComboBox, wxListBox or similar control
+WXD_EXPORTED bool wxd_UIActionSimulator_Select(wxd_UIActionSimulator_t* self, wxd_Window_t* window, const char* text);
+
+// Deliver a wxEVT_MOUSEWHEEL event to the window at the given client position. This is
+// not a native event: only wx handlers see it.
+WXD_EXPORTED bool wxd_UIActionSimulator_MouseWheel(wxd_Window_t* window, int x, int y, int rotation, bool horizontal);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif // WXD_UIACTION_H
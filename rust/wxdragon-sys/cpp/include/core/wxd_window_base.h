@@ -29,6 +29,14 @@ WXD_EXPORTED void wxd_Window_Center(wxd_Window_t* window);
 WXD_EXPORTED wxd_Point wxd_Window_ClientToScreen(wxd_Window_t* window, wxd_Point pt);
 WXD_EXPORTED wxd_Point wxd_Window_ScreenToClient(wxd_Window_t* window, wxd_Point pt);
 
+// Popup menus; a position of (-1, -1) uses the mouse position. Both block until the menu is dismissed.
+WXD_EXPORTED bool wxd_Window_PopupMenu(wxd_Window_t* window, wxd_Menu_t* menu, wxd_Point pos);
+// Returns the selected item id without sending a menu event, or wxID_NONE (-3)
+WXD_EXPORTED int wxd_Window_GetPopupMenuSelectionFromUser(wxd_Window_t* window, wxd_Menu_t* menu, wxd_Point pos);
+
+// Replace the window's accelerator table; a count of 0 removes it
+WXD_EXPORTED void wxd_Window_SetAcceleratorTable(wxd_Window_t* window, const wxd_AcceleratorEntry* entries, int count);
+
 // Declarations for functions that were previously in wxdragon.h directly
 WXD_EXPORTED void wxd_Window_Show(wxd_Window_t* self, bool show);
 WXD_EXPORTED bool wxd_Window_Close(wxd_Window_t* self, bool force);
@@ -114,6 +122,10 @@ WXD_EXPORTED void wxd_Window_ScrollIntoView(wxd_Window_t* window, int64_t positi
 WXD_EXPORTED bool wxd_Window_IsPositionVisible(wxd_Window_t* window, int64_t position);
 WXD_EXPORTED int64_t wxd_Window_GetLastPosition(wxd_Window_t* window);
 
+// --- Layout Direction Functions ---
+WXD_EXPORTED void wxd_Window_SetLayoutDirection(wxd_Window_t* window, wxd_LayoutDirection direction);
+WXD_EXPORTED wxd_LayoutDirection wxd_Window_GetLayoutDirection(wxd_Window_t* window);
+
 // Widget type identification using wxWidgets' built-in RTTI
 WXD_EXPORTED const char* wxd_Window_GetClassName(wxd_Window_t* window);
 
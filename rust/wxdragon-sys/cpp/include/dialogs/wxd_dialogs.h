@@ -7,6 +7,9 @@
 WXD_EXPORTED wxd_Dialog_t* wxd_Dialog_Create(wxd_Window_t* parent, const char* title, wxd_Style_t style, int x, int y, int width, int height);
 WXD_EXPORTED int wxd_Dialog_ShowModal(wxd_Dialog_t* self);
 WXD_EXPORTED void wxd_Dialog_EndModal(wxd_Dialog_t* self, int retCode);
+WXD_EXPORTED void wxd_Dialog_ShowWindowModal(wxd_Dialog_t* self);
+WXD_EXPORTED int wxd_Dialog_GetReturnCode(wxd_Dialog_t* self);
+WXD_EXPORTED bool wxd_Dialog_IsModal(wxd_Dialog_t* self);
 
 // --- MessageDialog ---
 WXD_EXPORTED wxd_MessageDialog_t* wxd_MessageDialog_Create(wxd_Window_t* parent, const char* message, const char* caption, wxd_Style_t style);
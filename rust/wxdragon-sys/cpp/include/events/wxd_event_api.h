@@ -10,7 +10,9 @@ extern "C" {
 typedef int wxEventType;
 
 // --- Event Handling & Data Access --- 
-WXD_EXPORTED void wxd_EvtHandler_Bind(
+// Binding functions return a non-zero binding id on success, or 0 if the
+// closure could not be bound (in which case it has already been dropped).
+WXD_EXPORTED uint64_t wxd_EvtHandler_Bind(
     wxd_EvtHandler_t* handler, 
     WXDEventTypeCEnum eventTypeC,
     void* rust_trampoline_fn,          
@@ -18,7 +20,16 @@ WXD_EXPORTED void wxd_EvtHandler_Bind(
 );
 
 // ID-specific event binding for tools and menu items
-WXD_EXPORTED void wxd_EvtHandler_BindWithId(wxd_EvtHandler_t* evt_handler, WXDEventTypeCEnum event_type, int id, void* callback, void* user_data);
+WXD_EXPORTED uint64_t wxd_EvtHandler_BindWithId(wxd_EvtHandler_t* evt_handler, WXDEventTypeCEnum event_type, int id, void* callback, void* user_data);
+
+// Removes a closure previously bound with wxd_EvtHandler_Bind/BindWithId and drops it.
+// Returns false if the binding id is unknown (already unbound or its handler was destroyed).
+// Unbinding from inside the closure being unbound is allowed; the drop is deferred
+// until the current dispatch finishes.
+WXD_EXPORTED bool wxd_EvtHandler_Unbind(uint64_t binding_id);
+
+// Returns true if the binding id still refers to a bound closure.
+WXD_EXPORTED bool wxd_EvtHandler_IsBound(uint64_t binding_id);
 
 WXD_EXPORTED int wxd_Event_GetId(wxd_Event_t* event);
 WXD_EXPORTED wxd_Window_t* wxd_Event_GetEventObject(wxd_Event_t* event);
@@ -30,6 +41,22 @@ WXD_EXPORTED bool wxd_CommandEvent_IsChecked(wxd_Event_t* event);
 WXD_EXPORTED wxd_Point wxd_MouseEvent_GetPosition(wxd_Event_t* event);
 WXD_EXPORTED int wxd_KeyEvent_GetKeyCode(wxd_Event_t* event);
 WXD_EXPORTED int wxd_KeyEvent_GetUnicodeKey(wxd_Event_t* event);
+// Modifier keys held during the event, as WXD_MOD_* bits
+WXD_EXPORTED int wxd_KeyEvent_GetModifiers(wxd_Event_t* event);
+// Platform-specific key code and flags, e.g. the virtual key code and lParam on Windows
+WXD_EXPORTED uint32_t wxd_KeyEvent_GetRawKeyCode(wxd_Event_t* event);
+WXD_EXPORTED uint32_t wxd_KeyEvent_GetRawKeyFlags(wxd_Event_t* event);
+// True for key down events generated by holding the key
+WXD_EXPORTED bool wxd_KeyEvent_IsAutoRepeat(wxd_Event_t* event);
+
+// Navigation key events (wxNavigationKeyEvent). GetDirection is true for forward.
+WXD_EXPORTED bool wxd_NavigationKeyEvent_GetDirection(wxd_Event_t* event);
+WXD_EXPORTED bool wxd_NavigationKeyEvent_IsWindowChange(wxd_Event_t* event);
+WXD_EXPORTED bool wxd_NavigationKeyEvent_IsFromTab(wxd_Event_t* event);
+WXD_EXPORTED wxd_Window_t* wxd_NavigationKeyEvent_GetCurrentFocus(wxd_Event_t* event);
+// Context menu events (wxContextMenuEvent). The position is in screen coordinates,
+// or wxDefaultPosition (-1, -1) when invoked from the keyboard.
+WXD_EXPORTED wxd_Point wxd_ContextMenuEvent_GetPosition(wxd_Event_t* event);
 WXD_EXPORTED int wxd_CommandEvent_GetInt(wxd_Event_t* event);
 
 WXD_EXPORTED int wxd_ScrollEvent_GetPosition(wxd_Event_t* event);
@@ -109,6 +136,56 @@ WXD_EXPORTED int wxd_IdleEvent_GetMode();
 // Mouse wheel event functions
 WXD_EXPORTED int wxd_MouseEvent_GetWheelRotation(wxd_Event_t* event);
 WXD_EXPORTED int wxd_MouseEvent_GetWheelDelta(wxd_Event_t* event);
+// 0 for the vertical wheel, 1 for the horizontal one
+WXD_EXPORTED int wxd_MouseEvent_GetWheelAxis(wxd_Event_t* event);
+WXD_EXPORTED int wxd_MouseEvent_GetLinesPerAction(wxd_Event_t* event);
+WXD_EXPORTED bool wxd_MouseEvent_IsPageScroll(wxd_Event_t* event);
+
+// Mouse buttons (values of wxMouseButton)
+typedef enum {
+    WXD_MOUSE_BTN_ANY = -1,
+    WXD_MOUSE_BTN_NONE = 0,
+    WXD_MOUSE_BTN_LEFT = 1,
+    WXD_MOUSE_BTN_MIDDLE = 2,
+    WXD_MOUSE_BTN_RIGHT = 3,
+    WXD_MOUSE_BTN_AUX1 = 4,
+    WXD_MOUSE_BTN_AUX2 = 5
+} wxd_MouseButton;
+
+// Bits of wxd_MouseEvent_GetButtonState
+typedef enum {
+    WXD_MOUSE_STATE_LEFT = 0x01,
+    WXD_MOUSE_STATE_MIDDLE = 0x02,
+    WXD_MOUSE_STATE_RIGHT = 0x04,
+    WXD_MOUSE_STATE_AUX1 = 0x08,
+    WXD_MOUSE_STATE_AUX2 = 0x10
+} wxd_MouseButtonState;
+
+// The button that changed state (a wxd_MouseButton), WXD_MOUSE_BTN_NONE for other events
+WXD_EXPORTED int wxd_MouseEvent_GetButton(wxd_Event_t* event);
+// Buttons held during the event, as wxd_MouseButtonState bits
+WXD_EXPORTED int wxd_MouseEvent_GetButtonState(wxd_Event_t* event);
+// Modifier keys held during the event, as WXD_MOD_* bits
+WXD_EXPORTED int wxd_MouseEvent_GetModifiers(wxd_Event_t* event);
+// 1 for a single click, 2 for a double click, ...
+WXD_EXPORTED int wxd_MouseEvent_GetClickCount(wxd_Event_t* event);
+
+// --- Custom (user-defined) events ---
+// Allocates a new event type with wxNewEventType(). Returns a value in the
+// WXD_EVENT_TYPE_CUSTOM_FIRST..WXD_EVENT_TYPE_CUSTOM_LAST range, or WXD_EVENT_TYPE_NULL
+// if the range is exhausted.
+WXD_EXPORTED WXDEventTypeCEnum wxd_EventType_NewCustom(void);
+
+// Queues a custom command event carrying an opaque Rust payload to `handler` (wxQueueEvent).
+// Ownership of `payload` is transferred; it is released with drop_rust_custom_event_payload
+// once the last copy of the event is destroyed. Returns false (and drops the payload) on failure.
+WXD_EXPORTED bool wxd_EvtHandler_QueueCustomEvent(wxd_EvtHandler_t* handler, WXDEventTypeCEnum event_type, int id, void* payload);
+
+// Returns the payload of a custom event, or NULL if the event is not a custom event.
+WXD_EXPORTED void* wxd_CustomEvent_GetPayload(wxd_Event_t* event);
+
+// Rust callback for dropping a custom event payload
+WXD_EXPORTED void drop_rust_custom_event_payload(void* ptr);
 
 // General veto support for all event types (replaces old close event specific functions)
 WXD_EXPORTED bool wxd_Event_CanVeto(wxd_Event_t* event);
@@ -19,6 +19,8 @@ WXD_EXPORTED void wxd_AuiManager_Delete(wxd_AuiManager_t* self);
 WXD_EXPORTED char* wxd_AuiManager_SavePerspective(wxd_AuiManager_t* self);
 WXD_EXPORTED bool wxd_AuiManager_LoadPerspective(wxd_AuiManager_t* self, const char* perspective, bool update);
 WXD_EXPORTED bool wxd_AuiManager_DetachPane(wxd_AuiManager_t* self, wxd_Window_t* window);
+// Restore the perspective saved under name and save it again when the managed window is destroyed
+WXD_EXPORTED bool wxd_AuiManager_RegisterPersistent(wxd_AuiManager_t* self, const char* name);
 
 // --- wxAuiPaneInfo ---
 WXD_EXPORTED wxd_AuiPaneInfo_t* wxd_AuiPaneInfo_Create();
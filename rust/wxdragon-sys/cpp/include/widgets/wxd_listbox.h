@@ -14,6 +14,5 @@ WXD_EXPORTED void wxd_ListBox_SetStringSelection(wxd_ListBox_t* listbox, const c
 WXD_EXPORTED int wxd_ListBox_GetString(wxd_ListBox_t* listbox, int index, char* buffer, int buffer_len);
 WXD_EXPORTED unsigned int wxd_ListBox_GetCount(wxd_ListBox_t* listbox);
 WXD_EXPORTED void wxd_ListBox_Delete(wxd_ListBox_t* listbox, int index);
-WXD_EXPORTED bool wxd_ListBox_PopupMenu(wxd_ListBox_t* listbox, wxd_Menu_t* menu, wxd_Point pos);
 
 #endif // WXD_LISTBOX_H 
\ No newline at end of file
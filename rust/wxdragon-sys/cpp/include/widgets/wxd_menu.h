@@ -10,6 +10,7 @@ WXD_EXPORTED wxd_Menu_t* wxd_Menu_Create(const char* title, wxd_Style_t style);
 WXD_EXPORTED void wxd_Menu_Destroy(wxd_Menu_t* menu);
 WXD_EXPORTED wxd_MenuItem_t* wxd_Menu_Append(wxd_Menu_t* menu, wxd_Id id, const char* item, const char* helpString, int kind);
 WXD_EXPORTED void wxd_Menu_AppendSeparator(wxd_Menu_t* menu);
+WXD_EXPORTED wxd_MenuItem_t* wxd_Menu_AppendSubMenu(wxd_Menu_t* menu, wxd_Menu_t* submenu, const char* text, const char* helpString); // menu takes ownership of submenu
 WXD_EXPORTED void wxd_MenuItem_Destroy(wxd_MenuItem_t* item);
 
 // --- MenuItem State Functions ---
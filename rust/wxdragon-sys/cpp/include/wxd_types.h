@@ -50,6 +50,20 @@ typedef struct {
     short second;
 } wxd_DateTime_t;
 
+// Accelerator table entry: flags are wxACCEL_* values, keyCode a wxKeyCode, command the menu id
+typedef struct {
+    int flags;
+    int keyCode;
+    int command;
+} wxd_AcceleratorEntry;
+
+// Layout direction of a window or locale (values of wxLayoutDirection)
+typedef enum {
+    WXD_LAYOUT_DEFAULT = 0,
+    WXD_LAYOUT_LEFT_TO_RIGHT,
+    WXD_LAYOUT_RIGHT_TO_LEFT
+} wxd_LayoutDirection;
+
 // Variant type codes for DataView
 #define WXD_VARIANT_TYPE_INVALID 0
 #define WXD_VARIANT_TYPE_BOOL 1
@@ -313,7 +327,39 @@ typedef enum {
     WXD_EVENT_TYPE_TASKBAR_BALLOON_TIMEOUT = 337,       // wxEVT_TASKBAR_BALLOON_TIMEOUT
     WXD_EVENT_TYPE_TASKBAR_BALLOON_CLICK = 338,         // wxEVT_TASKBAR_BALLOON_CLICK
 
-    WXD_EVENT_TYPE_MAX // Keep this last for count if needed, or remove if not used for iteration
+    // Dialog events
+    WXD_EVENT_TYPE_WINDOW_MODAL_DIALOG_CLOSED = 339,    // wxEVT_WINDOW_MODAL_DIALOG_CLOSED
+
+    // Child process events
+    WXD_EVENT_TYPE_PROCESS_OUTPUT = 340,                // line read from a child's stdout
+    WXD_EVENT_TYPE_PROCESS_ERROR_OUTPUT = 341,          // line read from a child's stderr
+    WXD_EVENT_TYPE_END_PROCESS = 342,                   // wxEVT_END_PROCESS
+
+    // File system watcher events
+    WXD_EVENT_TYPE_FSWATCHER = 343,                     // wxEVT_FSWATCHER
+
+    // Double-click and auxiliary (back/forward) mouse button events
+    WXD_EVENT_TYPE_LEFT_DCLICK = 344,                   // wxEVT_LEFT_DCLICK
+    WXD_EVENT_TYPE_RIGHT_DCLICK = 345,                  // wxEVT_RIGHT_DCLICK
+    WXD_EVENT_TYPE_MIDDLE_DCLICK = 346,                 // wxEVT_MIDDLE_DCLICK
+    WXD_EVENT_TYPE_AUX1_DOWN = 347,                     // wxEVT_AUX1_DOWN
+    WXD_EVENT_TYPE_AUX1_UP = 348,                       // wxEVT_AUX1_UP
+    WXD_EVENT_TYPE_AUX1_DCLICK = 349,                   // wxEVT_AUX1_DCLICK
+    WXD_EVENT_TYPE_AUX2_DOWN = 350,                     // wxEVT_AUX2_DOWN
+    WXD_EVENT_TYPE_AUX2_UP = 351,                       // wxEVT_AUX2_UP
+    WXD_EVENT_TYPE_AUX2_DCLICK = 352,                   // wxEVT_AUX2_DCLICK
+
+    // Keyboard navigation (Tab, Ctrl+Tab) between controls
+    WXD_EVENT_TYPE_NAVIGATION_KEY = 353,                // wxEVT_NAVIGATION_KEY
+
+    // Right click, the menu key or Shift+F10
+    WXD_EVENT_TYPE_CONTEXT_MENU = 354,                  // wxEVT_CONTEXT_MENU
+
+    WXD_EVENT_TYPE_MAX, // Keep this last among the built-in types; used for iteration
+
+    // Range reserved for event types allocated at runtime by wxd_EventType_NewCustom()
+    WXD_EVENT_TYPE_CUSTOM_FIRST = 10000,
+    WXD_EVENT_TYPE_CUSTOM_LAST = 19999
 } WXDEventTypeCEnum;
 
 typedef int64_t wxd_Style_t;
@@ -582,6 +628,36 @@ typedef struct wxd_Window_t wxd_Window_t;
 /// Opaque pointer to wxTimer
 typedef struct wxd_Timer_t wxd_Timer_t;
 
+/// Opaque pointer to wxSingleInstanceChecker
+typedef struct wxd_SingleInstanceChecker_t wxd_SingleInstanceChecker_t;
+
+/// Opaque pointer to a wxServer accepting local IPC connections
+typedef struct wxd_IpcServer_t wxd_IpcServer_t;
+
+/// Opaque pointer to a wxEventFilter forwarding to a Rust callback
+typedef struct wxd_EventFilter_t wxd_EventFilter_t;
+
+/// Opaque pointer to wxUIActionSimulator
+typedef struct wxd_UIActionSimulator_t wxd_UIActionSimulator_t;
+
+/// Opaque pointer to a wxCommandProcessor holding Rust commands
+typedef struct wxd_CommandProcessor_t wxd_CommandProcessor_t;
+
+/// Opaque pointer to wxFileHistory
+typedef struct wxd_FileHistory_t wxd_FileHistory_t;
+
+/// Opaque pointer to wxConfigBase
+typedef struct wxd_Config_t wxd_Config_t;
+
+/// Opaque pointer to wxLogNull, suppressing log messages while alive
+typedef struct wxd_LogNull_t wxd_LogNull_t;
+
+/// Opaque pointer to wxProcess, a child process started asynchronously
+typedef struct wxd_Process_t wxd_Process_t;
+
+/// Opaque pointer to a file system watcher
+typedef struct wxd_FileSystemWatcher_t wxd_FileSystemWatcher_t;
+
 /// Window ID type (must match wxWidgets window ID type)
 typedef int wxd_Id;
 
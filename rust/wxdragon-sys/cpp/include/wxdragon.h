@@ -111,6 +111,45 @@ extern "C" {
 // Timer
 #include "core/wxd_timer.h"
 
+// Event loop control
+#include "core/wxd_evtloop.h"
+
+// Global event filters
+#include "core/wxd_event_filter.h"
+
+// Single instance checking and local IPC
+#if wxdUSE_SINGLE_INSTANCE
+#include "core/wxd_single_instance.h"
+#endif
+
+// Synthetic input for GUI tests
+#include "core/wxd_uiaction.h"
+
+// Undo/redo history
+#include "core/wxd_cmdproc.h"
+
+// Recently used files
+#include "core/wxd_filehistory.h"
+
+// Settings storage and standard locations
+#include "core/wxd_config.h"
+#include "core/wxd_stdpaths.h"
+
+// Saving and restoring window state
+#include "core/wxd_persistence.h"
+
+// Locale, translations and formatting
+#include "core/wxd_locale.h"
+
+// Log targets and the log crate bridge
+#include "core/wxd_log.h"
+
+// Asynchronous child processes
+#include "core/wxd_process.h"
+
+// Watching files and directories for changes
+#include "core/wxd_fswatcher.h"
+
 // Application progress indicator
 #include "core/wxd_appprogress.h"
 
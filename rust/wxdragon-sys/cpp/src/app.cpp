@@ -1,15 +1,31 @@
 #include <wx/wxprec.h>
 #include <wx/wx.h>
 #include "../include/wxdragon.h"
+#include "wxd_utils.h"
 #include <wx/app.h>
+#include <wx/cmdline.h>
 #include <wx/image.h>
+#include <wx/richmsgdlg.h>
 #include <cstdlib>
+#include <exception>
+#include <string>
 
 // --- Globals --- 
 // Store the C callback and user data provided to wxd_Main
 static wxd_OnInitCallback g_OnInitCallback = nullptr;
 static void* g_OnInitUserData = nullptr;
 
+// Lifecycle hook callback and names applied at startup
+static wxd_AppHookCallback g_HookCallback = nullptr;
+static void* g_HookUserData = nullptr;
+static std::string g_AppName, g_VendorName, g_DisplayName, g_VendorDisplayName;
+static bool g_HasAppName = false, g_HasVendorName = false, g_HasDisplayName = false, g_HasVendorDisplayName = false;
+
+static int call_app_hook(wxd_AppHook hook, int arg, const char* info) {
+    if (!g_HookCallback) return 0;
+    return g_HookCallback(g_HookUserData, hook, arg, info);
+}
+
 // Function to process Rust callbacks, implemented in Rust
 extern "C" int process_rust_callbacks();
 
@@ -22,11 +38,33 @@ public:
 
     // Idle event handler to process callbacks
     void OnIdle(wxIdleEvent& event);
-    
-    // Optional: Override OnExit for cleanup if needed
-    // virtual int OnExit() override;
+
+    // Lifecycle hooks forwarded to Rust
+    virtual int OnExit() override;
+    virtual bool OnExceptionInMainLoop() override;
+    virtual void OnUnhandledException() override;
+    void OnActivateApp(wxActivateEvent& event);
+    void OnQueryEndSession(wxCloseEvent& event);
+    void OnEndSession(wxCloseEvent& event);
+
+#if wxUSE_CMDLINE_PARSER
+    // The real argv is forwarded to Rust, which parses it itself; never fail startup on it
+    virtual bool OnCmdLineError(wxCmdLineParser& WXUNUSED(parser)) override { return true; }
+    virtual bool OnCmdLineHelp(wxCmdLineParser& WXUNUSED(parser)) override { return true; }
+#endif
 };
 
+// Describes the exception currently being handled
+static std::string describe_current_exception() {
+    try {
+        throw;
+    } catch (const std::exception& e) {
+        return e.what();
+    } catch (...) {
+        return "unknown exception";
+    }
+}
+
 // Implementation of OnInit - this is where we call the C callback
 bool WxdApp::OnInit() {
     // Call base class OnInit (important)
@@ -45,6 +83,11 @@ bool WxdApp::OnInit() {
     // Bind idle event to process callbacks
     Bind(wxEVT_IDLE, &WxdApp::OnIdle, this);
 
+    // Lifecycle events forwarded to the Rust hooks
+    Bind(wxEVT_ACTIVATE_APP, &WxdApp::OnActivateApp, this);
+    Bind(wxEVT_QUERY_END_SESSION, &WxdApp::OnQueryEndSession, this);
+    Bind(wxEVT_END_SESSION, &WxdApp::OnEndSession, this);
+
     // Call the stored C callback function
     if (g_OnInitCallback) {
         // The callback is responsible for creating the main window
@@ -70,6 +113,45 @@ void WxdApp::OnIdle(wxIdleEvent& event) {
     }
 }
 
+int WxdApp::OnExit() {
+    call_app_hook(WXD_APP_HOOK_EXIT, 0, nullptr);
+    return wxApp::OnExit();
+}
+
+bool WxdApp::OnExceptionInMainLoop() {
+    std::string message = describe_current_exception();
+    if (call_app_hook(WXD_APP_HOOK_UNHANDLED_EXCEPTION, 1, message.c_str()) != 0) {
+        return true; // The hook handled it; keep running
+    }
+    return wxApp::OnExceptionInMainLoop(); // Rethrows by default
+}
+
+void WxdApp::OnUnhandledException() {
+    std::string message = describe_current_exception();
+    if (call_app_hook(WXD_APP_HOOK_UNHANDLED_EXCEPTION, 0, message.c_str()) == 0) {
+        wxApp::OnUnhandledException();
+    }
+}
+
+void WxdApp::OnActivateApp(wxActivateEvent& event) {
+    call_app_hook(WXD_APP_HOOK_ACTIVATE, event.GetActive() ? 1 : 0, nullptr);
+    event.Skip();
+}
+
+void WxdApp::OnQueryEndSession(wxCloseEvent& event) {
+    bool can_veto = event.CanVeto();
+    if (call_app_hook(WXD_APP_HOOK_QUERY_END_SESSION, can_veto ? 1 : 0, nullptr) != 0 && can_veto) {
+        event.Veto();
+        return;
+    }
+    event.Skip();
+}
+
+void WxdApp::OnEndSession(wxCloseEvent& event) {
+    call_app_hook(WXD_APP_HOOK_END_SESSION, 0, nullptr);
+    event.Skip(); // Let wxApp close the windows
+}
+
 // --- C API Implementation --- 
 
 // This macro creates the necessary wxWidgets entry points (like main or WinMain)
@@ -86,9 +168,44 @@ wxIMPLEMENT_APP_NO_MAIN(WxdApp);
 
 // Main entry point implementation
 int wxd_Main(int argc, char** argv, wxd_OnInitCallback on_init_cb, void* userData) {
+    int exitCode = 0;
+    if (!wxd_App_Run(argc, argv, on_init_cb, userData, &exitCode)) {
+        return 1;
+    }
+    return exitCode;
+}
+
+void wxd_App_SetStartupNames(const char* appName, const char* vendorName, const char* displayName, const char* vendorDisplayName) {
+    g_HasAppName = appName != nullptr;
+    g_AppName = appName ? appName : "";
+    g_HasVendorName = vendorName != nullptr;
+    g_VendorName = vendorName ? vendorName : "";
+    g_HasDisplayName = displayName != nullptr;
+    g_DisplayName = displayName ? displayName : "";
+    g_HasVendorDisplayName = vendorDisplayName != nullptr;
+    g_VendorDisplayName = vendorDisplayName ? vendorDisplayName : "";
+}
+
+void wxd_App_SetHookCallback(wxd_AppHookCallback callback, void* userData) {
+    g_HookCallback = callback;
+    g_HookUserData = callback ? userData : nullptr;
+}
+
+int wxd_App_GetAppName(char* buffer, int bufLen) {
+    if (!wxTheApp) return -1;
+    return GET_WX_STRING_RESULT(wxTheApp->GetAppName(), buffer, bufLen);
+}
+
+int wxd_App_GetVendorName(char* buffer, int bufLen) {
+    if (!wxTheApp) return -1;
+    return GET_WX_STRING_RESULT(wxTheApp->GetVendorName(), buffer, bufLen);
+}
+
+bool wxd_App_Run(int argc, char** argv, wxd_OnInitCallback on_init_cb, void* userData, int* exitCode) {
+    if (exitCode) *exitCode = 0;
     if (!on_init_cb) {
         fprintf(stderr, "wxDragon Error: No OnInit callback provided to wxd_Main.\n");
-        return 1;
+        return false;
     }
 
     g_OnInitCallback = on_init_cb;
@@ -96,7 +213,15 @@ int wxd_Main(int argc, char** argv, wxd_OnInitCallback on_init_cb, void* userDat
 
     if (!wxEntryStart(argc, argv)) {
         fprintf(stderr, "wxDragon Error: Failed to initialize wxWidgets (wxEntryStart failed).\n");
-        return 1;
+        return false;
+    }
+
+    // Apply names before OnInit so config paths and dialogs use them from the start
+    if (wxTheApp) {
+        if (g_HasAppName) wxTheApp->SetAppName(wxString::FromUTF8(g_AppName.c_str()));
+        if (g_HasVendorName) wxTheApp->SetVendorName(wxString::FromUTF8(g_VendorName.c_str()));
+        if (g_HasDisplayName) wxTheApp->SetAppDisplayName(wxString::FromUTF8(g_DisplayName.c_str()));
+        if (g_HasVendorDisplayName) wxTheApp->SetVendorDisplayName(wxString::FromUTF8(g_VendorDisplayName.c_str()));
     }
 
     // Initialize all available image handlers (PNG, JPEG, etc.)
@@ -105,9 +230,13 @@ int wxd_Main(int argc, char** argv, wxd_OnInitCallback on_init_cb, void* userDat
 
     // wxTheApp should now be a WxdApp instance.
     // CallOnInit will execute WxdApp::OnInit, which calls the Rust g_OnInitCallback.
+    bool initialized = false;
     if (wxTheApp && wxTheApp->CallOnInit()) {
         // Rust initialization was successful (returned true)
-        wxTheApp->OnRun(); // Start the main event loop
+        initialized = true;
+        int code = wxTheApp->OnRun(); // Start the main event loop; returns the loop's exit code
+        if (exitCode) *exitCode = code;
+        wxTheApp->OnExit(); // wxEntryCleanup does not call OnExit when the entry is split up
     } else {
         // wxApp initialization failed (CallOnInit returned false or wxTheApp was null)
         // Log this case, as WxdApp::OnInit (and thus Rust init) might have returned false.
@@ -118,7 +247,9 @@ int wxd_Main(int argc, char** argv, wxd_OnInitCallback on_init_cb, void* userDat
     wxEntryCleanup();
     g_OnInitCallback = nullptr;
     g_OnInitUserData = nullptr;
-    return 0; // Consider returning an error code if init failed
+    g_HookCallback = nullptr;
+    g_HookUserData = nullptr;
+    return initialized;
 }
 
 // Gets the handle to the global application instance.
@@ -140,6 +271,44 @@ void wxd_App_ProcessCallbacks() {
     process_rust_callbacks();
 }
 
+// Wake up the event loop from any thread so OnIdle runs and drains the Rust queue
+void wxd_App_WakeUpIdle() {
+    wxWakeUpIdle();
+}
+
+void wxd_App_ShowErrorDialog(const char* message, const char* details) {
+    if (!wxTheApp) return;
+
+    wxString title = wxTheApp->GetAppDisplayName();
+    if (title.IsEmpty()) title = "Error";
+    wxRichMessageDialog dialog(wxTheApp->GetTopWindow(), WXD_STR_TO_WX_STRING_UTF8_NULL_OK(message),
+                               title, wxOK | wxICON_ERROR | wxCENTRE);
+    wxString detailText = WXD_STR_TO_WX_STRING_UTF8_NULL_OK(details);
+    if (!detailText.IsEmpty()) {
+        dialog.ShowDetailedText(detailText);
+    }
+    dialog.ShowModal();
+}
+
+void wxd_App_SetExitOnFrameDelete(bool flag) {
+    if (wxTheApp) wxTheApp->SetExitOnFrameDelete(flag);
+}
+
+int wxd_App_DestroyTopLevelWindows() {
+    // Copy the list first: destroying a window removes it from wxTopLevelWindows
+    wxWindowList windows;
+    for (wxWindowList::compatibility_iterator node = wxTopLevelWindows.GetFirst(); node; node = node->GetNext()) {
+        windows.Append(node->GetData());
+    }
+    int count = 0;
+    for (wxWindowList::compatibility_iterator node = windows.GetFirst(); node; node = node->GetNext()) {
+        if (node->GetData()->Destroy()) ++count;
+    }
+    // Top-level windows are deleted lazily; delete them now rather than at the next idle
+    if (wxTheApp) wxTheApp->ProcessIdle();
+    return count;
+}
+
 // Implementation for wxd_free_string
 void wxd_free_string(char* str) {
     if (str) {
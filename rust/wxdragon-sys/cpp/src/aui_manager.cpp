@@ -5,6 +5,8 @@
 
 #include <wx/aui/framemanager.h>
 #include <wx/aui/auibook.h>
+#include <wx/persist.h>
+#include <wx/weakref.h>
 
 // Direction constants for AddPane function (matching wxAUI constants)
 #define WXD_AUI_DOCK_LEFT   (0)
@@ -29,6 +31,50 @@ struct wxd_AuiPaneInfo_t {
     wxd_AuiPaneInfo_t(const wxAuiPaneInfo& i) : info(i) {}
 };
 
+// Saves the perspective of an AUI manager, keyed by its wrapper, when the managed window goes away
+class WxdPersistentAuiManager : public wxPersistentObject {
+public:
+    WxdPersistentAuiManager(wxd_AuiManager_t* self, const wxString& name)
+        : wxPersistentObject(self), m_self(self), m_name(name) {
+        m_frame = self->manager->GetManagedWindow();
+        if (m_frame) {
+            m_frame->Bind(wxEVT_DESTROY, &WxdPersistentAuiManager::OnDestroy, this);
+        }
+    }
+
+    ~WxdPersistentAuiManager() override {
+        if (m_frame) {
+            m_frame->Unbind(wxEVT_DESTROY, &WxdPersistentAuiManager::OnDestroy, this);
+        }
+    }
+
+    wxString GetKind() const override { return "AuiManager"; }
+    wxString GetName() const override { return m_name; }
+
+    void Save() const override {
+        SaveValue("Perspective", m_self->manager->SavePerspective());
+    }
+
+    bool Restore() override {
+        wxString perspective;
+        return RestoreValue("Perspective", &perspective) &&
+               m_self->manager->LoadPerspective(perspective, true);
+    }
+
+private:
+    void OnDestroy(wxWindowDestroyEvent& event) {
+        event.Skip();
+        if (event.GetEventObject() == m_frame.get()) {
+            // Deletes this object
+            wxPersistenceManager::Get().SaveAndUnregister(m_self);
+        }
+    }
+
+    wxd_AuiManager_t* m_self;
+    wxString m_name;
+    wxWeakRef<wxWindow> m_frame;
+};
+
 // --- wxAuiManager implementation ---
 
 extern "C" {
@@ -109,6 +155,10 @@ bool wxd_AuiManager_Update(wxd_AuiManager_t* self) {
 
 void wxd_AuiManager_Delete(wxd_AuiManager_t* self) {
     if (!self) return;
+
+    if (wxPersistenceManager::Get().Find(self)) {
+        wxPersistenceManager::Get().SaveAndUnregister(self);
+    }
     
     if (self->manager) {
         // UnInit the manager first to ensure proper cleanup
@@ -144,6 +194,13 @@ bool wxd_AuiManager_DetachPane(wxd_AuiManager_t* self, wxd_Window_t* window) {
     return self->manager->DetachPane(wx_window);
 }
 
+bool wxd_AuiManager_RegisterPersistent(wxd_AuiManager_t* self, const char* name) {
+    if (!self || !self->manager || wxPersistenceManager::Get().Find(self)) return false;
+
+    wxString wx_name = WXD_STR_TO_WX_STRING_UTF8_NULL_OK(name);
+    return wxPersistenceManager::Get().RegisterAndRestore(self, new WxdPersistentAuiManager(self, wx_name));
+}
+
 // --- wxAuiPaneInfo implementation ---
 
 wxd_AuiPaneInfo_t* wxd_AuiPaneInfo_Create() {
@@ -0,0 +1,217 @@
+#include <wx/wxprec.h>
+#include <wx/wx.h>
+#include "../../include/wxdragon.h"
+#include "../../include/core/wxd_cmdproc.h"
+#include "../wxd_utils.h"
+#include <wx/cmdproc.h>
+#include <wx/weakref.h>
+#include <vector>
+
+namespace {
+
+// A command implemented in Rust. It has already been performed when it is stored.
+class WxdCommand : public wxCommand {
+public:
+    WxdCommand(const wxString& name, bool canUndo, wxd_CommandCallback doIt, wxd_CommandCallback undoIt,
+               wxd_CommandDropCallback drop, void* userData)
+        : wxCommand(canUndo, name), m_doIt(doIt), m_undoIt(undoIt), m_drop(drop), m_userData(userData) {}
+
+    virtual ~WxdCommand() {
+        if (m_drop) m_drop(m_userData);
+    }
+
+    virtual bool Do() override { return m_doIt && m_doIt(m_userData); }
+    virtual bool Undo() override { return m_undoIt && m_undoIt(m_userData); }
+
+    void SetName(const wxString& name) { m_commandName = name; }
+    void* GetUserData() const { return m_userData; }
+
+private:
+    wxd_CommandCallback m_doIt;
+    wxd_CommandCallback m_undoIt;
+    wxd_CommandDropCallback m_drop;
+    void* m_userData;
+};
+
+// Command processor that keeps the undo/redo items of attached windows' menu bars up to date
+// and honours a history limit changed after creation
+class WxdCommandProcessor : public wxCommandProcessor {
+public:
+    explicit WxdCommandProcessor(int maxCommands) : wxCommandProcessor(maxCommands) {}
+
+    virtual void Store(wxCommand* command) override {
+        wxCommandProcessor::Store(command);
+        TrimHistory();
+    }
+
+    virtual void SetMenuStrings() override {
+        wxCommandProcessor::SetMenuStrings();
+#if wxUSE_MENUS
+        for (auto it = m_windows.begin(); it != m_windows.end();) {
+            wxWindow* window = *it;
+            if (!window) {
+                it = m_windows.erase(it);
+                continue;
+            }
+            wxFrame* frame = wxDynamicCast(window, wxFrame);
+            wxMenuBar* menuBar = frame ? frame->GetMenuBar() : nullptr;
+            if (menuBar) {
+                if (wxMenuItem* undo = menuBar->FindItem(wxID_UNDO)) {
+                    undo->SetItemLabel(GetUndoMenuLabel());
+                    undo->Enable(CanUndo());
+                }
+                if (wxMenuItem* redo = menuBar->FindItem(wxID_REDO)) {
+                    redo->SetItemLabel(GetRedoMenuLabel());
+                    redo->Enable(CanRedo());
+                }
+            }
+            ++it;
+        }
+#endif
+    }
+
+    void AttachWindow(wxWindow* window) {
+        for (const auto& attached : m_windows) {
+            if (attached.get() == window) return;
+        }
+        m_windows.push_back(wxWeakRef<wxWindow>(window));
+    }
+
+    void SetMaxCommands(int maxCommands) {
+        m_maxNoCommands = maxCommands;
+        TrimHistory();
+        SetMenuStrings();
+    }
+
+    WxdCommand* GetUndoCommand() const {
+        return dynamic_cast<WxdCommand*>(GetCurrentCommand());
+    }
+
+    WxdCommand* GetRedoCommand() const {
+        wxList::compatibility_iterator node = m_currentCommand ? m_currentCommand->GetNext() : m_commands.GetFirst();
+        return node ? dynamic_cast<WxdCommand*>(static_cast<wxCommand*>(node->GetData())) : nullptr;
+    }
+
+private:
+    // Drops the oldest undoable commands until the history fits the limit
+    void TrimHistory() {
+        if (m_maxNoCommands < 0) return;
+        while ((int)m_commands.GetCount() > m_maxNoCommands && m_currentCommand) {
+            wxList::compatibility_iterator first = m_commands.GetFirst();
+            if (first == m_currentCommand) {
+                m_currentCommand = wxList::compatibility_iterator();
+            }
+            if (m_lastSavedCommand && m_lastSavedCommand == first) {
+                m_lastSavedCommand = wxList::compatibility_iterator();
+            }
+            delete static_cast<wxCommand*>(first->GetData());
+            m_commands.Erase(first);
+        }
+    }
+
+    std::vector<wxWeakRef<wxWindow>> m_windows;
+};
+
+WxdCommandProcessor* AsProcessor(wxd_CommandProcessor_t* self) {
+    return reinterpret_cast<WxdCommandProcessor*>(self);
+}
+
+} // namespace
+
+extern "C" {
+
+wxd_CommandProcessor_t* wxd_CommandProcessor_Create(int maxCommands) {
+    return reinterpret_cast<wxd_CommandProcessor_t*>(new WxdCommandProcessor(maxCommands));
+}
+
+void wxd_CommandProcessor_Destroy(wxd_CommandProcessor_t* self) {
+    delete AsProcessor(self);
+}
+
+void wxd_CommandProcessor_Store(wxd_CommandProcessor_t* self, const char* name, bool canUndo,
+                                wxd_CommandCallback doIt, wxd_CommandCallback undoIt,
+                                wxd_CommandDropCallback drop, void* userData) {
+    WxdCommand* command = new WxdCommand(WXD_STR_TO_WX_STRING_UTF8_NULL_OK(name), canUndo, doIt, undoIt, drop,
+                                         userData);
+    if (!self) {
+        delete command;
+        return;
+    }
+    AsProcessor(self)->Store(command);
+}
+
+bool wxd_CommandProcessor_Undo(wxd_CommandProcessor_t* self) {
+    return self && AsProcessor(self)->Undo();
+}
+
+bool wxd_CommandProcessor_Redo(wxd_CommandProcessor_t* self) {
+    return self && AsProcessor(self)->Redo();
+}
+
+bool wxd_CommandProcessor_CanUndo(wxd_CommandProcessor_t* self) {
+    return self && AsProcessor(self)->CanUndo();
+}
+
+bool wxd_CommandProcessor_CanRedo(wxd_CommandProcessor_t* self) {
+    return self && AsProcessor(self)->CanRedo();
+}
+
+void* wxd_CommandProcessor_GetUndoCommandData(wxd_CommandProcessor_t* self) {
+    if (!self) return nullptr;
+    WxdCommand* command = AsProcessor(self)->GetUndoCommand();
+    return command ? command->GetUserData() : nullptr;
+}
+
+void* wxd_CommandProcessor_GetRedoCommandData(wxd_CommandProcessor_t* self) {
+    if (!self) return nullptr;
+    WxdCommand* command = AsProcessor(self)->GetRedoCommand();
+    return command ? command->GetUserData() : nullptr;
+}
+
+void wxd_CommandProcessor_SetUndoCommandName(wxd_CommandProcessor_t* self, const char* name) {
+    if (!self) return;
+    if (WxdCommand* command = AsProcessor(self)->GetUndoCommand()) {
+        command->SetName(WXD_STR_TO_WX_STRING_UTF8_NULL_OK(name));
+    }
+}
+
+void wxd_CommandProcessor_ClearCommands(wxd_CommandProcessor_t* self) {
+    if (!self) return;
+    AsProcessor(self)->ClearCommands();
+    AsProcessor(self)->SetMenuStrings();
+}
+
+void wxd_CommandProcessor_SetMaxCommands(wxd_CommandProcessor_t* self, int maxCommands) {
+    if (!self) return;
+    AsProcessor(self)->SetMaxCommands(maxCommands);
+}
+
+int wxd_CommandProcessor_GetMaxCommands(wxd_CommandProcessor_t* self) {
+    return self ? AsProcessor(self)->GetMaxCommands() : -1;
+}
+
+int wxd_CommandProcessor_GetCount(wxd_CommandProcessor_t* self) {
+    return self ? (int)AsProcessor(self)->GetCommands().GetCount() : 0;
+}
+
+void wxd_CommandProcessor_MarkAsSaved(wxd_CommandProcessor_t* self) {
+    if (!self) return;
+    AsProcessor(self)->MarkAsSaved();
+}
+
+bool wxd_CommandProcessor_IsDirty(wxd_CommandProcessor_t* self) {
+    return self && AsProcessor(self)->IsDirty();
+}
+
+void wxd_CommandProcessor_AttachWindow(wxd_CommandProcessor_t* self, wxd_Window_t* window) {
+    if (!self || !window) return;
+    AsProcessor(self)->AttachWindow(reinterpret_cast<wxWindow*>(window));
+    AsProcessor(self)->SetMenuStrings();
+}
+
+void wxd_CommandProcessor_UpdateMenus(wxd_CommandProcessor_t* self) {
+    if (!self) return;
+    AsProcessor(self)->SetMenuStrings();
+}
+
+} // extern "C"
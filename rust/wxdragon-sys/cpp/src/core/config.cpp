@@ -0,0 +1,198 @@
+#include <wx/wxprec.h>
+#include <wx/wx.h>
+#include "../../include/wxdragon.h"
+#include "../../include/core/wxd_config.h"
+#include "../wxd_utils.h"
+#include <wx/config.h>
+#include <wx/fileconf.h>
+
+namespace {
+
+wxConfigBase* AsConfig(wxd_Config_t* self) {
+    return reinterpret_cast<wxConfigBase*>(self);
+}
+
+wxString Key(const char* key) {
+    return WXD_STR_TO_WX_STRING_UTF8_NULL_OK(key);
+}
+
+char* ToCString(const wxString& value) {
+    return strdup(value.ToUTF8().data());
+}
+
+// Appends the names produced by a GetFirstXxx/GetNextXxx enumeration
+template <typename First, typename Next>
+int CollectNames(wxd_ArrayString_t* out, First first, Next next) {
+    if (!out || !out->internal_data) return 0;
+    wxArrayString* names = static_cast<wxArrayString*>(out->internal_data);
+    int count = 0;
+    wxString name;
+    long cookie = 0;
+    for (bool more = first(name, cookie); more; more = next(name, cookie)) {
+        names->Add(name);
+        ++count;
+    }
+    return count;
+}
+
+} // namespace
+
+extern "C" {
+
+wxd_Config_t* wxd_Config_Create(const char* appName, const char* vendorName) {
+    wxConfigBase* config = new wxConfig(Key(appName), Key(vendorName));
+    return reinterpret_cast<wxd_Config_t*>(config);
+}
+
+wxd_Config_t* wxd_Config_CreateFile(const char* path) {
+    wxConfigBase* config = new wxFileConfig(wxEmptyString, wxEmptyString, Key(path), wxEmptyString,
+                                            wxCONFIG_USE_LOCAL_FILE);
+    return reinterpret_cast<wxd_Config_t*>(config);
+}
+
+void wxd_Config_Destroy(wxd_Config_t* self) {
+    if (!self) return;
+    // Never leave a dangling global
+    if (wxConfigBase::Get(false) == AsConfig(self)) {
+        wxConfigBase::Set(nullptr);
+    }
+    delete AsConfig(self);
+}
+
+wxd_Config_t* wxd_Config_GetGlobal(bool createOnDemand) {
+    return reinterpret_cast<wxd_Config_t*>(wxConfigBase::Get(createOnDemand));
+}
+
+void wxd_Config_SetGlobal(wxd_Config_t* config) {
+    wxConfigBase* previous = wxConfigBase::Set(AsConfig(config));
+    if (previous && previous != AsConfig(config)) {
+        delete previous;
+    }
+}
+
+void wxd_Config_SetPath(wxd_Config_t* self, const char* path) {
+    if (!self) return;
+    AsConfig(self)->SetPath(Key(path));
+}
+
+char* wxd_Config_GetPath(wxd_Config_t* self) {
+    if (!self) return nullptr;
+    return ToCString(AsConfig(self)->GetPath());
+}
+
+bool wxd_Config_HasEntry(wxd_Config_t* self, const char* key) {
+    return self && AsConfig(self)->HasEntry(Key(key));
+}
+
+bool wxd_Config_HasGroup(wxd_Config_t* self, const char* key) {
+    return self && AsConfig(self)->HasGroup(Key(key));
+}
+
+char* wxd_Config_ReadString(wxd_Config_t* self, const char* key) {
+    wxString value;
+    if (!self || !AsConfig(self)->Read(Key(key), &value)) return nullptr;
+    return ToCString(value);
+}
+
+bool wxd_Config_ReadLong(wxd_Config_t* self, const char* key, int64_t* value) {
+    wxLongLong_t result;
+    if (!self || !value || !AsConfig(self)->Read(Key(key), &result)) return false;
+    *value = (int64_t)result;
+    return true;
+}
+
+bool wxd_Config_ReadDouble(wxd_Config_t* self, const char* key, double* value) {
+    return self && value && AsConfig(self)->Read(Key(key), value);
+}
+
+bool wxd_Config_ReadBool(wxd_Config_t* self, const char* key, bool* value) {
+    return self && value && AsConfig(self)->Read(Key(key), value);
+}
+
+bool wxd_Config_ReadColour(wxd_Config_t* self, const char* key, wxd_Colour_t* value) {
+    wxString text;
+    if (!self || !value || !AsConfig(self)->Read(Key(key), &text)) return false;
+    wxColour colour;
+    if (!wxFromString(text, &colour) || !colour.IsOk()) return false;
+    *value = wxd_Colour_t{colour.Red(), colour.Green(), colour.Blue(), colour.Alpha()};
+    return true;
+}
+
+wxd_Font_t* wxd_Config_ReadFont(wxd_Config_t* self, const char* key) {
+    wxString text;
+    if (!self || !AsConfig(self)->Read(Key(key), &text)) return nullptr;
+    wxFont font;
+    if (!wxFromString(text, &font) || !font.IsOk()) return nullptr;
+    return reinterpret_cast<wxd_Font_t*>(new wxFont(font));
+}
+
+bool wxd_Config_WriteString(wxd_Config_t* self, const char* key, const char* value) {
+    return self && AsConfig(self)->Write(Key(key), WXD_STR_TO_WX_STRING_UTF8_NULL_OK(value));
+}
+
+bool wxd_Config_WriteLong(wxd_Config_t* self, const char* key, int64_t value) {
+    return self && AsConfig(self)->Write(Key(key), (wxLongLong_t)value);
+}
+
+bool wxd_Config_WriteDouble(wxd_Config_t* self, const char* key, double value) {
+    return self && AsConfig(self)->Write(Key(key), value);
+}
+
+bool wxd_Config_WriteBool(wxd_Config_t* self, const char* key, bool value) {
+    return self && AsConfig(self)->Write(Key(key), value);
+}
+
+bool wxd_Config_WriteColour(wxd_Config_t* self, const char* key, wxd_Colour_t value) {
+    if (!self) return false;
+    wxColour colour(value.r, value.g, value.b, value.a);
+    return AsConfig(self)->Write(Key(key), wxToString(colour));
+}
+
+bool wxd_Config_WriteFont(wxd_Config_t* self, const char* key, const wxd_Font_t* value) {
+    if (!self || !value) return false;
+    const wxFont* font = reinterpret_cast<const wxFont*>(value);
+    if (!font->IsOk()) return false;
+    return AsConfig(self)->Write(Key(key), wxToString(*font));
+}
+
+int wxd_Config_GetEntries(wxd_Config_t* self, wxd_ArrayString_t* out) {
+    if (!self) return 0;
+    wxConfigBase* config = AsConfig(self);
+    return CollectNames(out,
+        [config](wxString& name, long& cookie) { return config->GetFirstEntry(name, cookie); },
+        [config](wxString& name, long& cookie) { return config->GetNextEntry(name, cookie); });
+}
+
+int wxd_Config_GetGroups(wxd_Config_t* self, wxd_ArrayString_t* out) {
+    if (!self) return 0;
+    wxConfigBase* config = AsConfig(self);
+    return CollectNames(out,
+        [config](wxString& name, long& cookie) { return config->GetFirstGroup(name, cookie); },
+        [config](wxString& name, long& cookie) { return config->GetNextGroup(name, cookie); });
+}
+
+bool wxd_Config_RenameEntry(wxd_Config_t* self, const char* oldName, const char* newName) {
+    return self && AsConfig(self)->RenameEntry(Key(oldName), Key(newName));
+}
+
+bool wxd_Config_RenameGroup(wxd_Config_t* self, const char* oldName, const char* newName) {
+    return self && AsConfig(self)->RenameGroup(Key(oldName), Key(newName));
+}
+
+bool wxd_Config_DeleteEntry(wxd_Config_t* self, const char* key, bool deleteGroupIfEmpty) {
+    return self && AsConfig(self)->DeleteEntry(Key(key), deleteGroupIfEmpty);
+}
+
+bool wxd_Config_DeleteGroup(wxd_Config_t* self, const char* key) {
+    return self && AsConfig(self)->DeleteGroup(Key(key));
+}
+
+bool wxd_Config_DeleteAll(wxd_Config_t* self) {
+    return self && AsConfig(self)->DeleteAll();
+}
+
+bool wxd_Config_Flush(wxd_Config_t* self) {
+    return self && AsConfig(self)->Flush();
+}
+
+} // extern "C"
@@ -0,0 +1,47 @@
+#include <wx/wxprec.h>
+#include <wx/wx.h>
+#include "../../include/wxdragon.h"
+#include "../../include/core/wxd_event_filter.h"
+#include <wx/eventfilter.h>
+
+namespace {
+
+// Forwards every event to a Rust callback
+class WxdEventFilter : public wxEventFilter {
+public:
+    WxdEventFilter(wxd_EventFilterCallback callback, void* userData)
+        : m_callback(callback), m_userData(userData) {}
+
+    virtual int FilterEvent(wxEvent& event) override {
+        if (!m_callback) return Event_Skip;
+        switch (m_callback(m_userData, reinterpret_cast<wxd_Event_t*>(&event))) {
+            case WXD_EVENT_FILTER_IGNORE: return Event_Ignore;
+            case WXD_EVENT_FILTER_PROCESSED: return Event_Processed;
+            default: return Event_Skip;
+        }
+    }
+
+private:
+    wxd_EventFilterCallback m_callback;
+    void* m_userData;
+};
+
+} // namespace
+
+extern "C" {
+
+wxd_EventFilter_t* wxd_EventFilter_Add(wxd_EventFilterCallback callback, void* userData) {
+    if (!callback) return nullptr;
+    WxdEventFilter* filter = new WxdEventFilter(callback, userData);
+    wxEvtHandler::AddFilter(filter);
+    return reinterpret_cast<wxd_EventFilter_t*>(filter);
+}
+
+void wxd_EventFilter_Remove(wxd_EventFilter_t* self) {
+    if (!self) return;
+    WxdEventFilter* filter = reinterpret_cast<WxdEventFilter*>(self);
+    wxEvtHandler::RemoveFilter(filter);
+    delete filter;
+}
+
+} // extern "C"
@@ -0,0 +1,132 @@
+#include <wx/wxprec.h>
+#include <wx/wx.h>
+#include "../../include/wxdragon.h"
+#include "../../include/core/wxd_evtloop.h"
+#include <wx/evtloop.h>
+#include <wx/timer.h>
+#include <wx/utils.h>
+
+namespace {
+
+// Nested loop that ends itself as soon as the Rust predicate is satisfied
+class WxdPredicateEventLoop : public wxGUIEventLoop {
+public:
+    WxdPredicateEventLoop(wxd_EventLoopPredicate predicate, void* userData)
+        : m_predicate(predicate), m_userData(userData), m_done(false) {}
+
+    // Checks the predicate and schedules exit when it holds
+    void CheckPredicate() {
+        if (m_done || !IsRunning()) return;
+        if (m_predicate(m_userData)) {
+            m_done = true;
+            ScheduleExit(0);
+        }
+    }
+
+    virtual bool ProcessIdle() override {
+        bool more = wxGUIEventLoop::ProcessIdle();
+        CheckPredicate();
+        return more && !m_done;
+    }
+
+private:
+    wxd_EventLoopPredicate m_predicate;
+    void* m_userData;
+    bool m_done;
+};
+
+// Wakes the nested loop periodically so the predicate is re-checked even without input
+class WxdPredicatePollTimer : public wxTimer {
+public:
+    explicit WxdPredicatePollTimer(WxdPredicateEventLoop* loop) : m_loop(loop) {}
+
+    virtual void Notify() override {
+        m_loop->CheckPredicate();
+        wxWakeUpIdle();
+    }
+
+private:
+    WxdPredicateEventLoop* m_loop;
+};
+
+} // namespace
+
+extern "C" {
+
+WXD_EXPORTED bool wxd_EventLoop_IsRunning() {
+    wxEventLoopBase* loop = wxEventLoopBase::GetActive();
+    return loop && loop->IsRunning();
+}
+
+WXD_EXPORTED bool wxd_EventLoop_IsMain() {
+    wxEventLoopBase* loop = wxEventLoopBase::GetActive();
+    return loop && loop->IsMain();
+}
+
+WXD_EXPORTED bool wxd_EventLoop_Exit(int exitCode) {
+    wxEventLoopBase* loop = wxEventLoopBase::GetActive();
+    if (!loop || !loop->IsInsideRun()) return false;
+    loop->ScheduleExit(exitCode);
+    return true;
+}
+
+WXD_EXPORTED bool wxd_EventLoop_ExitMain(int exitCode) {
+    if (!wxTheApp) return false;
+    wxEventLoopBase* loop = wxTheApp->GetMainLoop();
+    if (!loop || !loop->IsInsideRun()) return false;
+    loop->ScheduleExit(exitCode);
+    return true;
+}
+
+WXD_EXPORTED bool wxd_EventLoop_Pending() {
+    wxEventLoopBase* loop = wxEventLoopBase::GetActive();
+    return loop && loop->Pending();
+}
+
+WXD_EXPORTED bool wxd_EventLoop_Dispatch(int timeoutMs) {
+    wxEventLoopBase* loop = wxEventLoopBase::GetActive();
+    if (!loop) return false;
+    if (timeoutMs < 0) {
+        return loop->Dispatch();
+    }
+    // DispatchTimeout returns 1 if an event was dispatched, 0 on timeout, -1 on exit
+    return loop->DispatchTimeout(static_cast<unsigned long>(timeoutMs)) == 1;
+}
+
+WXD_EXPORTED bool wxd_EventLoop_Yield(bool onlyIfNeeded, bool safe, wxd_Window_t* enableWindow) {
+    if (safe) {
+        return wxSafeYield(reinterpret_cast<wxWindow*>(enableWindow), onlyIfNeeded);
+    }
+    wxEventLoopBase* loop = wxEventLoopBase::GetActive();
+    if (!loop) return false;
+    return loop->Yield(onlyIfNeeded);
+}
+
+WXD_EXPORTED bool wxd_EventLoop_IsYielding() {
+    wxEventLoopBase* loop = wxEventLoopBase::GetActive();
+    return loop && loop->IsYielding();
+}
+
+WXD_EXPORTED void wxd_EventLoop_ProcessPendingEvents() {
+    if (wxTheApp) {
+        wxTheApp->ProcessPendingEvents();
+    }
+}
+
+WXD_EXPORTED int wxd_EventLoop_RunUntil(wxd_EventLoopPredicate predicate, void* userData, int pollIntervalMs) {
+    if (!predicate) return -1;
+    if (predicate(userData)) return 0;
+
+    WxdPredicateEventLoop loop(predicate, userData);
+    WxdPredicatePollTimer timer(&loop);
+    if (pollIntervalMs > 0) {
+        timer.Start(pollIntervalMs);
+    }
+
+    // Run() activates the loop for its duration and restores the outer loop afterwards
+    int exitCode = loop.Run();
+    timer.Stop();
+    return exitCode;
+}
+
+} // extern "C"
@@ -0,0 +1,154 @@
+#include <wx/wxprec.h>
+#include <wx/wx.h>
+#include "../../include/wxdragon.h"
+#include "../../include/core/wxd_filehistory.h"
+#include "../wxd_utils.h"
+#include <wx/filehistory.h>
+#include <wx/config.h>
+#include <wx/weakref.h>
+#include <vector>
+
+namespace {
+
+// File history that stops updating menus once they are destroyed
+class WxdFileHistory : public wxFileHistory {
+public:
+    WxdFileHistory(size_t maxFiles, wxWindowID idBase) : wxFileHistory(maxFiles, idBase) {}
+
+    void TrackMenu(wxMenu* menu) {
+        PruneMenus();
+        for (const auto& tracked : m_menus) {
+            if (tracked.raw == menu) return;
+        }
+        UseMenu(menu);
+        AddFilesToMenu(menu);
+        m_menus.push_back(TrackedMenu{wxWeakRef<wxMenu>(menu), menu});
+    }
+
+    void UntrackMenu(wxMenu* menu) {
+        RemoveMenu(menu);
+        for (auto it = m_menus.begin(); it != m_menus.end(); ++it) {
+            if (it->raw == menu) {
+                m_menus.erase(it);
+                break;
+            }
+        }
+    }
+
+    // Forget destroyed menus; RemoveMenu only compares the pointer
+    void PruneMenus() {
+        for (auto it = m_menus.begin(); it != m_menus.end();) {
+            if (!it->menu) {
+                RemoveMenu(it->raw);
+                it = m_menus.erase(it);
+            } else {
+                ++it;
+            }
+        }
+    }
+
+private:
+    struct TrackedMenu {
+        wxWeakRef<wxMenu> menu;
+        wxMenu* raw;
+    };
+
+    std::vector<TrackedMenu> m_menus;
+};
+
+WxdFileHistory* AsHistory(wxd_FileHistory_t* self) {
+    return reinterpret_cast<WxdFileHistory*>(self);
+}
+
+// Absolute config path for a group name
+wxString GroupPath(const char* group) {
+    wxString path = WXD_STR_TO_WX_STRING_UTF8_NULL_OK(group);
+    if (!path.StartsWith("/")) path.Prepend("/");
+    return path;
+}
+
+} // namespace
+
+extern "C" {
+
+wxd_FileHistory_t* wxd_FileHistory_Create(int maxFiles, wxd_Id idBase) {
+    if (maxFiles < 1) maxFiles = 1;
+    return reinterpret_cast<wxd_FileHistory_t*>(new WxdFileHistory((size_t)maxFiles, idBase));
+}
+
+void wxd_FileHistory_Destroy(wxd_FileHistory_t* self) {
+    delete AsHistory(self);
+}
+
+void wxd_FileHistory_AddFile(wxd_FileHistory_t* self, const char* path) {
+    if (!self || !path) return;
+    AsHistory(self)->PruneMenus();
+    AsHistory(self)->AddFileToHistory(WXD_STR_TO_WX_STRING_UTF8_NULL_OK(path));
+}
+
+void wxd_FileHistory_RemoveFile(wxd_FileHistory_t* self, int index) {
+    if (!self || index < 0 || (size_t)index >= AsHistory(self)->GetCount()) return;
+    AsHistory(self)->PruneMenus();
+    AsHistory(self)->RemoveFileFromHistory((size_t)index);
+}
+
+void wxd_FileHistory_Clear(wxd_FileHistory_t* self) {
+    if (!self) return;
+    WxdFileHistory* history = AsHistory(self);
+    history->PruneMenus();
+    while (history->GetCount() > 0) {
+        history->RemoveFileFromHistory(0);
+    }
+}
+
+int wxd_FileHistory_GetCount(wxd_FileHistory_t* self) {
+    return self ? (int)AsHistory(self)->GetCount() : 0;
+}
+
+int wxd_FileHistory_GetMaxFiles(wxd_FileHistory_t* self) {
+    return self ? (int)AsHistory(self)->GetMaxFiles() : 0;
+}
+
+wxd_Id wxd_FileHistory_GetBaseId(wxd_FileHistory_t* self) {
+    return self ? AsHistory(self)->GetBaseId() : wxID_ANY;
+}
+
+char* wxd_FileHistory_GetFile(wxd_FileHistory_t* self, int index) {
+    if (!self || index < 0 || (size_t)index >= AsHistory(self)->GetCount()) return nullptr;
+    wxString path = AsHistory(self)->GetHistoryFile((size_t)index);
+    return strdup(path.ToUTF8().data());
+}
+
+void wxd_FileHistory_UseMenu(wxd_FileHistory_t* self, wxd_Menu_t* menu) {
+    if (!self || !menu) return;
+    AsHistory(self)->TrackMenu(reinterpret_cast<wxMenu*>(menu));
+}
+
+void wxd_FileHistory_RemoveMenu(wxd_FileHistory_t* self, wxd_Menu_t* menu) {
+    if (!self || !menu) return;
+    AsHistory(self)->UntrackMenu(reinterpret_cast<wxMenu*>(menu));
+}
+
+bool wxd_FileHistory_Load(wxd_FileHistory_t* self, const char* group) {
+    wxConfigBase* config = wxConfigBase::Get();
+    if (!self || !config) return false;
+    WxdFileHistory* history = AsHistory(self);
+    history->PruneMenus();
+    const wxString oldPath = config->GetPath();
+    config->SetPath(GroupPath(group));
+    history->Load(*config);
+    config->SetPath(oldPath);
+    return true;
+}
+
+bool wxd_FileHistory_Save(wxd_FileHistory_t* self, const char* group) {
+    wxConfigBase* config = wxConfigBase::Get();
+    if (!self || !config) return false;
+    const wxString oldPath = config->GetPath();
+    config->SetPath(GroupPath(group));
+    AsHistory(self)->Save(*config);
+    config->SetPath(oldPath);
+    return config->Flush();
+}
+
+} // extern "C"
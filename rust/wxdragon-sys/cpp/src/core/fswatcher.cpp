@@ -0,0 +1,284 @@
+#include <wx/wxprec.h>
+#include <wx/wx.h>
+#include "../../include/wxdragon.h"
+#include "../../include/core/wxd_fswatcher.h"
+#include "../wxd_utils.h"
+#include <wx/fswatcher.h>
+#include <wx/filename.h>
+#include <wx/evtloop.h>
+#include <algorithm>
+#include <map>
+#include <memory>
+#include <vector>
+
+#if wxUSE_FSWATCHER
+
+namespace {
+
+char* ToCString(const wxString& value) {
+    return strdup(value.ToUTF8().data());
+}
+
+wxFileName ToFileName(const char* path) {
+    wxString text = WXD_STR_TO_WX_STRING_UTF8_NULL_OK(path);
+    wxFileName name = wxDirExists(text) ? wxFileName::DirName(text) : wxFileName::FileName(text);
+    name.MakeAbsolute();
+    return name;
+}
+
+wxString FilespecKey(const wxFileName& dir) {
+    wxString key = dir.GetPath();
+    if (!wxFileName::IsCaseSensitive()) key.MakeLower();
+    return key;
+}
+
+// Owns a wxFileSystemWatcher and forwards its events to itself, where Rust
+// handlers are bound. The watcher needs a running event loop, so paths added
+// before it starts are watched from the loop's first iteration on.
+class WxdFileSystemWatcher : public wxEvtHandler {
+public:
+    ~WxdFileSystemWatcher() override {
+        delete m_watcher;
+    }
+
+    bool Add(const wxFileName& path, int events, const wxString& filespec, bool tree) {
+        if (m_destroyed) return false;
+        if (!tree && path.IsDir()) {
+            if (filespec.empty()) {
+                m_filespecs.erase(FilespecKey(path));
+            } else {
+                m_filespecs[FilespecKey(path)] = filespec;
+            }
+        }
+        if (!EnsureWatcher()) {
+            m_pending.push_back(Watch{path, events, filespec, tree});
+            return true;
+        }
+        return DoAdd(Watch{path, events, filespec, tree});
+    }
+
+    bool Remove(const wxFileName& path, bool tree) {
+        // Filespecs belong to directory watches; a file's key is its parent directory
+        if (!tree && path.IsDir()) m_filespecs.erase(FilespecKey(path));
+        if (!m_watcher) {
+            size_t before = m_pending.size();
+            m_pending.erase(std::remove_if(m_pending.begin(), m_pending.end(),
+                                           [&](const Watch& watch) { return watch.path == path; }),
+                            m_pending.end());
+            return m_pending.size() != before;
+        }
+        return tree ? m_watcher->RemoveTree(path) : m_watcher->Remove(path);
+    }
+
+    bool RemoveAll() {
+        m_filespecs.clear();
+        m_pending.clear();
+        return !m_watcher || m_watcher->RemoveAll();
+    }
+
+    void GetWatchedPaths(wxArrayString* out) {
+        if (m_watcher) {
+            m_watcher->GetWatchedPaths(out);
+            return;
+        }
+        for (const Watch& watch : m_pending) out->Add(watch.path.GetFullPath());
+    }
+
+    void Destroy() {
+        // May be called from one of our own handlers, so never delete right away
+        m_destroyed = true;
+        m_pending.clear();
+        if (m_watcher) m_watcher->RemoveAll();
+        if (wxTheApp) {
+            wxTheApp->ScheduleForDestruction(this);
+        } else {
+            delete this;
+        }
+    }
+
+private:
+    struct Watch {
+        wxFileName path;
+        int events;
+        wxString filespec;
+        bool tree;
+    };
+
+    bool EnsureWatcher() {
+        if (m_watcher) return true;
+        if (!wxEventLoopBase::GetActive()) {
+            if (!m_createScheduled) {
+                m_createScheduled = true;
+                CallAfter(&WxdFileSystemWatcher::CreateWatcher);
+            }
+            return false;
+        }
+        CreateWatcher();
+        return m_watcher != nullptr;
+    }
+
+    void CreateWatcher() {
+        if (m_watcher || m_destroyed) return;
+        m_watcher = new wxFileSystemWatcher();
+        m_watcher->Bind(wxEVT_FSWATCHER, &WxdFileSystemWatcher::OnWatcherEvent, this);
+        std::vector<Watch> pending;
+        pending.swap(m_pending);
+        for (const Watch& watch : pending) DoAdd(watch);
+    }
+
+    bool DoAdd(const Watch& watch) {
+        if (watch.tree) return m_watcher->AddTree(watch.path, watch.events, watch.filespec);
+        return m_watcher->Add(watch.path, watch.events);
+    }
+
+    // wxFileSystemWatcher::Add() has no filespec, so filter its events here
+    bool Matches(const wxFileName& path) const {
+        auto it = m_filespecs.find(FilespecKey(path));
+        if (it == m_filespecs.end()) return true;
+        wxString spec = it->second;
+        wxString name = path.GetFullName();
+        if (!wxFileName::IsCaseSensitive()) {
+            spec.MakeLower();
+            name.MakeLower();
+        }
+        return wxMatchWild(spec, name, false);
+    }
+
+    void OnWatcherEvent(wxFileSystemWatcherEvent& event) {
+        if (m_destroyed) return;
+        int type = event.GetChangeType();
+        if (!(type & (wxFSW_EVENT_WARNING | wxFSW_EVENT_ERROR)) && !m_filespecs.empty()) {
+            bool matches = Matches(event.GetPath());
+            if (type & wxFSW_EVENT_RENAME) matches = matches || Matches(event.GetNewPath());
+            if (!matches) return;
+        }
+        std::unique_ptr<wxEvent> copy(event.Clone());
+        copy->SetEventObject(this);
+        ProcessEvent(*copy);
+    }
+
+    wxFileSystemWatcher* m_watcher = nullptr;
+    std::vector<Watch> m_pending;
+    // Filespecs of directories added without their subdirectories
+    std::map<wxString, wxString> m_filespecs;
+    bool m_createScheduled = false;
+    bool m_destroyed = false;
+};
+
+WxdFileSystemWatcher* AsWatcher(wxd_FileSystemWatcher_t* self) {
+    return reinterpret_cast<WxdFileSystemWatcher*>(self);
+}
+
+wxFileSystemWatcherEvent* AsWatcherEvent(wxd_Event_t* event) {
+    return dynamic_cast<wxFileSystemWatcherEvent*>(reinterpret_cast<wxEvent*>(event));
+}
+
+} // namespace
+
+extern "C" {
+
+wxd_FileSystemWatcher_t* wxd_FileSystemWatcher_Create(void) {
+    return reinterpret_cast<wxd_FileSystemWatcher_t*>(new WxdFileSystemWatcher());
+}
+
+void wxd_FileSystemWatcher_Destroy(wxd_FileSystemWatcher_t* self) {
+    if (self) AsWatcher(self)->Destroy();
+}
+
+wxd_EvtHandler_t* wxd_FileSystemWatcher_GetEvtHandler(wxd_FileSystemWatcher_t* self) {
+    if (!self) return nullptr;
+    return reinterpret_cast<wxd_EvtHandler_t*>(static_cast<wxEvtHandler*>(AsWatcher(self)));
+}
+
+bool wxd_FileSystemWatcher_Add(wxd_FileSystemWatcher_t* self, const char* path, int events, const char* filespec) {
+    if (!self || !path) return false;
+    return AsWatcher(self)->Add(ToFileName(path), events, WXD_STR_TO_WX_STRING_UTF8_NULL_OK(filespec), false);
+}
+
+bool wxd_FileSystemWatcher_AddTree(wxd_FileSystemWatcher_t* self, const char* path, int events, const char* filespec) {
+    if (!self || !path) return false;
+    wxFileName dir = wxFileName::DirName(WXD_STR_TO_WX_STRING_UTF8_NULL_OK(path));
+    dir.MakeAbsolute();
+    return AsWatcher(self)->Add(dir, events, WXD_STR_TO_WX_STRING_UTF8_NULL_OK(filespec), true);
+}
+
+bool wxd_FileSystemWatcher_Remove(wxd_FileSystemWatcher_t* self, const char* path) {
+    if (!self || !path) return false;
+    return AsWatcher(self)->Remove(ToFileName(path), false);
+}
+
+bool wxd_FileSystemWatcher_RemoveTree(wxd_FileSystemWatcher_t* self, const char* path) {
+    if (!self || !path) return false;
+    wxFileName dir = wxFileName::DirName(WXD_STR_TO_WX_STRING_UTF8_NULL_OK(path));
+    dir.MakeAbsolute();
+    return AsWatcher(self)->Remove(dir, true);
+}
+
+bool wxd_FileSystemWatcher_RemoveAll(wxd_FileSystemWatcher_t* self) {
+    return self && AsWatcher(self)->RemoveAll();
+}
+
+void wxd_FileSystemWatcher_GetWatchedPaths(wxd_FileSystemWatcher_t* self, wxd_ArrayString_t* out) {
+    if (!self || !out || !out->internal_data) return;
+    AsWatcher(self)->GetWatchedPaths(static_cast<wxArrayString*>(out->internal_data));
+}
+
+int wxd_FileSystemWatcherEvent_GetChangeType(wxd_Event_t* event) {
+    wxFileSystemWatcherEvent* fs_event = AsWatcherEvent(event);
+    return fs_event ? fs_event->GetChangeType() : 0;
+}
+
+char* wxd_FileSystemWatcherEvent_GetPath(wxd_Event_t* event) {
+    wxFileSystemWatcherEvent* fs_event = AsWatcherEvent(event);
+    return fs_event ? ToCString(fs_event->GetPath().GetFullPath()) : nullptr;
+}
+
+char* wxd_FileSystemWatcherEvent_GetNewPath(wxd_Event_t* event) {
+    wxFileSystemWatcherEvent* fs_event = AsWatcherEvent(event);
+    return fs_event ? ToCString(fs_event->GetNewPath().GetFullPath()) : nullptr;
+}
+
+wxd_FswWarningType wxd_FileSystemWatcherEvent_GetWarningType(wxd_Event_t* event) {
+    wxFileSystemWatcherEvent* fs_event = AsWatcherEvent(event);
+    return fs_event ? static_cast<wxd_FswWarningType>(fs_event->GetWarningType()) : WXD_FSW_WARNING_NONE;
+}
+
+char* wxd_FileSystemWatcherEvent_GetErrorDescription(wxd_Event_t* event) {
+    wxFileSystemWatcherEvent* fs_event = AsWatcherEvent(event);
+    return fs_event ? ToCString(fs_event->GetErrorDescription()) : nullptr;
+}
+
+} // extern "C"
+
+#else // !wxUSE_FSWATCHER
+
+// wxWidgets was built without wxFileSystemWatcher: watchers exist but never watch anything
+extern "C" {
+
+wxd_FileSystemWatcher_t* wxd_FileSystemWatcher_Create(void) {
+    return reinterpret_cast<wxd_FileSystemWatcher_t*>(new wxEvtHandler());
+}
+
+void wxd_FileSystemWatcher_Destroy(wxd_FileSystemWatcher_t* self) {
+    delete reinterpret_cast<wxEvtHandler*>(self);
+}
+
+wxd_EvtHandler_t* wxd_FileSystemWatcher_GetEvtHandler(wxd_FileSystemWatcher_t* self) {
+    return reinterpret_cast<wxd_EvtHandler_t*>(self);
+}
+
+bool wxd_FileSystemWatcher_Add(wxd_FileSystemWatcher_t*, const char*, int, const char*) { return false; }
+bool wxd_FileSystemWatcher_AddTree(wxd_FileSystemWatcher_t*, const char*, int, const char*) { return false; }
+bool wxd_FileSystemWatcher_Remove(wxd_FileSystemWatcher_t*, const char*) { return false; }
+bool wxd_FileSystemWatcher_RemoveTree(wxd_FileSystemWatcher_t*, const char*) { return false; }
+bool wxd_FileSystemWatcher_RemoveAll(wxd_FileSystemWatcher_t*) { return false; }
+void wxd_FileSystemWatcher_GetWatchedPaths(wxd_FileSystemWatcher_t*, wxd_ArrayString_t*) {}
+int wxd_FileSystemWatcherEvent_GetChangeType(wxd_Event_t*) { return 0; }
+char* wxd_FileSystemWatcherEvent_GetPath(wxd_Event_t*) { return nullptr; }
+char* wxd_FileSystemWatcherEvent_GetNewPath(wxd_Event_t*) { return nullptr; }
+wxd_FswWarningType wxd_FileSystemWatcherEvent_GetWarningType(wxd_Event_t*) { return WXD_FSW_WARNING_NONE; }
+char* wxd_FileSystemWatcherEvent_GetErrorDescription(wxd_Event_t*) { return nullptr; }
+
+} // extern "C"
+
+#endif // wxUSE_FSWATCHER
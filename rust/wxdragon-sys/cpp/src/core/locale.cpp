@@ -0,0 +1,204 @@
+#include <wx/wxprec.h>
+#include <wx/wx.h>
+#include "../../include/wxdragon.h"
+#include "../../include/core/wxd_locale.h"
+#include "../wxd_utils.h"
+#include <wx/uilocale.h>
+#include <wx/intl.h>
+#include <wx/translation.h>
+#include <wx/numformatter.h>
+#include <wx/datetime.h>
+#include <map>
+#include <string>
+#include <utility>
+
+namespace {
+
+char* ToCString(const wxString& value) {
+    return strdup(value.ToUTF8().data());
+}
+
+wxString ToWxString(const char* text) {
+    return WXD_STR_TO_WX_STRING_UTF8_NULL_OK(text);
+}
+
+// .mo data added from memory, by domain and language
+std::map<std::pair<wxString, wxString>, std::string>& MemoryCatalogs() {
+    static std::map<std::pair<wxString, wxString>, std::string> catalogs;
+    return catalogs;
+}
+
+// Looks in the in-memory catalogs first, then in the catalog lookup paths
+class WxdTranslationsLoader : public wxFileTranslationsLoader {
+public:
+    wxMsgCatalog* LoadCatalog(const wxString& domain, const wxString& lang) override {
+        const auto& catalogs = MemoryCatalogs();
+        auto it = catalogs.find(std::make_pair(domain, lang));
+        if (it != catalogs.end()) {
+            const std::string& data = it->second;
+            return wxMsgCatalog::CreateFromData(
+                wxScopedCharBuffer::CreateNonOwned(data.data(), data.size()), domain);
+        }
+        return wxFileTranslationsLoader::LoadCatalog(domain, lang);
+    }
+
+    wxArrayString GetAvailableTranslations(const wxString& domain) const override {
+        wxArrayString langs = wxFileTranslationsLoader::GetAvailableTranslations(domain);
+        for (const auto& entry : MemoryCatalogs()) {
+            if (entry.first.first == domain && langs.Index(entry.first.second) == wxNOT_FOUND) {
+                langs.Add(entry.first.second);
+            }
+        }
+        return langs;
+    }
+};
+
+// The current translations object, given our loader. A wxTranslations set
+// elsewhere (e.g. by wxLocale) has its loader replaced the first time it is seen;
+// ours falls back to the same file lookup as the default one.
+wxTranslations* GetTranslations() {
+    static wxTranslations* withLoader = nullptr;
+    wxTranslations* translations = wxTranslations::Get();
+    if (!translations) {
+        translations = new wxTranslations;
+        wxTranslations::Set(translations);
+    }
+    if (translations != withLoader) {
+        translations->SetLoader(new WxdTranslationsLoader);
+        withLoader = translations;
+    }
+    return translations;
+}
+
+wxLocaleInfo InfoForFormat(wxd_LocaleDateFormat format) {
+    switch (format) {
+        case WXD_LOCALE_DATE_SHORT: return wxLOCALE_SHORT_DATE_FMT;
+        case WXD_LOCALE_DATE_LONG: return wxLOCALE_LONG_DATE_FMT;
+        case WXD_LOCALE_TIME: return wxLOCALE_TIME_FMT;
+        case WXD_LOCALE_DATE_TIME: return wxLOCALE_DATE_TIME_FMT;
+    }
+    return wxLOCALE_SHORT_DATE_FMT;
+}
+
+} // namespace
+
+extern "C" {
+
+// --- wxUILocale ---
+
+bool wxd_UILocale_UseDefault(void) {
+    return wxUILocale::UseDefault();
+}
+
+char* wxd_UILocale_GetName(void) {
+    return ToCString(wxUILocale::GetCurrent().GetName());
+}
+
+char* wxd_UILocale_GetSystemLanguage(void) {
+    return ToCString(wxUILocale::GetLanguageCanonicalName(wxUILocale::GetSystemLanguage()));
+}
+
+wxd_LayoutDirection wxd_UILocale_GetLayoutDirection(void) {
+    return static_cast<wxd_LayoutDirection>(wxUILocale::GetCurrent().GetLayoutDirection());
+}
+
+char* wxd_UILocale_GetDecimalSeparator(void) {
+    return ToCString(wxUILocale::GetCurrent().GetInfo(wxLOCALE_DECIMAL_POINT, wxLOCALE_CAT_NUMBER));
+}
+
+char* wxd_UILocale_GetThousandsSeparator(void) {
+    return ToCString(wxUILocale::GetCurrent().GetInfo(wxLOCALE_THOUSANDS_SEP, wxLOCALE_CAT_NUMBER));
+}
+
+char* wxd_UILocale_FormatNumber(double value, int precision, bool groupThousands) {
+    int style = groupThousands ? wxNumberFormatter::Style_WithThousandsSep : wxNumberFormatter::Style_None;
+    if (precision < 0) {
+        // Enough digits for any double, without the noise past them
+        precision = 15;
+        style |= wxNumberFormatter::Style_NoTrailingZeroes;
+    }
+    return ToCString(wxNumberFormatter::ToString(value, precision, style));
+}
+
+char* wxd_UILocale_FormatInteger(int64_t value, bool groupThousands) {
+    int style = groupThousands ? wxNumberFormatter::Style_WithThousandsSep : wxNumberFormatter::Style_None;
+    return ToCString(wxNumberFormatter::ToString(static_cast<wxLongLong_t>(value), style));
+}
+
+bool wxd_UILocale_ParseNumber(const char* text, double* value) {
+    if (!text || !value) return false;
+    return wxNumberFormatter::FromString(ToWxString(text), value);
+}
+
+char* wxd_UILocale_FormatDateTime(const wxd_DateTime_t* dt, wxd_LocaleDateFormat format) {
+    if (!dt) return nullptr;
+    wxDateTime value(static_cast<wxDateTime::wxDateTime_t>(dt->day),
+                     static_cast<wxDateTime::Month>(dt->month),
+                     dt->year,
+                     static_cast<wxDateTime::wxDateTime_t>(dt->hour),
+                     static_cast<wxDateTime::wxDateTime_t>(dt->minute),
+                     static_cast<wxDateTime::wxDateTime_t>(dt->second));
+    if (!value.IsValid()) return nullptr;
+    const wxString pattern = wxUILocale::GetCurrent().GetInfo(InfoForFormat(format), wxLOCALE_CAT_DATE);
+    return ToCString(value.Format(pattern));
+}
+
+// --- wxTranslations ---
+
+void wxd_Translations_SetLanguage(const char* language) {
+    wxString lang = ToWxString(language);
+    if (lang.empty()) {
+        GetTranslations()->SetLanguage(wxLANGUAGE_DEFAULT);
+    } else {
+        GetTranslations()->SetLanguage(lang);
+    }
+}
+
+void wxd_Translations_AddCatalogLookupPath(const char* prefix) {
+    wxFileTranslationsLoader::AddCatalogLookupPathPrefix(ToWxString(prefix));
+}
+
+bool wxd_Translations_AddCatalogData(const char* domain, const char* language, const unsigned char* data, size_t len) {
+    if (!data || len == 0) return false;
+    const char* bytes = reinterpret_cast<const char*>(data);
+
+    // Reject anything that does not parse as a catalog now rather than when it is loaded
+    wxMsgCatalog* check = wxMsgCatalog::CreateFromData(wxScopedCharBuffer::CreateNonOwned(bytes, len), ToWxString(domain));
+    if (!check) return false;
+    delete check;
+
+    MemoryCatalogs()[std::make_pair(ToWxString(domain), ToWxString(language))] = std::string(bytes, len);
+    GetTranslations();
+    return true;
+}
+
+bool wxd_Translations_AddCatalog(const char* domain) {
+    return GetTranslations()->AddCatalog(ToWxString(domain));
+}
+
+bool wxd_Translations_AddStdCatalog(void) {
+    return GetTranslations()->AddStdCatalog();
+}
+
+bool wxd_Translations_IsLoaded(const char* domain) {
+    wxTranslations* translations = wxTranslations::Get();
+    return translations && translations->IsLoaded(ToWxString(domain));
+}
+
+void wxd_Translations_GetAvailableLanguages(const char* domain, wxd_ArrayString_t* out) {
+    if (!out || !out->internal_data) return;
+    wxArrayString* langs = static_cast<wxArrayString*>(out->internal_data);
+    for (const wxString& lang : GetTranslations()->GetAvailableTranslations(ToWxString(domain))) {
+        langs->Add(lang);
+    }
+}
+
+char* wxd_Translations_Translate(const char* text, const char* domain) {
+    return ToCString(wxGetTranslation(ToWxString(text), ToWxString(domain)));
+}
+
+char* wxd_Translations_TranslatePlural(const char* singular, const char* plural, unsigned int n, const char* domain) {
+    return ToCString(wxGetTranslation(ToWxString(singular), ToWxString(plural), n, ToWxString(domain)));
+}
+
+} // extern "C"
@@ -0,0 +1,141 @@
+#include <wx/wxprec.h>
+#include <wx/wx.h>
+#include "../../include/wxdragon.h"
+#include "../../include/core/wxd_log.h"
+#include "../wxd_utils.h"
+#include <wx/log.h>
+#include <wx/textctrl.h>
+#include <wx/weakref.h>
+
+namespace {
+
+wxd_LogLevel ToWxdLevel(wxLogLevel level) {
+    // Custom levels above wxLOG_Progress are treated as tracing
+    if (level > wxLOG_Progress) return WXD_LOG_TRACE;
+    return static_cast<wxd_LogLevel>(level);
+}
+
+// Hands every message to a Rust callback
+class WxdLogCallback : public wxLog {
+public:
+    WxdLogCallback(wxd_LogCallback callback, void* userData)
+        : m_callback(callback), m_userData(userData) {}
+
+protected:
+    void DoLogRecord(wxLogLevel level, const wxString& msg, const wxLogRecordInfo& info) override {
+        if (!m_callback) return;
+        m_callback(m_userData, ToWxdLevel(level), msg.utf8_str(), info.component, info.filename, info.line);
+    }
+
+private:
+    wxd_LogCallback m_callback;
+    void* m_userData;
+};
+
+// Like wxLogTextCtrl, but safe to outlive the control
+class WxdLogTextCtrl : public wxLog {
+public:
+    explicit WxdLogTextCtrl(wxTextCtrl* text) : m_text(text) {}
+
+protected:
+    void DoLogText(const wxString& msg) override {
+        if (m_text) m_text->AppendText(msg + wxS('\n'));
+    }
+
+private:
+    wxWeakRef<wxTextCtrl> m_text;
+};
+
+wxLogWindow* g_logWindow = nullptr;
+
+class WxdLogWindow : public wxLogWindow {
+public:
+    WxdLogWindow(wxWindow* parent, const wxString& title, bool show)
+        : wxLogWindow(parent, title, show, false) {
+        g_logWindow = this;
+    }
+
+    ~WxdLogWindow() override {
+        if (g_logWindow == this) g_logWindow = nullptr;
+    }
+};
+
+// Delete the active target and any targets it chains to. A chain (such as a
+// log window) reactivates its predecessor when deleted, hence the loop.
+void DeleteTargets() {
+    while (wxLog* old = wxLog::SetActiveTarget(nullptr)) {
+        delete old;
+    }
+}
+
+void ReplaceTarget(wxLog* target) {
+    DeleteTargets();
+    wxLog::SetActiveTarget(target);
+}
+
+} // namespace
+
+extern "C" {
+
+void wxd_Log_SetDefaultTarget(void) {
+    DeleteTargets();
+}
+
+void wxd_Log_SetStderrTarget(void) {
+    ReplaceTarget(new wxLogStderr());
+}
+
+void wxd_Log_SetCallbackTarget(wxd_LogCallback callback, void* userData) {
+    ReplaceTarget(new WxdLogCallback(callback, userData));
+}
+
+bool wxd_Log_SetTextCtrlTarget(wxd_Window_t* textCtrl) {
+    wxTextCtrl* text = wxDynamicCast(reinterpret_cast<wxWindow*>(textCtrl), wxTextCtrl);
+    if (!text) return false;
+    ReplaceTarget(new WxdLogTextCtrl(text));
+    return true;
+}
+
+void wxd_Log_SetWindowTarget(wxd_Window_t* parent, const char* title, bool show) {
+    DeleteTargets();
+    // Makes itself the active target
+    new WxdLogWindow(reinterpret_cast<wxWindow*>(parent), WXD_STR_TO_WX_STRING_UTF8_NULL_OK(title), show);
+}
+
+bool wxd_Log_ShowWindow(bool show) {
+    if (!g_logWindow) return false;
+    g_logWindow->Show(show);
+    return true;
+}
+
+void wxd_Log_Message(wxd_LogLevel level, const char* message) {
+    wxLogGeneric(static_cast<wxLogLevel>(level), "%s", WXD_STR_TO_WX_STRING_UTF8_NULL_OK(message));
+}
+
+void wxd_Log_Flush(void) {
+    wxLog::FlushActive();
+}
+
+void wxd_Log_SetLogLevel(wxd_LogLevel level) {
+    wxLog::SetLogLevel(static_cast<wxLogLevel>(level));
+}
+
+void wxd_Log_SetVerbose(bool verbose) {
+    wxLog::SetVerbose(verbose);
+}
+
+bool wxd_Log_EnableLogging(bool enable) {
+    return wxLog::EnableLogging(enable);
+}
+
+// --- wxLogNull ---
+
+wxd_LogNull_t* wxd_LogNull_Create(void) {
+    return reinterpret_cast<wxd_LogNull_t*>(new wxLogNull());
+}
+
+void wxd_LogNull_Destroy(wxd_LogNull_t* self) {
+    delete reinterpret_cast<wxLogNull*>(self);
+}
+
+} // extern "C"
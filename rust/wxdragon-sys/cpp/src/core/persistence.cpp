@@ -0,0 +1,208 @@
+#include <wx/wxprec.h>
+#include <wx/wx.h>
+#include "../../include/wxdragon.h"
+#include "../../include/core/wxd_persistence.h"
+#include "../wxd_utils.h"
+#include <wx/persist.h>
+#include <wx/persist/toplevel.h>
+#include <wx/persist/bookctrl.h>
+#include <wx/persist/treebook.h>
+#include <wx/persist/dataview.h>
+#include <wx/persist/window.h>
+#include <wx/splitter.h>
+#include <wx/headerctrl.h>
+#include <wx/listctrl.h>
+
+namespace {
+
+wxString JoinInts(const wxArrayInt& values) {
+    wxString joined;
+    for (size_t i = 0; i < values.size(); ++i) {
+        if (i) joined << ',';
+        joined << values[i];
+    }
+    return joined;
+}
+
+bool SplitInts(const wxString& text, wxArrayInt* values) {
+    for (const wxString& part : wxSplit(text, ',', '\0')) {
+        long value;
+        if (!part.ToLong(&value)) return false;
+        values->push_back(static_cast<int>(value));
+    }
+    return true;
+}
+
+// Saves the sash position of a split splitter window
+class WxdPersistentSplitter : public wxPersistentWindow<wxSplitterWindow> {
+public:
+    explicit WxdPersistentSplitter(wxSplitterWindow* splitter)
+        : wxPersistentWindow<wxSplitterWindow>(splitter) {}
+
+    wxString GetKind() const override { return "Splitter"; }
+
+    void Save() const override {
+        const wxSplitterWindow* const splitter = Get();
+        if (splitter->IsSplit()) {
+            SaveValue("SashPosition", splitter->GetSashPosition());
+        }
+    }
+
+    bool Restore() override {
+        int position;
+        if (!RestoreValue("SashPosition", &position)) return false;
+
+        // The splitter usually has no size yet, which would clamp the position,
+        // so apply it once the initial layout is done.
+        wxSplitterWindow* const splitter = Get();
+        splitter->CallAfter([splitter, position]() {
+            if (splitter->IsSplit()) splitter->SetSashPosition(position);
+        });
+        return true;
+    }
+};
+
+// Saves the column widths, and the column order where the platform has one, of a report list
+class WxdPersistentListCtrl : public wxPersistentWindow<wxListCtrl> {
+public:
+    explicit WxdPersistentListCtrl(wxListCtrl* list)
+        : wxPersistentWindow<wxListCtrl>(list) {}
+
+    wxString GetKind() const override { return "ListCtrl"; }
+
+    void Save() const override {
+        const wxListCtrl* const list = Get();
+        const int count = list->GetColumnCount();
+        if (count == 0) return;
+
+        wxArrayInt widths;
+        for (int i = 0; i < count; ++i) {
+            widths.push_back(list->GetColumnWidth(i));
+        }
+        SaveValue("Widths", JoinInts(widths));
+#ifdef wxHAS_LISTCTRL_COLUMN_ORDER
+        SaveValue("Order", JoinInts(list->GetColumnsOrder()));
+#endif
+    }
+
+    bool Restore() override {
+        wxListCtrl* const list = Get();
+        const size_t count = static_cast<size_t>(list->GetColumnCount());
+
+        // Saved state for a different set of columns is ignored
+        wxString text;
+        wxArrayInt widths;
+        if (!RestoreValue("Widths", &text) || !SplitInts(text, &widths) || widths.size() != count) {
+            return false;
+        }
+        for (size_t i = 0; i < count; ++i) {
+            list->SetColumnWidth(static_cast<int>(i), widths[i]);
+        }
+#ifdef wxHAS_LISTCTRL_COLUMN_ORDER
+        wxArrayInt order;
+        if (RestoreValue("Order", &text) && SplitInts(text, &order) && order.size() == count) {
+            list->SetColumnsOrder(order);
+        }
+#endif
+        return true;
+    }
+};
+
+// Adds the column order, where the generic implementation lets the user reorder
+// columns, to the widths, visibility and sorting saved by wxWidgets
+class WxdPersistentDataViewCtrl : public wxPersistentDataViewCtrl {
+public:
+    explicit WxdPersistentDataViewCtrl(wxDataViewCtrl* control)
+        : wxPersistentDataViewCtrl(control) {}
+
+    void Save() const override {
+        wxPersistentDataViewCtrl::Save();
+#ifdef wxHAS_GENERIC_DATAVIEWCTRL
+        const wxHeaderCtrl* const header = Get()->GenericGetHeader();
+        if (header && header->GetColumnCount() > 0) {
+            SaveValue("ColumnsOrder", JoinInts(header->GetColumnsOrder()));
+        }
+#endif
+    }
+
+    bool Restore() override {
+        const bool restored = wxPersistentDataViewCtrl::Restore();
+#ifdef wxHAS_GENERIC_DATAVIEWCTRL
+        wxDataViewCtrl* const control = Get();
+        wxHeaderCtrl* const header = control->GenericGetHeader();
+
+        // Saved state for a different set of columns is ignored
+        wxString text;
+        wxArrayInt order;
+        if (header && RestoreValue("ColumnsOrder", &text) && SplitInts(text, &order) &&
+            order.size() == header->GetColumnCount()) {
+            header->SetColumnsOrder(order);
+            control->Refresh();
+        }
+#endif
+        return restored;
+    }
+};
+
+template <class T>
+bool RegisterAndRestoreAs(wxWindow* window, const wxString& name) {
+    T* const typed = wxDynamicCast(window, T);
+    if (!typed) return false;
+    return wxPersistentRegisterAndRestore(typed, name);
+}
+
+template <class T, class Adapter>
+bool RegisterAndRestoreWith(wxWindow* window, const wxString& name) {
+    T* const typed = wxDynamicCast(window, T);
+    if (!typed) return false;
+    typed->SetName(name);
+    return wxPersistenceManager::Get().RegisterAndRestore(typed, new Adapter(typed));
+}
+
+} // namespace
+
+extern "C" {
+
+bool wxd_Persistence_RegisterAndRestore(wxd_Window_t* window, wxd_PersistentKind kind, const char* name) {
+    wxWindow* const win = reinterpret_cast<wxWindow*>(window);
+    if (!win) return false;
+
+    // Registering twice would assert; the first registration stays in effect
+    if (wxPersistenceManager::Get().Find(win)) return false;
+
+    const wxString wx_name = WXD_STR_TO_WX_STRING_UTF8_NULL_OK(name);
+    switch (kind) {
+        case WXD_PERSIST_TOPLEVEL: return RegisterAndRestoreAs<wxTopLevelWindow>(win, wx_name);
+        case WXD_PERSIST_BOOK: return RegisterAndRestoreAs<wxBookCtrlBase>(win, wx_name);
+        case WXD_PERSIST_TREEBOOK: return RegisterAndRestoreAs<wxTreebook>(win, wx_name);
+        case WXD_PERSIST_DATAVIEW:
+            return RegisterAndRestoreWith<wxDataViewCtrl, WxdPersistentDataViewCtrl>(win, wx_name);
+        case WXD_PERSIST_SPLITTER:
+            return RegisterAndRestoreWith<wxSplitterWindow, WxdPersistentSplitter>(win, wx_name);
+        case WXD_PERSIST_LISTCTRL:
+            return RegisterAndRestoreWith<wxListCtrl, WxdPersistentListCtrl>(win, wx_name);
+    }
+    return false;
+}
+
+void wxd_Persistence_Save(void* object) {
+    if (object && wxPersistenceManager::Get().Find(object)) {
+        wxPersistenceManager::Get().Save(object);
+    }
+}
+
+void wxd_Persistence_Unregister(void* object) {
+    if (object && wxPersistenceManager::Get().Find(object)) {
+        wxPersistenceManager::Get().Unregister(object);
+    }
+}
+
+void wxd_Persistence_DisableRestoring(void) {
+    wxPersistenceManager::Get().DisableRestoring();
+}
+
+void wxd_Persistence_DisableSaving(void) {
+    wxPersistenceManager::Get().DisableSaving();
+}
+
+} // extern "C"
@@ -0,0 +1,249 @@
+#include <wx/wxprec.h>
+#include <wx/wx.h>
+#include "../../include/wxdragon.h"
+#include "../../include/core/wxd_process.h"
+#include "../wxd_utils.h"
+#include <wx/process.h>
+#include <wx/utils.h>
+#include <wx/timer.h>
+#include <string>
+#include <vector>
+
+wxDEFINE_EVENT(wxdEVT_PROCESS_OUTPUT, wxCommandEvent);
+wxDEFINE_EVENT(wxdEVT_PROCESS_ERROR_OUTPUT, wxCommandEvent);
+
+namespace {
+
+// Bytes read from one stream per poll, so a chatty child cannot starve the UI
+const size_t kPollReadLimit = 64 * 1024;
+const int kPollIntervalMs = 50;
+
+wxString LineToString(const std::string& line) {
+    wxString text = wxString::FromUTF8(line.data(), line.size());
+    // Not UTF-8, e.g. a console code page on Windows
+    if (text.empty() && !line.empty()) text = wxString(line.data(), *wxConvCurrent, line.size());
+    if (text.empty() && !line.empty()) text = wxString::From8BitData(line.data(), line.size());
+    return text;
+}
+
+// A wxProcess with redirected streams, polling them for complete lines and
+// sending each one as an event to itself.
+class WxdProcess : public wxProcess {
+public:
+    WxdProcess() {
+        Redirect();
+        m_timer.SetOwner(this);
+        Bind(wxEVT_TIMER, &WxdProcess::OnPoll, this, m_timer.GetId());
+    }
+
+    void Started(long pid) {
+        m_pid = pid;
+        m_running = true;
+        m_timer.Start(kPollIntervalMs);
+    }
+
+    long Pid() const { return m_pid; }
+    bool Running() const { return m_running; }
+
+    // Called when the Rust handle is dropped
+    void Release() {
+        m_released = true;
+        if (!m_running) Destroy();
+    }
+
+    bool Write(const unsigned char* data, size_t len) {
+        wxOutputStream* out = m_running ? GetOutputStream() : nullptr;
+        if (!out) return false;
+        out->Write(data, len);
+        return out->LastWrite() == len;
+    }
+
+    void OnTerminate(int pid, int status) override {
+        m_timer.Stop();
+        if (m_draining) {
+            // An output handler runs a nested loop: the drain it was called from
+            // finishes reading first, then reports the termination
+            m_terminatePending = true;
+            m_exitPid = pid;
+            m_exitStatus = status;
+            return;
+        }
+        Finish(pid, status);
+    }
+
+private:
+    // Sends the remaining output, then the termination event
+    void Finish(int pid, int status) {
+        Drain(SIZE_MAX);
+        Flush(m_out, wxdEVT_PROCESS_OUTPUT);
+        Flush(m_err, wxdEVT_PROCESS_ERROR_OUTPUT);
+        m_running = false;
+
+        wxProcessEvent event(GetId(), pid, status);
+        event.SetEventObject(this);
+        ProcessEvent(event);
+
+        if (m_released) Destroy();
+    }
+
+    void Destroy() {
+        // Possibly called from one of our own event handlers
+        if (wxTheApp) {
+            wxTheApp->ScheduleForDestruction(this);
+        } else {
+            delete this;
+        }
+    }
+
+    void OnPoll(wxTimerEvent&) {
+        Drain(kPollReadLimit);
+    }
+
+    void Drain(size_t limit) {
+        // A handler running a nested event loop must not read the streams twice
+        if (m_draining) return;
+        m_draining = true;
+        Read(GetInputStream(), &wxProcess::IsInputAvailable, m_out, wxdEVT_PROCESS_OUTPUT, limit);
+        Read(GetErrorStream(), &wxProcess::IsErrorAvailable, m_err, wxdEVT_PROCESS_ERROR_OUTPUT, limit);
+        m_draining = false;
+        if (m_terminatePending) {
+            m_terminatePending = false;
+            Finish(m_exitPid, m_exitStatus);
+        }
+    }
+
+    void Read(wxInputStream* in, bool (wxProcess::*available)() const, std::string& pending,
+              const wxEventType& type, size_t limit) {
+        if (!in) return;
+        for (size_t count = 0; count < limit && (this->*available)(); ++count) {
+            int c = in->GetC();
+            if (in->LastRead() == 0) break;
+            if (c == '\n') {
+                if (!pending.empty() && pending.back() == '\r') pending.pop_back();
+                std::string line;
+                line.swap(pending);
+                SendLine(type, line);
+            } else {
+                pending += static_cast<char>(c);
+            }
+        }
+    }
+
+    void Flush(std::string& pending, const wxEventType& type) {
+        if (pending.empty()) return;
+        std::string line;
+        line.swap(pending);
+        SendLine(type, line);
+    }
+
+    void SendLine(const wxEventType& type, const std::string& line) {
+        wxCommandEvent event(type, GetId());
+        event.SetString(LineToString(line));
+        event.SetEventObject(this);
+        ProcessEvent(event);
+    }
+
+    wxTimer m_timer;
+    std::string m_out;
+    std::string m_err;
+    long m_pid = 0;
+    bool m_running = false;
+    bool m_released = false;
+    bool m_draining = false;
+    bool m_terminatePending = false;
+    int m_exitPid = 0;
+    int m_exitStatus = 0;
+};
+
+WxdProcess* AsProcess(wxd_Process_t* self) {
+    return reinterpret_cast<WxdProcess*>(self);
+}
+
+} // namespace
+
+extern "C" {
+
+wxd_Process_t* wxd_Process_Start(const char* const* argv, int argc, const char* cwd,
+                                 const char* const* env_keys, const char* const* env_values,
+                                 int env_count, bool clear_env) {
+    if (!argv || argc <= 0) return nullptr;
+
+    std::vector<std::wstring> args;
+    for (int i = 0; i < argc; ++i) {
+        args.push_back(WXD_STR_TO_WX_STRING_UTF8_NULL_OK(argv[i]).ToStdWstring());
+    }
+    std::vector<const wchar_t*> args_c;
+    for (const std::wstring& arg : args) args_c.push_back(arg.c_str());
+    args_c.push_back(nullptr);
+
+    wxExecuteEnv env;
+    if (cwd && *cwd) env.cwd = wxString::FromUTF8(cwd);
+    // An empty map makes wxExecute inherit the environment, so always fill it
+    if (!clear_env || env_count > 0) {
+        if (!clear_env) wxGetEnvMap(&env.env);
+        for (int i = 0; i < env_count; ++i) {
+            if (!env_keys || !env_keys[i]) continue;
+            wxString key = wxString::FromUTF8(env_keys[i]);
+            if (env_values && env_values[i]) {
+                env.env[key] = wxString::FromUTF8(env_values[i]);
+            } else {
+                env.env.erase(key);
+            }
+        }
+    }
+
+    WxdProcess* process = new WxdProcess();
+    long pid = wxExecute(args_c.data(), wxEXEC_ASYNC | wxEXEC_HIDE_CONSOLE | wxEXEC_MAKE_GROUP_LEADER,
+                         process, &env);
+    if (pid == 0) {
+        delete process;
+        return nullptr;
+    }
+    process->Started(pid);
+    return reinterpret_cast<wxd_Process_t*>(process);
+}
+
+void wxd_Process_Release(wxd_Process_t* self) {
+    if (self) AsProcess(self)->Release();
+}
+
+wxd_EvtHandler_t* wxd_Process_GetEvtHandler(wxd_Process_t* self) {
+    if (!self) return nullptr;
+    return reinterpret_cast<wxd_EvtHandler_t*>(static_cast<wxEvtHandler*>(AsProcess(self)));
+}
+
+long wxd_Process_GetPid(wxd_Process_t* self) {
+    return self ? AsProcess(self)->Pid() : 0;
+}
+
+bool wxd_Process_IsRunning(wxd_Process_t* self) {
+    return self && AsProcess(self)->Running();
+}
+
+bool wxd_Process_WriteStdin(wxd_Process_t* self, const unsigned char* data, size_t len) {
+    if (!self || (!data && len > 0)) return false;
+    return AsProcess(self)->Write(data, len);
+}
+
+void wxd_Process_CloseStdin(wxd_Process_t* self) {
+    if (self) AsProcess(self)->CloseOutput();
+}
+
+wxd_KillError wxd_Process_Kill(wxd_Process_t* self, wxd_Signal signal, bool withChildren) {
+    if (!self || !AsProcess(self)->Running()) return WXD_KILL_NO_PROCESS;
+    int flags = withChildren ? wxKILL_CHILDREN : wxKILL_NOCHILDREN;
+    return static_cast<wxd_KillError>(
+        wxProcess::Kill(static_cast<int>(AsProcess(self)->Pid()), static_cast<wxSignal>(signal), flags));
+}
+
+int wxd_ProcessEvent_GetExitCode(wxd_Event_t* event) {
+    wxProcessEvent* process_event = dynamic_cast<wxProcessEvent*>(reinterpret_cast<wxEvent*>(event));
+    return process_event ? process_event->GetExitCode() : -1;
+}
+
+int wxd_ProcessEvent_GetPid(wxd_Event_t* event) {
+    wxProcessEvent* process_event = dynamic_cast<wxProcessEvent*>(reinterpret_cast<wxEvent*>(event));
+    return process_event ? process_event->GetPid() : 0;
+}
+
+} // extern "C"
@@ -0,0 +1,168 @@
+#include <wx/wxprec.h>
+#include <wx/wx.h>
+#include "../../include/wxdragon.h"
+#include "../../include/core/wxd_single_instance.h"
+#include <wx/snglinst.h>
+#include <wx/utils.h>
+#ifdef __UNIX__
+#include <unistd.h>
+#endif
+#if wxUSE_IPC
+#include <wx/ipc.h>
+#include <set>
+#endif
+
+#if wxUSE_IPC
+namespace {
+
+class WxdIpcServer;
+
+// Connection accepted by WxdIpcServer; forwards executed data to the Rust callback
+class WxdIpcConnection : public wxConnection {
+public:
+    explicit WxdIpcConnection(WxdIpcServer* server) : m_server(server) {}
+
+    virtual bool OnExecute(const wxString& topic, const void* data, size_t size, wxIPCFormat format) override;
+    virtual bool OnDisconnect() override;
+
+    void DetachServer() { m_server = nullptr; }
+
+private:
+    WxdIpcServer* m_server;
+};
+
+class WxdIpcServer : public wxServer {
+public:
+    WxdIpcServer(const wxString& topic, wxd_IpcMessageCallback callback, void* userData)
+        : m_topic(topic), m_callback(callback), m_userData(userData) {}
+
+    virtual ~WxdIpcServer() {
+        // Connections still open outlive the server; make sure they stop calling back
+        for (WxdIpcConnection* connection : m_connections) {
+            connection->DetachServer();
+        }
+    }
+
+    virtual wxConnectionBase* OnAcceptConnection(const wxString& topic) override {
+        if (topic != m_topic) {
+            return nullptr;
+        }
+        // Senders connect, execute once and disconnect
+        WxdIpcConnection* connection = new WxdIpcConnection(this);
+        m_connections.insert(connection);
+        return connection;
+    }
+
+    void Deliver(const void* data, size_t size) {
+        if (m_callback) {
+            m_callback(m_userData, static_cast<const char*>(data), static_cast<int>(size));
+        }
+    }
+
+    void ConnectionClosed(WxdIpcConnection* connection) {
+        m_connections.erase(connection);
+    }
+
+private:
+    wxString m_topic;
+    wxd_IpcMessageCallback m_callback;
+    void* m_userData;
+    std::set<WxdIpcConnection*> m_connections;
+};
+
+bool WxdIpcConnection::OnExecute(const wxString& WXUNUSED(topic), const void* data, size_t size, wxIPCFormat WXUNUSED(format)) {
+    if (m_server) {
+        m_server->Deliver(data, size);
+    }
+    return true;
+}
+
+bool WxdIpcConnection::OnDisconnect() {
+    if (m_server) {
+        m_server->ConnectionClosed(this);
+    }
+    delete this;
+    return true;
+}
+
+} // namespace
+#endif // wxUSE_IPC
+
+extern "C" {
+
+// --- wxSingleInstanceChecker ---
+
+WXD_EXPORTED wxd_SingleInstanceChecker_t* wxd_SingleInstanceChecker_Create(const char* name, const char* path) {
+    if (!name) return nullptr;
+    // Make the lock per-user, as recommended by the wxSingleInstanceChecker docs
+    wxString lockName = wxString::FromUTF8(name) + "-" + wxGetUserId();
+    wxSingleInstanceChecker* checker = new wxSingleInstanceChecker();
+    if (!checker->Create(lockName, path ? wxString::FromUTF8(path) : wxString())) {
+        delete checker;
+        return nullptr;
+    }
+    return reinterpret_cast<wxd_SingleInstanceChecker_t*>(checker);
+}
+
+WXD_EXPORTED unsigned int wxd_SingleInstance_GetUserUid(void) {
+#ifdef __UNIX__
+    return static_cast<unsigned int>(::getuid());
+#else
+    return 0;
+#endif
+}
+
+WXD_EXPORTED void wxd_SingleInstanceChecker_Destroy(wxd_SingleInstanceChecker_t* self) {
+    if (!self) return;
+    delete reinterpret_cast<wxSingleInstanceChecker*>(self);
+}
+
+WXD_EXPORTED bool wxd_SingleInstanceChecker_IsAnotherRunning(wxd_SingleInstanceChecker_t* self) {
+    if (!self) return false;
+    return reinterpret_cast<wxSingleInstanceChecker*>(self)->IsAnotherRunning();
+}
+
+// --- Local IPC ---
+
+WXD_EXPORTED wxd_IpcServer_t* wxd_IpcServer_Create(const char* service, const char* topic, wxd_IpcMessageCallback callback, void* userData) {
+#if wxUSE_IPC
+    if (!service || !topic || !callback) return nullptr;
+    WxdIpcServer* server = new WxdIpcServer(wxString::FromUTF8(topic), callback, userData);
+    if (!server->Create(wxString::FromUTF8(service))) {
+        delete server;
+        return nullptr;
+    }
+    return reinterpret_cast<wxd_IpcServer_t*>(server);
+#else
+    (void)service; (void)topic; (void)callback; (void)userData;
+    return nullptr;
+#endif
+}
+
+WXD_EXPORTED void wxd_IpcServer_Destroy(wxd_IpcServer_t* self) {
+#if wxUSE_IPC
+    if (!self) return;
+    delete reinterpret_cast<WxdIpcServer*>(self);
+#else
+    (void)self;
+#endif
+}
+
+WXD_EXPORTED bool wxd_IpcClient_Execute(const char* service, const char* topic, const char* data, int len) {
+#if wxUSE_IPC
+    if (!service || !topic || (!data && len > 0) || len < 0) return false;
+    wxLogNull noLog; // A missing server is an expected outcome, not an error to report
+    wxClient client;
+    wxConnectionBase* connection = client.MakeConnection("localhost", wxString::FromUTF8(service), wxString::FromUTF8(topic));
+    if (!connection) return false;
+    bool ok = connection->Execute(data, static_cast<size_t>(len), wxIPC_PRIVATE);
+    connection->Disconnect();
+    delete connection;
+    return ok;
+#else
+    (void)service; (void)topic; (void)data; (void)len;
+    return false;
+#endif
+}
+
+} // extern "C"
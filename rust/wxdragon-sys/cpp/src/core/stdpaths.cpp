@@ -0,0 +1,54 @@
+#include <wx/wxprec.h>
+#include <wx/wx.h>
+#include "../../include/wxdragon.h"
+#include "../../include/core/wxd_stdpaths.h"
+#include "../wxd_utils.h"
+#include <wx/stdpaths.h>
+#include <wx/fileconf.h>
+
+namespace {
+
+wxString GetStandardPath(wxd_StandardPath which) {
+    wxStandardPaths& paths = wxStandardPaths::Get();
+    switch (which) {
+        case WXD_STDPATH_USER_CONFIG_DIR: return paths.GetUserConfigDir();
+        case WXD_STDPATH_USER_DATA_DIR: return paths.GetUserDataDir();
+        case WXD_STDPATH_USER_LOCAL_DATA_DIR: return paths.GetUserLocalDataDir();
+        case WXD_STDPATH_CONFIG_DIR: return paths.GetConfigDir();
+        case WXD_STDPATH_DATA_DIR: return paths.GetDataDir();
+        case WXD_STDPATH_LOCAL_DATA_DIR: return paths.GetLocalDataDir();
+        case WXD_STDPATH_RESOURCES_DIR: return paths.GetResourcesDir();
+        case WXD_STDPATH_PLUGINS_DIR: return paths.GetPluginsDir();
+        case WXD_STDPATH_APP_DOCUMENTS_DIR: return paths.GetAppDocumentsDir();
+        case WXD_STDPATH_TEMP_DIR: return paths.GetTempDir();
+        case WXD_STDPATH_EXECUTABLE_PATH: return paths.GetExecutablePath();
+        case WXD_STDPATH_DOCUMENTS_DIR: return paths.GetDocumentsDir();
+        case WXD_STDPATH_DESKTOP_DIR: return paths.GetUserDir(wxStandardPaths::Dir_Desktop);
+        case WXD_STDPATH_DOWNLOADS_DIR: return paths.GetUserDir(wxStandardPaths::Dir_Downloads);
+        case WXD_STDPATH_MUSIC_DIR: return paths.GetUserDir(wxStandardPaths::Dir_Music);
+        case WXD_STDPATH_PICTURES_DIR: return paths.GetUserDir(wxStandardPaths::Dir_Pictures);
+        case WXD_STDPATH_VIDEOS_DIR: return paths.GetUserDir(wxStandardPaths::Dir_Videos);
+        case WXD_STDPATH_CACHE_DIR: return paths.GetUserDir(wxStandardPaths::Dir_Cache);
+    }
+    return wxString();
+}
+
+} // namespace
+
+extern "C" {
+
+char* wxd_StandardPaths_Get(wxd_StandardPath which) {
+    return strdup(GetStandardPath(which).ToUTF8().data());
+}
+
+char* wxd_StandardPaths_GetLocalConfigFile(const char* basename) {
+    wxString path = wxFileConfig::GetLocalFileName(WXD_STR_TO_WX_STRING_UTF8_NULL_OK(basename));
+    return strdup(path.ToUTF8().data());
+}
+
+void wxd_StandardPaths_UseXdgLayout(bool xdg) {
+    wxStandardPaths::Get().SetFileLayout(xdg ? wxStandardPaths::FileLayout_XDG
+                                             : wxStandardPaths::FileLayout_Classic);
+}
+
+} // extern "C"
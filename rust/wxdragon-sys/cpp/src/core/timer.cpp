@@ -4,6 +4,52 @@
 #include "../../include/core/wxd_timer.h"
 #include <wx/timer.h>
 
+namespace {
+
+// Timer without an owner that forwards notifications to a Rust callback
+class WxdCallbackTimer : public wxTimer {
+public:
+    WxdCallbackTimer(wxd_TimerCallback callback, void* userData)
+        : m_callback(callback), m_userData(userData), m_inNotify(false), m_destroyed(false) {}
+
+    virtual void Notify() override {
+        if (!m_callback) return;
+        m_inNotify = true;
+        m_callback(m_userData);
+        m_inNotify = false;
+        if (m_destroyed) {
+            // Destroyed from inside the callback: delete once the timer code has unwound
+            CallAfterDelete();
+        }
+    }
+
+    void Destroy() {
+        Stop();
+        m_callback = nullptr;
+        if (m_inNotify) {
+            m_destroyed = true;
+        } else {
+            delete this;
+        }
+    }
+
+private:
+    void CallAfterDelete() {
+        if (wxTheApp) {
+            wxTheApp->CallAfter([this]() { delete this; });
+        } else {
+            delete this;
+        }
+    }
+
+    wxd_TimerCallback m_callback;
+    void* m_userData;
+    bool m_inNotify;
+    bool m_destroyed;
+};
+
+} // namespace
+
 extern "C" {
 
 // Create a new wxTimer associated with an event handler
@@ -16,10 +62,23 @@ WXD_EXPORTED wxd_Timer_t* wxd_Timer_Create(wxd_EvtHandler_t* owner) {
     return reinterpret_cast<wxd_Timer_t*>(timer);
 }
 
+// Create an owner-less wxTimer that calls back into Rust
+WXD_EXPORTED wxd_Timer_t* wxd_Timer_CreateWithCallback(wxd_TimerCallback callback, void* userData) {
+    if (!callback) {
+        return nullptr;
+    }
+    wxTimer* timer = new WxdCallbackTimer(callback, userData);
+    return reinterpret_cast<wxd_Timer_t*>(timer);
+}
+
 // Destroy/delete a wxTimer
 WXD_EXPORTED void wxd_Timer_Destroy(wxd_Timer_t* self) {
     if (!self) return;
     wxTimer* timer = reinterpret_cast<wxTimer*>(self);
+    if (WxdCallbackTimer* callbackTimer = dynamic_cast<WxdCallbackTimer*>(timer)) {
+        callbackTimer->Destroy();
+        return;
+    }
     if (timer->IsRunning()) {
         timer->Stop();
     }
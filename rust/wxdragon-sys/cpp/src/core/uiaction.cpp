@@ -0,0 +1,123 @@
+#include <wx/wxprec.h>
+#include <wx/wx.h>
+#include "../../include/wxdragon.h"
+#include "../../include/core/wxd_uiaction.h"
+#include "../wxd_utils.h"
+#if wxUSE_UIACTIONSIMULATOR
+#include <wx/uiaction.h>
+#endif
+
+extern "C" {
+
+#if wxUSE_UIACTIONSIMULATOR
+
+static wxUIActionSimulator* as_sim(wxd_UIActionSimulator_t* self) {
+    return reinterpret_cast<wxUIActionSimulator*>(self);
+}
+
+wxd_UIActionSimulator_t* wxd_UIActionSimulator_Create() {
+    return reinterpret_cast<wxd_UIActionSimulator_t*>(new wxUIActionSimulator());
+}
+
+void wxd_UIActionSimulator_Destroy(wxd_UIActionSimulator_t* self) {
+    delete as_sim(self);
+}
+
+bool wxd_UIActionSimulator_MouseMove(wxd_UIActionSimulator_t* self, int x, int y) {
+    if (!self) return false;
+    return as_sim(self)->MouseMove(x, y);
+}
+
+bool wxd_UIActionSimulator_MouseDown(wxd_UIActionSimulator_t* self, int button) {
+    if (!self) return false;
+    return as_sim(self)->MouseDown(button);
+}
+
+bool wxd_UIActionSimulator_MouseUp(wxd_UIActionSimulator_t* self, int button) {
+    if (!self) return false;
+    return as_sim(self)->MouseUp(button);
+}
+
+bool wxd_UIActionSimulator_MouseClick(wxd_UIActionSimulator_t* self, int button) {
+    if (!self) return false;
+    return as_sim(self)->MouseClick(button);
+}
+
+bool wxd_UIActionSimulator_MouseDblClick(wxd_UIActionSimulator_t* self, int button) {
+    if (!self) return false;
+    return as_sim(self)->MouseDblClick(button);
+}
+
+bool wxd_UIActionSimulator_MouseDragDrop(wxd_UIActionSimulator_t* self, int x1, int y1, int x2, int y2, int button) {
+    if (!self) return false;
+    return as_sim(self)->MouseDragDrop(x1, y1, x2, y2, button);
+}
+
+bool wxd_UIActionSimulator_KeyDown(wxd_UIActionSimulator_t* self, int keyCode, int modifiers) {
+    if (!self) return false;
+    return as_sim(self)->KeyDown(keyCode, modifiers);
+}
+
+bool wxd_UIActionSimulator_KeyUp(wxd_UIActionSimulator_t* self, int keyCode, int modifiers) {
+    if (!self) return false;
+    return as_sim(self)->KeyUp(keyCode, modifiers);
+}
+
+bool wxd_UIActionSimulator_Char(wxd_UIActionSimulator_t* self, int keyCode, int modifiers) {
+    if (!self) return false;
+    return as_sim(self)->Char(keyCode, modifiers);
+}
+
+bool wxd_UIActionSimulator_Text(wxd_UIActionSimulator_t* self, const char* text) {
+    if (!self || !text) return false;
+    // Text() takes a narrow string and only supports ASCII, so type other characters one by one
+    wxString str = wxString::FromUTF8(text);
+    if (str.IsAscii()) {
+        return as_sim(self)->Text(str.mb_str());
+    }
+    for (wxString::const_iterator it = str.begin(); it != str.end(); ++it) {
+        if (!as_sim(self)->Char(static_cast<int>(wxUniChar(*it).GetValue()))) return false;
+    }
+    return true;
+}
+
+bool wxd_UIActionSimulator_Select(wxd_UIActionSimulator_t* self, wxd_Window_t* window, const char* text) {
+    if (!self || !window) return false;
+    return as_sim(self)->Select(reinterpret_cast<wxWindow*>(window), WXD_STR_TO_WX_STRING_UTF8_NULL_OK(text));
+}
+
+#else // !wxUSE_UIACTIONSIMULATOR
+
+wxd_UIActionSimulator_t* wxd_UIActionSimulator_Create() { return nullptr; }
+void wxd_UIActionSimulator_Destroy(wxd_UIActionSimulator_t*) {}
+bool wxd_UIActionSimulator_MouseMove(wxd_UIActionSimulator_t*, int, int) { return false; }
+bool wxd_UIActionSimulator_MouseDown(wxd_UIActionSimulator_t*, int) { return false; }
+bool wxd_UIActionSimulator_MouseUp(wxd_UIActionSimulator_t*, int) { return false; }
+bool wxd_UIActionSimulator_MouseClick(wxd_UIActionSimulator_t*, int) { return false; }
+bool wxd_UIActionSimulator_MouseDblClick(wxd_UIActionSimulator_t*, int) { return false; }
+bool wxd_UIActionSimulator_MouseDragDrop(wxd_UIActionSimulator_t*, int, int, int, int, int) { return false; }
+bool wxd_UIActionSimulator_KeyDown(wxd_UIActionSimulator_t*, int, int) { return false; }
+bool wxd_UIActionSimulator_KeyUp(wxd_UIActionSimulator_t*, int, int) { return false; }
+bool wxd_UIActionSimulator_Char(wxd_UIActionSimulator_t*, int, int) { return false; }
+bool wxd_UIActionSimulator_Text(wxd_UIActionSimulator_t*, const char*) { return false; }
+bool wxd_UIActionSimulator_Select(wxd_UIActionSimulator_t*, wxd_Window_t*, const char*) { return false; }
+
+#endif // wxUSE_UIACTIONSIMULATOR
+
+bool wxd_UIActionSimulator_MouseWheel(wxd_Window_t* window, int x, int y, int rotation, bool horizontal) {
+    wxWindow* win = reinterpret_cast<wxWindow*>(window);
+    if (!win) return false;
+
+    wxMouseEvent event(wxEVT_MOUSEWHEEL);
+    event.SetEventObject(win);
+    event.SetId(win->GetId());
+    event.SetPosition(wxPoint(x, y));
+    event.m_wheelRotation = rotation;
+    event.m_wheelDelta = 120; // One notch, as reported by most platforms
+    event.m_linesPerAction = 3;
+    event.m_columnsPerAction = 3;
+    event.m_wheelAxis = horizontal ? wxMOUSE_WHEEL_HORIZONTAL : wxMOUSE_WHEEL_VERTICAL;
+    return win->HandleWindowEvent(event);
+}
+
+} // extern "C"
@@ -2,6 +2,97 @@
 #include <wx/wx.h>
 #include "wxdragon.h"
 #include "wx/dialog.h"
+#include <wx/msgdlg.h>
+#include <wx/filedlg.h>
+#include <wx/dirdlg.h>
+#if wxUSE_COLOURDLG
+#include <wx/colordlg.h>
+#endif
+#if wxUSE_FONTDLG
+#include <wx/fontdlg.h>
+#endif
+
+namespace {
+
+#ifndef __WXOSX__
+// Native common dialogs can only be shown modally
+bool IsNativeModalOnly(wxDialog* dialog) {
+    if (wxDynamicCast(dialog, wxMessageDialogBase)) return true;
+    if (wxDynamicCast(dialog, wxFileDialogBase)) return true;
+    if (wxDynamicCast(dialog, wxDirDialogBase)) return true;
+#if wxUSE_COLOURDLG
+    if (wxDynamicCast(dialog, wxColourDialog)) return true;
+#endif
+#if wxUSE_FONTDLG
+    if (wxDynamicCast(dialog, wxFontDialog)) return true;
+#endif
+    return false;
+}
+
+// Emulates a window-modal dialog without a nested event loop, where wxWidgets would
+// fall back to ShowModal(): the parent is disabled, the dialog shown modelessly and
+// wxEVT_WINDOW_MODAL_DIALOG_CLOSED sent once it is hidden. Deletes itself then, or
+// when the dialog is destroyed while shown.
+class WxdWindowModalFallback : public wxEvtHandler {
+public:
+    explicit WxdWindowModalFallback(wxDialog* dialog)
+        : m_dialog(dialog), m_parent(wxGetTopLevelParent(dialog->GetParent())) {
+        if (m_parent && m_parent->IsEnabled()) {
+            m_parent->Disable();
+        } else {
+            m_parent = nullptr;
+        }
+        m_dialog->Bind(wxEVT_BUTTON, &WxdWindowModalFallback::OnButton, this);
+        m_dialog->Bind(wxEVT_SHOW, &WxdWindowModalFallback::OnShow, this);
+        m_dialog->Bind(wxEVT_DESTROY, &WxdWindowModalFallback::OnDestroy, this);
+        m_dialog->SetReturnCode(wxID_CANCEL);
+        m_dialog->Show();
+    }
+
+private:
+    // A modeless dialog is only hidden by its standard buttons, so remember the code
+    // ShowModal() would have returned
+    void OnButton(wxCommandEvent& event) {
+        const int id = event.GetId();
+        if (id == m_dialog->GetAffirmativeId()) {
+            m_dialog->SetReturnCode(id);
+        } else if (id == m_dialog->GetEscapeId() ||
+                   (id == wxID_CANCEL && m_dialog->GetEscapeId() == wxID_ANY)) {
+            m_dialog->SetReturnCode(wxID_CANCEL);
+        }
+        event.Skip();
+    }
+
+    void OnShow(wxShowEvent& event) {
+        event.Skip();
+        if (event.IsShown()) return;
+        wxDialog* const dialog = m_dialog;
+        Finish();
+        wxWindowModalDialogEvent closed(wxEVT_WINDOW_MODAL_DIALOG_CLOSED, dialog->GetId());
+        closed.SetEventObject(dialog);
+        dialog->GetEventHandler()->ProcessEvent(closed);
+    }
+
+    void OnDestroy(wxWindowDestroyEvent& event) {
+        event.Skip();
+        if (event.GetEventObject() == m_dialog) Finish();
+    }
+
+    void Finish() {
+        m_dialog->Unbind(wxEVT_BUTTON, &WxdWindowModalFallback::OnButton, this);
+        m_dialog->Unbind(wxEVT_SHOW, &WxdWindowModalFallback::OnShow, this);
+        m_dialog->Unbind(wxEVT_DESTROY, &WxdWindowModalFallback::OnDestroy, this);
+        if (m_parent) m_parent->Enable();
+        // Called from one of our own handlers; wxWidgets is done with us after it returns
+        wxTheApp->CallAfter([self = this]() { delete self; });
+    }
+
+    wxDialog* m_dialog;
+    wxWindow* m_parent;
+};
+#endif
+
+} // namespace
 
 extern "C" {
 
@@ -30,7 +121,46 @@ int wxd_Dialog_ShowModal(wxd_Dialog* self) {
 
 void wxd_Dialog_EndModal(wxd_Dialog* self, int retCode) {
     if (!self) return;
-    ((wxDialog*)self)->EndModal(retCode);
+    wxDialog* dialog = (wxDialog*)self;
+    if (!dialog->IsModal()) {
+        // Shown by the window-modal fallback below
+        dialog->SetReturnCode(retCode);
+        dialog->Hide();
+        return;
+    }
+    dialog->EndModal(retCode);
+}
+
+// Shows the dialog from the next event loop iteration, so the caller never runs a
+// nested loop. macOS uses a native sheet. Elsewhere wxWidgets would fall back to
+// ShowModal(), so dialogs are shown modelessly over their disabled parent instead;
+// only native common dialogs, which cannot be shown modelessly, still get a modal
+// loop. Either way wxEVT_WINDOW_MODAL_DIALOG_CLOSED is sent on close.
+void wxd_Dialog_ShowWindowModal(wxd_Dialog* self) {
+    if (!self) return;
+    wxDialog* dialog = (wxDialog*)self;
+    // Pending calls are discarded if the dialog is destroyed first
+    dialog->CallAfter([dialog]() {
+#ifdef __WXOSX__
+        dialog->ShowWindowModal();
+#else
+        if (IsNativeModalOnly(dialog)) {
+            dialog->ShowWindowModal();
+        } else {
+            new WxdWindowModalFallback(dialog);
+        }
+#endif
+    });
+}
+
+int wxd_Dialog_GetReturnCode(wxd_Dialog* self) {
+    if (!self) return wxID_NONE;
+    return ((wxDialog*)self)->GetReturnCode();
+}
+
+bool wxd_Dialog_IsModal(wxd_Dialog* self) {
+    if (!self) return false;
+    return ((wxDialog*)self)->IsModal();
 }
 
 // Note: wxDialog itself is usually not created directly with a simple 'Create' function in this C API.
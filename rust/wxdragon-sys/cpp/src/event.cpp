@@ -6,6 +6,7 @@
 #include <vector>  // For std::vector used in closureMap
 #include <memory> // For std::unique_ptr if we want safer memory management
 #include <tuple>  // For std::pair used in map key
+#include <mutex>  // For guarding the custom event type table
 #include <wx/event.h>
 #include <wx/app.h>
 #include <wx/window.h> // For wxCloseEvent
@@ -45,12 +46,18 @@
 #include <wx/utils.h>
 #include <wx/rearrangectrl.h> // ADDED: For wxEVT_REARRANGE_LIST
 #include <wx/collpane.h> // ADDED: For wxEVT_COLLAPSIBLEPANE_CHANGED
+#include <wx/process.h> // For wxEVT_END_PROCESS
+#include <wx/fswatcher.h> // For wxEVT_FSWATCHER
 #if wxdUSE_RICHTEXT
 #include <wx/richtext/richtextctrl.h> // ADDED: For richtext events
 #endif
 
 struct wxd_Event_t { wxEvent* event; };
 
+// Output line events sent by child processes, defined in core/process.cpp
+wxDECLARE_EVENT(wxdEVT_PROCESS_OUTPUT, wxCommandEvent);
+wxDECLARE_EVENT(wxdEVT_PROCESS_ERROR_OUTPUT, wxCommandEvent);
+
 // --- Internal C++ Structures/Classes (Not exposed in C API) ---
 
 // Define a hash function for std::pair<int, int>
@@ -70,12 +77,70 @@ struct RustClosureInfo {
     // We might need the trampoline pointer here too if it varies,
     // but for now, assume a single global trampoline `rust_event_handler_trampoline`.
     wxd_ClosureCallback rust_trampoline = nullptr; // Store the trampoline func ptr
+    uint64_t binding_id = 0; // Token handed back to Rust for explicit unbinding
+    bool unbound = false; // Set when unbound during dispatch; dropped once dispatch finishes
 };
 
 // Forward declarations
 class WxdEventHandler;
 static wxEventType get_wx_event_type_for_c_enum(WXDEventTypeCEnum c_enum_val);
 
+// Global registry mapping binding ids to the handler that owns the closure.
+// All binding/unbinding happens on the GUI thread, so no locking is needed.
+static std::unordered_map<uint64_t, WxdEventHandler*> g_binding_registry;
+static uint64_t g_next_binding_id = 1;
+
+// --- Custom Event Types ---
+// Event types allocated at runtime with wxNewEventType(). The C enum value of entry i
+// is WXD_EVENT_TYPE_CUSTOM_FIRST + i. Types may be allocated from any thread.
+static std::vector<wxEventType> g_custom_event_types;
+static std::mutex g_custom_event_types_mutex;
+
+static wxEventType get_wx_event_type_for_custom_c_enum(int c_enum_val) {
+    if (c_enum_val < WXD_EVENT_TYPE_CUSTOM_FIRST || c_enum_val > WXD_EVENT_TYPE_CUSTOM_LAST) {
+        return wxEVT_NULL;
+    }
+    std::lock_guard<std::mutex> lock(g_custom_event_types_mutex);
+    size_t index = static_cast<size_t>(c_enum_val - WXD_EVENT_TYPE_CUSTOM_FIRST);
+    return index < g_custom_event_types.size() ? g_custom_event_types[index] : wxEVT_NULL;
+}
+
+static WXDEventTypeCEnum get_custom_c_enum_for_wx_event_type(wxEventType wx_event_type) {
+    std::lock_guard<std::mutex> lock(g_custom_event_types_mutex);
+    for (size_t i = 0; i < g_custom_event_types.size(); ++i) {
+        if (g_custom_event_types[i] == wx_event_type) {
+            return static_cast<WXDEventTypeCEnum>(WXD_EVENT_TYPE_CUSTOM_FIRST + static_cast<int>(i));
+        }
+    }
+    return WXD_EVENT_TYPE_NULL;
+}
+
+extern "C" void drop_rust_custom_event_payload(void* ptr);
+
+// Command event carrying an opaque Rust payload. Deriving from wxCommandEvent makes
+// it propagate up the window hierarchy like other command events. The payload is
+// shared between clones and dropped on the Rust side when the last copy goes away.
+class WxdCustomEvent : public wxCommandEvent {
+public:
+    WxdCustomEvent(wxEventType type, int id, void* payload)
+        : wxCommandEvent(type, id), m_payload(payload, PayloadDeleter()) {}
+
+    WxdCustomEvent(const WxdCustomEvent& other) = default;
+
+    wxEvent* Clone() const override { return new WxdCustomEvent(*this); }
+
+    void* GetPayload() const { return m_payload.get(); }
+
+private:
+    struct PayloadDeleter {
+        void operator()(void* ptr) const {
+            if (ptr) drop_rust_custom_event_payload(ptr);
+        }
+    };
+
+    std::shared_ptr<void> m_payload;
+};
+
 // Check if an event type typically supports veto (derived from wxNotifyEvent or wxCloseEvent)
 static bool IsVetableEventType(wxEventType eventType) {
     // Close events
@@ -173,6 +238,10 @@ public:
     wxd_EvtHandler_t* c_handle = nullptr; // Changed type to wxd_EvtHandler_t*
     wxEvtHandler* ownerHandler = nullptr; // Store the actual wxEvtHandler*
 
+    // Nesting depth of DispatchEvent calls, used to defer closure drops
+    int dispatch_depth = 0;
+    bool has_unbound_closures = false;
+
     WxdEventHandler(wxd_EvtHandler_t* handle, wxEvtHandler* owner) : c_handle(handle), ownerHandler(owner) {}
 
     // Destructor - Now needs to notify Rust to drop closures via drop_rust_closure_box
@@ -183,6 +252,19 @@ public:
     
     // Special dispatch method for close events with correct signature
     void DispatchCloseEvent(wxCloseEvent& event);
+
+    // Registers a closure under the given key and returns its binding id
+    uint64_t AddClosure(const std::pair<wxEventType, wxd_Id>& key, void* closure_ptr, wxd_ClosureCallback trampoline);
+
+    // Removes the closure with the given binding id, dropping it now or after the current dispatch
+    bool RemoveClosure(uint64_t binding_id);
+
+private:
+    // Calls the closures bound under `key`; returns true if one of them consumed the event
+    bool DispatchToClosures(const std::pair<wxEventType, wxd_Id>& key, wxEvent& event);
+
+    // Drops closures that were unbound while a dispatch was in progress
+    void PurgeUnboundClosures();
 };
 
 // Define WxdHandlerClientData destructor (no change needed here, it still just deletes the handler)
@@ -206,6 +288,7 @@ WxdEventHandler::~WxdEventHandler() {
     // wxLogDebug("WxdEventHandler destroying for handler %p. Notifying Rust to drop closures.", ownerHandler);
     for (auto const& [key, closure_vector] : closureMap) {
         for (auto const& info : closure_vector) {
+            g_binding_registry.erase(info.binding_id);
             if (info.closure_ptr) {
                 // Tell Rust to drop the Box corresponding to this pointer
                 drop_rust_closure_box(info.closure_ptr);
@@ -217,6 +300,86 @@ WxdEventHandler::~WxdEventHandler() {
     wx_bindings_made.clear();
 }
 
+uint64_t WxdEventHandler::AddClosure(const std::pair<wxEventType, wxd_Id>& key, void* closure_ptr, wxd_ClosureCallback trampoline) {
+    RustClosureInfo info;
+    info.closure_ptr = closure_ptr;
+    info.rust_trampoline = trampoline;
+    info.binding_id = g_next_binding_id++;
+    closureMap[key].push_back(info);
+    g_binding_registry[info.binding_id] = this;
+    return info.binding_id;
+}
+
+bool WxdEventHandler::RemoveClosure(uint64_t binding_id) {
+    for (auto& [key, closure_vector] : closureMap) {
+        for (auto it = closure_vector.begin(); it != closure_vector.end(); ++it) {
+            if (it->binding_id != binding_id || it->unbound) continue;
+
+            g_binding_registry.erase(binding_id);
+            if (dispatch_depth > 0) {
+                // The closure (or one next to it) may be executing right now;
+                // keep the box alive until the outermost dispatch returns.
+                it->unbound = true;
+                has_unbound_closures = true;
+            } else {
+                void* closure_ptr = it->closure_ptr;
+                closure_vector.erase(it);
+                if (closure_ptr) {
+                    drop_rust_closure_box(closure_ptr);
+                }
+            }
+            return true;
+        }
+    }
+    return false;
+}
+
+void WxdEventHandler::PurgeUnboundClosures() {
+    if (!has_unbound_closures) return;
+    has_unbound_closures = false;
+
+    std::vector<void*> to_drop;
+    for (auto& [key, closure_vector] : closureMap) {
+        for (auto it = closure_vector.begin(); it != closure_vector.end();) {
+            if (it->unbound) {
+                if (it->closure_ptr) to_drop.push_back(it->closure_ptr);
+                it = closure_vector.erase(it);
+            } else {
+                ++it;
+            }
+        }
+    }
+    // Drop after the maps are consistent, as dropping may run arbitrary Rust code
+    for (void* closure_ptr : to_drop) {
+        drop_rust_closure_box(closure_ptr);
+    }
+}
+
+bool WxdEventHandler::DispatchToClosures(const std::pair<wxEventType, wxd_Id>& key, wxEvent& event) {
+    auto it = closureMap.find(key);
+    if (it == closureMap.end()) return false;
+
+    // Index-based iteration: closures may bind new handlers while we are running,
+    // which can reallocate the vector.
+    std::vector<RustClosureInfo>& closures = it->second;
+    for (size_t i = 0; i < closures.size(); ++i) {
+        RustClosureInfo info = closures[i];
+        if (info.unbound || !info.closure_ptr || !info.rust_trampoline) continue;
+
+        // Reset skip to true before each handler call
+        event.Skip(true);
+
+        // Call the Rust trampoline function
+        info.rust_trampoline(info.closure_ptr, reinterpret_cast<wxd_Event_t*>(&event));
+
+        // Check if this handler consumed the event
+        if (!event.GetSkipped()) {
+            return true; // Stop processing further handlers
+        }
+    }
+    return false;
+}
+
 // New DispatchEvent method that handles multiple closures per event
 void WxdEventHandler::DispatchEvent(wxEvent& event) {
     wxEventType eventType = event.GetEventType();
@@ -225,49 +388,18 @@ void WxdEventHandler::DispatchEvent(wxEvent& event) {
     // Create keys for specific ID and wxID_ANY
     std::pair<wxEventType, wxd_Id> key_specific_id = {eventType, id};
     std::pair<wxEventType, wxd_Id> key_any_id = {eventType, wxID_ANY};
-    
-    bool event_consumed = false;
-
-    // Process Specific ID Handlers first
-    auto it_specific = closureMap.find(key_specific_id);
-    if (it_specific != closureMap.end()) {
-        for (auto const& info : it_specific->second) {
-            if (info.closure_ptr && info.rust_trampoline) {
-                // Reset skip to true before each handler call
-                event.Skip(true);
-                
-                // Call the Rust trampoline function
-                info.rust_trampoline(info.closure_ptr, reinterpret_cast<wxd_Event_t*>(&event));
-                
-                // Check if this handler consumed the event
-                if (!event.GetSkipped()) {
-                    event_consumed = true;
-                    break; // Stop processing further handlers
-                }
-            }
-        }
+
+    dispatch_depth++;
+
+    // Process Specific ID Handlers first, then wxID_ANY handlers (if not already consumed)
+    bool event_consumed = DispatchToClosures(key_specific_id, event);
+    if (!event_consumed && id != wxID_ANY) {
+        event_consumed = DispatchToClosures(key_any_id, event);
     }
 
-    // Process wxID_ANY Handlers (if not already consumed)
-    if (!event_consumed) {
-        auto it_any = closureMap.find(key_any_id);
-        if (it_any != closureMap.end()) {
-            for (auto const& info : it_any->second) {
-                if (info.closure_ptr && info.rust_trampoline) {
-                    // Reset skip to true before each handler call
-                    event.Skip(true);
-                    
-                    // Call the Rust trampoline function
-                    info.rust_trampoline(info.closure_ptr, reinterpret_cast<wxd_Event_t*>(&event));
-                    
-                    // Check if this handler consumed the event
-                    if (!event.GetSkipped()) {
-                        event_consumed = true;
-                        break; // Stop processing further handlers
-                    }
-                }
-            }
-        }
+    dispatch_depth--;
+    if (dispatch_depth == 0) {
+        PurgeUnboundClosures();
     }
 
     // Set final event state
@@ -399,7 +531,7 @@ public:
 
 // --- C API Implementation ---
 
-extern "C" void wxd_EvtHandler_Bind(
+extern "C" uint64_t wxd_EvtHandler_Bind(
     wxd_EvtHandler_t* handler,
     WXDEventTypeCEnum eventTypeC, 
     void* rust_trampoline_fn, 
@@ -409,13 +541,13 @@ extern "C" void wxd_EvtHandler_Bind(
     if (!wx_handler) {
          wxLogWarning("wxd_EvtHandler_Bind called with null handler."); 
          if (rust_closure_ptr) { drop_rust_closure_box(rust_closure_ptr); }
-         return;
+         return 0;
     }
 
     if (!rust_trampoline_fn || !rust_closure_ptr) {
         wxLogWarning("wxd_EvtHandler_Bind called with null trampoline (%p) or closure (%p).", rust_trampoline_fn, rust_closure_ptr);
         if (rust_closure_ptr) { drop_rust_closure_box(rust_closure_ptr); } // Drop if trampoline is null but closure isn't
-        return;
+        return 0;
     }
 
     // Get or create the custom event handler
@@ -423,7 +555,7 @@ extern "C" void wxd_EvtHandler_Bind(
     if (!customHandler) {
         wxLogWarning("wxd_EvtHandler_Bind: Failed to create custom handler.");
         if (rust_closure_ptr) { drop_rust_closure_box(rust_closure_ptr); }
-        return;
+        return 0;
     }
 
     // Convert C enum to wxEventType
@@ -431,7 +563,7 @@ extern "C" void wxd_EvtHandler_Bind(
     if (wx_event_type_to_bind == wxEVT_NULL) {
         wxLogWarning("wxd_EvtHandler_Bind: Unsupported WXDEventTypeCEnum value %d.", (int)eventTypeC);
         if (rust_closure_ptr) { drop_rust_closure_box(rust_closure_ptr); }
-        return;
+        return 0;
     }
 
     // Use wxID_ANY for wxd_EvtHandler_Bind (non-ID-specific binding)
@@ -440,9 +572,6 @@ extern "C" void wxd_EvtHandler_Bind(
     // Create map key
     std::pair<wxEventType, wxd_Id> map_key = {wx_event_type_to_bind, actual_id_for_map_key};
     
-    // Create Rust closure info
-    RustClosureInfo new_rust_info = {rust_closure_ptr, reinterpret_cast<wxd_ClosureCallback>(rust_trampoline_fn)};
-    
     // Check if we need to bind DispatchEvent to wxWidgets
     if (!customHandler->wx_bindings_made[map_key]) {
         // For vetable events, use Connect() with higher priority to intercept before default handlers
@@ -468,11 +597,11 @@ extern "C" void wxd_EvtHandler_Bind(
     }
     
     // Add the closure to the vector (do this after binding to ensure cleanup on failure)
-    customHandler->closureMap[map_key].push_back(new_rust_info);
+    return customHandler->AddClosure(map_key, rust_closure_ptr, reinterpret_cast<wxd_ClosureCallback>(rust_trampoline_fn));
 }
 
 // ID-specific event binding implementation
-extern "C" void wxd_EvtHandler_BindWithId(
+extern "C" uint64_t wxd_EvtHandler_BindWithId(
     wxd_EvtHandler_t* handler,
     WXDEventTypeCEnum eventTypeC, 
     int id,
@@ -483,13 +612,13 @@ extern "C" void wxd_EvtHandler_BindWithId(
     if (!wx_handler) {
          wxLogWarning("wxd_EvtHandler_BindWithId called with null handler."); 
          if (rust_closure_ptr) { drop_rust_closure_box(rust_closure_ptr); }
-         return;
+         return 0;
     }
 
     if (!rust_trampoline_fn || !rust_closure_ptr) {
         wxLogWarning("wxd_EvtHandler_BindWithId called with null trampoline (%p) or closure (%p).", rust_trampoline_fn, rust_closure_ptr);
         if (rust_closure_ptr) { drop_rust_closure_box(rust_closure_ptr); }
-        return;
+        return 0;
     }
 
     // Get or create the custom event handler
@@ -497,7 +626,7 @@ extern "C" void wxd_EvtHandler_BindWithId(
     if (!customHandler) {
         wxLogWarning("wxd_EvtHandler_BindWithId: Failed to create custom handler.");
         if (rust_closure_ptr) { drop_rust_closure_box(rust_closure_ptr); }
-        return;
+        return 0;
     }
 
     // Convert C enum to wxEventType
@@ -505,7 +634,7 @@ extern "C" void wxd_EvtHandler_BindWithId(
     if (wx_event_type_to_bind == wxEVT_NULL) {
         wxLogWarning("wxd_EvtHandler_BindWithId: Unsupported WXDEventTypeCEnum value %d.", (int)eventTypeC);
         if (rust_closure_ptr) { drop_rust_closure_box(rust_closure_ptr); }
-        return;
+        return 0;
     }
 
     // Use the specific ID for ID-specific binding
@@ -514,9 +643,6 @@ extern "C" void wxd_EvtHandler_BindWithId(
     // Create map key
     std::pair<wxEventType, wxd_Id> map_key = {wx_event_type_to_bind, actual_id_for_map_key};
     
-    // Create Rust closure info
-    RustClosureInfo new_rust_info = {rust_closure_ptr, reinterpret_cast<wxd_ClosureCallback>(rust_trampoline_fn)};
-    
     // Check if we need to bind DispatchEvent to wxWidgets
     if (!customHandler->wx_bindings_made[map_key]) {
         // For vetable events, use Connect() with higher priority to intercept before default handlers
@@ -542,7 +668,19 @@ extern "C" void wxd_EvtHandler_BindWithId(
     }
     
     // Add the closure to the vector (do this after binding to ensure cleanup on failure)
-    customHandler->closureMap[map_key].push_back(new_rust_info);
+    return customHandler->AddClosure(map_key, rust_closure_ptr, reinterpret_cast<wxd_ClosureCallback>(rust_trampoline_fn));
+}
+
+extern "C" bool wxd_EvtHandler_Unbind(uint64_t binding_id) {
+    if (binding_id == 0) return false;
+    auto it = g_binding_registry.find(binding_id);
+    if (it == g_binding_registry.end()) return false;
+    return it->second->RemoveClosure(binding_id);
+}
+
+extern "C" bool wxd_EvtHandler_IsBound(uint64_t binding_id) {
+    if (binding_id == 0) return false;
+    return g_binding_registry.find(binding_id) != g_binding_registry.end();
 }
 
 // --- Event Accessors (Unchanged) ---
@@ -618,6 +756,69 @@ WXD_EXPORTED int wxd_KeyEvent_GetUnicodeKey(wxd_Event_t* event) {
     return keyEvent->GetUnicodeKey();
 }
 
+WXD_EXPORTED int wxd_KeyEvent_GetModifiers(wxd_Event_t* event) {
+    if (!event) return 0;
+    wxKeyEvent* keyEvent = dynamic_cast<wxKeyEvent*>(reinterpret_cast<wxEvent*>(event));
+    if (!keyEvent) return 0;
+    return keyEvent->GetModifiers();
+}
+
+WXD_EXPORTED uint32_t wxd_KeyEvent_GetRawKeyCode(wxd_Event_t* event) {
+    if (!event) return 0;
+    wxKeyEvent* keyEvent = dynamic_cast<wxKeyEvent*>(reinterpret_cast<wxEvent*>(event));
+    if (!keyEvent) return 0;
+    return keyEvent->GetRawKeyCode();
+}
+
+WXD_EXPORTED uint32_t wxd_KeyEvent_GetRawKeyFlags(wxd_Event_t* event) {
+    if (!event) return 0;
+    wxKeyEvent* keyEvent = dynamic_cast<wxKeyEvent*>(reinterpret_cast<wxEvent*>(event));
+    if (!keyEvent) return 0;
+    return keyEvent->GetRawKeyFlags();
+}
+
+WXD_EXPORTED bool wxd_KeyEvent_IsAutoRepeat(wxd_Event_t* event) {
+    if (!event) return false;
+    wxKeyEvent* keyEvent = dynamic_cast<wxKeyEvent*>(reinterpret_cast<wxEvent*>(event));
+    return keyEvent && keyEvent->IsAutoRepeat();
+}
+
+// --- Navigation key events ---
+
+WXD_EXPORTED bool wxd_NavigationKeyEvent_GetDirection(wxd_Event_t* event) {
+    if (!event) return true;
+    wxNavigationKeyEvent* navEvent = dynamic_cast<wxNavigationKeyEvent*>(reinterpret_cast<wxEvent*>(event));
+    return !navEvent || navEvent->GetDirection();
+}
+
+WXD_EXPORTED bool wxd_NavigationKeyEvent_IsWindowChange(wxd_Event_t* event) {
+    if (!event) return false;
+    wxNavigationKeyEvent* navEvent = dynamic_cast<wxNavigationKeyEvent*>(reinterpret_cast<wxEvent*>(event));
+    return navEvent && navEvent->IsWindowChange();
+}
+
+WXD_EXPORTED bool wxd_NavigationKeyEvent_IsFromTab(wxd_Event_t* event) {
+    if (!event) return false;
+    wxNavigationKeyEvent* navEvent = dynamic_cast<wxNavigationKeyEvent*>(reinterpret_cast<wxEvent*>(event));
+    return navEvent && navEvent->IsFromTab();
+}
+
+WXD_EXPORTED wxd_Window_t* wxd_NavigationKeyEvent_GetCurrentFocus(wxd_Event_t* event) {
+    if (!event) return nullptr;
+    wxNavigationKeyEvent* navEvent = dynamic_cast<wxNavigationKeyEvent*>(reinterpret_cast<wxEvent*>(event));
+    if (!navEvent) return nullptr;
+    return reinterpret_cast<wxd_Window_t*>(navEvent->GetCurrentFocus());
+}
+
+WXD_EXPORTED wxd_Point wxd_ContextMenuEvent_GetPosition(wxd_Event_t* event) {
+    wxd_Point defaultPos = { -1, -1 };
+    if (!event) return defaultPos;
+    wxContextMenuEvent* menuEvent = dynamic_cast<wxContextMenuEvent*>(reinterpret_cast<wxEvent*>(event));
+    if (!menuEvent) return defaultPos;
+    wxPoint wxPos = menuEvent->GetPosition();
+    return { wxPos.x, wxPos.y };
+}
+
 // ADDED: Implementation for wxd_CommandEvent_GetInt
 WXD_EXPORTED int wxd_CommandEvent_GetInt(wxd_Event_t* event) {
     if (!event) return 0;
@@ -663,19 +864,20 @@ static WXDEventTypeCEnum get_c_enum_for_wx_event_type(wxEventType wx_event_type)
             return c_enum;
         }
     }
-    // If we can't find a matching C enum, return NULL
-    return WXD_EVENT_TYPE_NULL;
+    // Not a built-in type; it may be one allocated with wxd_EventType_NewCustom
+    return get_custom_c_enum_for_wx_event_type(wx_event_type);
 }
 
 // Add the implementation of wxd_Event_GetEventType
+// Returns the WXDEventTypeCEnum value of the event (WXD_EVENT_TYPE_NULL if unmapped)
 wxEventType wxd_Event_GetEventType(wxd_Event_t* event) {
-    if (!event || !event->event) {
-        wxLogWarning("wxd_Event_GetEventType called with null event or null event->event");
-        return wxEVT_NULL;
+    if (!event) {
+        wxLogWarning("wxd_Event_GetEventType called with null event");
+        return WXD_EVENT_TYPE_NULL;
     }
-    wxEventType eventType = event->event->GetEventType();
-    WXDEventTypeCEnum c_enum_val = get_c_enum_for_wx_event_type(eventType);
-    return eventType;
+    wxEvent* wx_event = reinterpret_cast<wxEvent*>(event);
+    WXDEventTypeCEnum c_enum_val = get_c_enum_for_wx_event_type(wx_event->GetEventType());
+    return static_cast<wxEventType>(c_enum_val);
 }
 
 // Implement get_wx_event_type_for_c_enum to handle the mapping
@@ -993,8 +1195,41 @@ static wxEventType get_wx_event_type_for_c_enum(WXDEventTypeCEnum c_enum_val) {
             return wxEVT_NULL; // Event not available on this platform
             #endif
         #endif
+
+        // Dialog events
+        case WXD_EVENT_TYPE_WINDOW_MODAL_DIALOG_CLOSED: return wxEVT_WINDOW_MODAL_DIALOG_CLOSED;
+
+        // Child process events
+        case WXD_EVENT_TYPE_PROCESS_OUTPUT: return wxdEVT_PROCESS_OUTPUT;
+        case WXD_EVENT_TYPE_PROCESS_ERROR_OUTPUT: return wxdEVT_PROCESS_ERROR_OUTPUT;
+        case WXD_EVENT_TYPE_END_PROCESS: return wxEVT_END_PROCESS;
+
+        // File system watcher events
+        case WXD_EVENT_TYPE_FSWATCHER:
+            #if wxUSE_FSWATCHER
+            return wxEVT_FSWATCHER;
+            #else
+            return wxEVT_NULL;
+            #endif
+
+        // Double-click and auxiliary mouse button events
+        case WXD_EVENT_TYPE_LEFT_DCLICK: return wxEVT_LEFT_DCLICK;
+        case WXD_EVENT_TYPE_RIGHT_DCLICK: return wxEVT_RIGHT_DCLICK;
+        case WXD_EVENT_TYPE_MIDDLE_DCLICK: return wxEVT_MIDDLE_DCLICK;
+        case WXD_EVENT_TYPE_AUX1_DOWN: return wxEVT_AUX1_DOWN;
+        case WXD_EVENT_TYPE_AUX1_UP: return wxEVT_AUX1_UP;
+        case WXD_EVENT_TYPE_AUX1_DCLICK: return wxEVT_AUX1_DCLICK;
+        case WXD_EVENT_TYPE_AUX2_DOWN: return wxEVT_AUX2_DOWN;
+        case WXD_EVENT_TYPE_AUX2_UP: return wxEVT_AUX2_UP;
+        case WXD_EVENT_TYPE_AUX2_DCLICK: return wxEVT_AUX2_DCLICK;
+
+        // Keyboard navigation
+        case WXD_EVENT_TYPE_NAVIGATION_KEY: return wxEVT_NAVIGATION_KEY;
+
+        // Context menus
+        case WXD_EVENT_TYPE_CONTEXT_MENU: return wxEVT_CONTEXT_MENU;
         
-        default: return wxEVT_NULL;
+        default: return get_wx_event_type_for_custom_c_enum(static_cast<int>(c_enum_val));
     }
 }
 
@@ -1020,6 +1255,15 @@ extern "C" int wxd_IsMouseButtonEvent(wxd_Event_t* event) {
             type == wxEVT_RIGHT_UP || 
             type == wxEVT_MIDDLE_DOWN || 
             type == wxEVT_MIDDLE_UP || 
+            type == wxEVT_LEFT_DCLICK ||
+            type == wxEVT_RIGHT_DCLICK ||
+            type == wxEVT_MIDDLE_DCLICK ||
+            type == wxEVT_AUX1_DOWN ||
+            type == wxEVT_AUX1_UP ||
+            type == wxEVT_AUX1_DCLICK ||
+            type == wxEVT_AUX2_DOWN ||
+            type == wxEVT_AUX2_UP ||
+            type == wxEVT_AUX2_DCLICK ||
             type == wxEVT_MOUSEWHEEL) ? 1 : 0;
 }
 
@@ -1247,6 +1491,62 @@ WXD_EXPORTED int wxd_MouseEvent_GetWheelDelta(wxd_Event_t* event) {
     return mouse_event->GetWheelDelta();
 }
 
+WXD_EXPORTED int wxd_MouseEvent_GetWheelAxis(wxd_Event_t* event) {
+    if (!event) return 0;
+    wxMouseEvent* mouse_event = wxDynamicCast(reinterpret_cast<wxEvent*>(event), wxMouseEvent);
+    if (!mouse_event) return 0;
+    return mouse_event->GetWheelAxis() == wxMOUSE_WHEEL_HORIZONTAL ? 1 : 0;
+}
+
+WXD_EXPORTED int wxd_MouseEvent_GetLinesPerAction(wxd_Event_t* event) {
+    if (!event) return 3;
+    wxMouseEvent* mouse_event = wxDynamicCast(reinterpret_cast<wxEvent*>(event), wxMouseEvent);
+    if (!mouse_event) return 3;
+    return mouse_event->GetLinesPerAction();
+}
+
+WXD_EXPORTED bool wxd_MouseEvent_IsPageScroll(wxd_Event_t* event) {
+    if (!event) return false;
+    wxMouseEvent* mouse_event = wxDynamicCast(reinterpret_cast<wxEvent*>(event), wxMouseEvent);
+    return mouse_event && mouse_event->IsPageScroll();
+}
+
+// Mouse button and modifier state
+WXD_EXPORTED int wxd_MouseEvent_GetButton(wxd_Event_t* event) {
+    if (!event) return WXD_MOUSE_BTN_NONE;
+    wxMouseEvent* mouse_event = wxDynamicCast(reinterpret_cast<wxEvent*>(event), wxMouseEvent);
+    if (!mouse_event) return WXD_MOUSE_BTN_NONE;
+    // wxMouseButton and wxd_MouseButton share their values
+    return mouse_event->GetButton();
+}
+
+WXD_EXPORTED int wxd_MouseEvent_GetButtonState(wxd_Event_t* event) {
+    if (!event) return 0;
+    wxMouseEvent* mouse_event = wxDynamicCast(reinterpret_cast<wxEvent*>(event), wxMouseEvent);
+    if (!mouse_event) return 0;
+    int state = 0;
+    if (mouse_event->LeftIsDown()) state |= WXD_MOUSE_STATE_LEFT;
+    if (mouse_event->MiddleIsDown()) state |= WXD_MOUSE_STATE_MIDDLE;
+    if (mouse_event->RightIsDown()) state |= WXD_MOUSE_STATE_RIGHT;
+    if (mouse_event->Aux1IsDown()) state |= WXD_MOUSE_STATE_AUX1;
+    if (mouse_event->Aux2IsDown()) state |= WXD_MOUSE_STATE_AUX2;
+    return state;
+}
+
+WXD_EXPORTED int wxd_MouseEvent_GetModifiers(wxd_Event_t* event) {
+    if (!event) return 0;
+    wxMouseEvent* mouse_event = wxDynamicCast(reinterpret_cast<wxEvent*>(event), wxMouseEvent);
+    if (!mouse_event) return 0;
+    return mouse_event->GetModifiers();
+}
+
+WXD_EXPORTED int wxd_MouseEvent_GetClickCount(wxd_Event_t* event) {
+    if (!event) return 0;
+    wxMouseEvent* mouse_event = wxDynamicCast(reinterpret_cast<wxEvent*>(event), wxMouseEvent);
+    if (!mouse_event) return 0;
+    return mouse_event->GetClickCount();
+}
+
 // General veto support for all event types (replaces old close event specific functions)
 WXD_EXPORTED bool wxd_Event_CanVeto(wxd_Event_t* event) {
     if (!event) return false;
@@ -1312,4 +1612,40 @@ WXD_EXPORTED void wxd_Event_SetCanVeto(wxd_Event_t* event, bool can_veto) {
         // No action needed for wxNotifyEvent-derived events
         return;
     }
-}
\ No newline at end of file
+}
+
+// --- Custom Events ---
+
+WXD_EXPORTED WXDEventTypeCEnum wxd_EventType_NewCustom(void) {
+    std::lock_guard<std::mutex> lock(g_custom_event_types_mutex);
+    int next = WXD_EVENT_TYPE_CUSTOM_FIRST + static_cast<int>(g_custom_event_types.size());
+    if (next > WXD_EVENT_TYPE_CUSTOM_LAST) {
+        return WXD_EVENT_TYPE_NULL;
+    }
+    g_custom_event_types.push_back(wxNewEventType());
+    return static_cast<WXDEventTypeCEnum>(next);
+}
+
+WXD_EXPORTED bool wxd_EvtHandler_QueueCustomEvent(wxd_EvtHandler_t* handler, WXDEventTypeCEnum event_type, int id, void* payload) {
+    wxEvtHandler* wx_handler = reinterpret_cast<wxEvtHandler*>(handler);
+    wxEventType wx_event_type = get_wx_event_type_for_custom_c_enum(static_cast<int>(event_type));
+    if (!wx_handler || wx_event_type == wxEVT_NULL) {
+        wxLogWarning("wxd_EvtHandler_QueueCustomEvent called with null handler or unknown event type %d.", (int)event_type);
+        if (payload) drop_rust_custom_event_payload(payload);
+        return false;
+    }
+
+    WxdCustomEvent* event = new WxdCustomEvent(wx_event_type, id, payload);
+    event->SetEventObject(wx_handler);
+    // QueueEvent takes ownership of the heap-allocated event
+    wx_handler->QueueEvent(event);
+    return true;
+}
+
+WXD_EXPORTED void* wxd_CustomEvent_GetPayload(wxd_Event_t* event) {
+    if (!event) return nullptr;
+    wxEvent* wx_event = reinterpret_cast<wxEvent*>(event);
+    WxdCustomEvent* custom_event = dynamic_cast<WxdCustomEvent*>(wx_event);
+    if (!custom_event) return nullptr;
+    return custom_event->GetPayload();
+}
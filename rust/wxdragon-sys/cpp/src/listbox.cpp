@@ -96,11 +96,4 @@ WXD_EXPORTED void wxd_ListBox_Delete(wxd_ListBox_t* listbox, int index) {
     }
 }
 
-WXD_EXPORTED bool wxd_ListBox_PopupMenu(wxd_ListBox_t* listbox, wxd_Menu_t* menu, wxd_Point pos) {
-    wxListBox* lb = (wxListBox*)listbox;
-    if (!lb || !menu) return 0;
-    wxMenu* wx_menu = reinterpret_cast<wxMenu*>(menu);
-    return lb->PopupMenu(wx_menu, wxd_to_wx_point_sb(pos));
-}
-
 } // extern "C" 
\ No newline at end of file
@@ -26,6 +26,14 @@ WXD_EXPORTED wxd_Menu_t* wxd_Menu_Create(const char* title, wxd_Style_t style) {
     return reinterpret_cast<wxd_Menu_t*>(menu);
 }
 
+WXD_EXPORTED void wxd_Menu_Destroy(wxd_Menu_t* menu) {
+    if (!menu) return;
+    wxMenu* wx_menu = reinterpret_cast<wxMenu*>(menu);
+    // Menus in a menubar or parent menu are deleted by their owner
+    if (wx_menu->IsAttached() || wx_menu->GetParent()) return;
+    delete wx_menu;
+}
+
 WXD_EXPORTED wxd_MenuItem_t* wxd_Menu_Append(wxd_Menu_t* menu, wxd_Id id, const char* item, const char* helpString, int kind) {
     if (!menu) return nullptr;
     wxMenu* wx_menu = reinterpret_cast<wxMenu*>(menu);
@@ -44,6 +52,16 @@ WXD_EXPORTED void wxd_Menu_AppendSeparator(wxd_Menu_t* menu) {
     wx_menu->AppendSeparator();
 }
 
+WXD_EXPORTED wxd_MenuItem_t* wxd_Menu_AppendSubMenu(wxd_Menu_t* menu, wxd_Menu_t* submenu, const char* text, const char* helpString) {
+    if (!menu || !submenu) return nullptr;
+    wxMenu* wx_menu = reinterpret_cast<wxMenu*>(menu);
+    wxMenuItem* wx_item = wx_menu->AppendSubMenu(reinterpret_cast<wxMenu*>(submenu),
+                                                 wxString::FromUTF8(text ? text : ""),
+                                                 wxString::FromUTF8(helpString ? helpString : ""));
+    // The parent menu now owns the submenu.
+    return reinterpret_cast<wxd_MenuItem_t*>(wx_item);
+}
+
 // --- MenuItem Functions ---
 WXD_EXPORTED void wxd_MenuItem_Destroy(wxd_MenuItem_t* item) {
     // Generally not needed - wxMenu manages item deletion.
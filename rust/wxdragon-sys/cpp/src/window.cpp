@@ -10,6 +10,8 @@
 #include <wx/settings.h> // For wxSystemSettings and wxSYS_DEFAULT_GUI_FONT
 #include <wx/cursor.h> // For wxCursor
 #include <wx/textctrl.h> // For wxTextCtrl scrolling
+#include <wx/accel.h> // For wxAcceleratorTable
+#include <vector>
 
 // Conditional includes for optional features
 #if wxdUSE_RICHTEXT
@@ -350,6 +352,33 @@ WXD_EXPORTED wxd_Point wxd_Window_ScreenToClient(wxd_Window_t* window, wxd_Point
     return { wx_result.x, wx_result.y };
 }
 
+WXD_EXPORTED bool wxd_Window_PopupMenu(wxd_Window_t* window, wxd_Menu_t* menu, wxd_Point pos) {
+    wxWindow* wx_window = reinterpret_cast<wxWindow*>(window);
+    if (!wx_window || !menu) return false;
+    return wx_window->PopupMenu(reinterpret_cast<wxMenu*>(menu), wxPoint(pos.x, pos.y));
+}
+
+WXD_EXPORTED int wxd_Window_GetPopupMenuSelectionFromUser(wxd_Window_t* window, wxd_Menu_t* menu, wxd_Point pos) {
+    wxWindow* wx_window = reinterpret_cast<wxWindow*>(window);
+    if (!wx_window || !menu) return wxID_NONE;
+    return wx_window->GetPopupMenuSelectionFromUser(*reinterpret_cast<wxMenu*>(menu), wxPoint(pos.x, pos.y));
+}
+
+WXD_EXPORTED void wxd_Window_SetAcceleratorTable(wxd_Window_t* window, const wxd_AcceleratorEntry* entries, int count) {
+    wxWindow* wx_window = reinterpret_cast<wxWindow*>(window);
+    if (!wx_window) return;
+    if (!entries || count <= 0) {
+        wx_window->SetAcceleratorTable(wxNullAcceleratorTable);
+        return;
+    }
+    std::vector<wxAcceleratorEntry> wx_entries;
+    wx_entries.reserve(count);
+    for (int i = 0; i < count; ++i) {
+        wx_entries.emplace_back(entries[i].flags, entries[i].keyCode, entries[i].command);
+    }
+    wx_window->SetAcceleratorTable(wxAcceleratorTable(count, wx_entries.data()));
+}
+
 // Extra window style functions
 WXD_EXPORTED void wxd_Window_SetExtraStyle(wxd_Window_t* window, int64_t exStyle) {
     wxWindow* wx_window = reinterpret_cast<wxWindow*>(window);
@@ -791,6 +820,21 @@ WXD_EXPORTED int64_t wxd_Window_GetLastPosition(wxd_Window_t* window) {
 
 // --- Platform-specific Functions ---
 
+WXD_EXPORTED void wxd_Window_SetLayoutDirection(wxd_Window_t* window, wxd_LayoutDirection direction) {
+    wxWindow* wx_window = reinterpret_cast<wxWindow*>(window);
+    if (wx_window) {
+        wx_window->SetLayoutDirection(static_cast<wxLayoutDirection>(direction));
+    }
+}
+
+WXD_EXPORTED wxd_LayoutDirection wxd_Window_GetLayoutDirection(wxd_Window_t* window) {
+    wxWindow* wx_window = reinterpret_cast<wxWindow*>(window);
+    if (!wx_window) {
+        return WXD_LAYOUT_DEFAULT;
+    }
+    return static_cast<wxd_LayoutDirection>(wx_window->GetLayoutDirection());
+}
+
 WXD_EXPORTED void* wxd_Window_GetHandle(wxd_Window_t* self) {
     if (!self) {
         return nullptr;
@@ -83,6 +83,7 @@ int main(int argc, char **argv) {
     // Populate the vector using push_back
     // IDs
     constants_to_extract.push_back({"wxID_ANY", wxID_ANY});
+    constants_to_extract.push_back({"wxID_NONE", wxID_NONE});
     constants_to_extract.push_back({"wxID_HIGHEST", wxID_HIGHEST});
     constants_to_extract.push_back({"wxID_OK", wxID_OK});
     constants_to_extract.push_back({"wxID_CANCEL", wxID_CANCEL});
@@ -721,6 +722,150 @@ int main(int argc, char **argv) {
     constants_to_extract.push_back({"wxFONTWEIGHT_EXTRABOLD", wxFONTWEIGHT_EXTRABOLD});
     constants_to_extract.push_back({"wxFONTWEIGHT_HEAVY", wxFONTWEIGHT_HEAVY});
 
+    // Key modifiers
+    constants_to_extract.push_back({"wxMOD_NONE", wxMOD_NONE});
+    constants_to_extract.push_back({"wxMOD_ALT", wxMOD_ALT});
+    constants_to_extract.push_back({"wxMOD_CONTROL", wxMOD_CONTROL});
+    constants_to_extract.push_back({"wxMOD_SHIFT", wxMOD_SHIFT});
+    constants_to_extract.push_back({"wxMOD_META", wxMOD_META});
+    constants_to_extract.push_back({"wxMOD_RAW_CONTROL", wxMOD_RAW_CONTROL});
+    constants_to_extract.push_back({"wxMOD_CMD", wxMOD_CMD});
+
+    // Mouse buttons
+    constants_to_extract.push_back({"wxMOUSE_BTN_LEFT", wxMOUSE_BTN_LEFT});
+    constants_to_extract.push_back({"wxMOUSE_BTN_MIDDLE", wxMOUSE_BTN_MIDDLE});
+    constants_to_extract.push_back({"wxMOUSE_BTN_RIGHT", wxMOUSE_BTN_RIGHT});
+    constants_to_extract.push_back({"wxMOUSE_BTN_AUX1", wxMOUSE_BTN_AUX1});
+    constants_to_extract.push_back({"wxMOUSE_BTN_AUX2", wxMOUSE_BTN_AUX2});
+
+    // Accelerator flags
+    constants_to_extract.push_back({"wxACCEL_NORMAL", wxACCEL_NORMAL});
+    constants_to_extract.push_back({"wxACCEL_ALT", wxACCEL_ALT});
+    constants_to_extract.push_back({"wxACCEL_CTRL", wxACCEL_CTRL});
+    constants_to_extract.push_back({"wxACCEL_SHIFT", wxACCEL_SHIFT});
+    constants_to_extract.push_back({"wxACCEL_RAW_CTRL", wxACCEL_RAW_CTRL});
+    constants_to_extract.push_back({"wxACCEL_CMD", wxACCEL_CMD});
+
+    // Key codes
+    constants_to_extract.push_back({"WXK_BACK", WXK_BACK});
+    constants_to_extract.push_back({"WXK_TAB", WXK_TAB});
+    constants_to_extract.push_back({"WXK_RETURN", WXK_RETURN});
+    constants_to_extract.push_back({"WXK_ESCAPE", WXK_ESCAPE});
+    constants_to_extract.push_back({"WXK_SPACE", WXK_SPACE});
+    constants_to_extract.push_back({"WXK_DELETE", WXK_DELETE});
+    constants_to_extract.push_back({"WXK_END", WXK_END});
+    constants_to_extract.push_back({"WXK_HOME", WXK_HOME});
+    constants_to_extract.push_back({"WXK_LEFT", WXK_LEFT});
+    constants_to_extract.push_back({"WXK_UP", WXK_UP});
+    constants_to_extract.push_back({"WXK_RIGHT", WXK_RIGHT});
+    constants_to_extract.push_back({"WXK_DOWN", WXK_DOWN});
+    constants_to_extract.push_back({"WXK_INSERT", WXK_INSERT});
+    constants_to_extract.push_back({"WXK_F1", WXK_F1});
+    constants_to_extract.push_back({"WXK_F2", WXK_F2});
+    constants_to_extract.push_back({"WXK_F3", WXK_F3});
+    constants_to_extract.push_back({"WXK_F4", WXK_F4});
+    constants_to_extract.push_back({"WXK_F5", WXK_F5});
+    constants_to_extract.push_back({"WXK_F6", WXK_F6});
+    constants_to_extract.push_back({"WXK_F7", WXK_F7});
+    constants_to_extract.push_back({"WXK_F8", WXK_F8});
+    constants_to_extract.push_back({"WXK_F9", WXK_F9});
+    constants_to_extract.push_back({"WXK_F10", WXK_F10});
+    constants_to_extract.push_back({"WXK_F11", WXK_F11});
+    constants_to_extract.push_back({"WXK_F12", WXK_F12});
+    constants_to_extract.push_back({"WXK_F13", WXK_F13});
+    constants_to_extract.push_back({"WXK_F14", WXK_F14});
+    constants_to_extract.push_back({"WXK_F15", WXK_F15});
+    constants_to_extract.push_back({"WXK_F16", WXK_F16});
+    constants_to_extract.push_back({"WXK_F17", WXK_F17});
+    constants_to_extract.push_back({"WXK_F18", WXK_F18});
+    constants_to_extract.push_back({"WXK_F19", WXK_F19});
+    constants_to_extract.push_back({"WXK_F20", WXK_F20});
+    constants_to_extract.push_back({"WXK_F21", WXK_F21});
+    constants_to_extract.push_back({"WXK_F22", WXK_F22});
+    constants_to_extract.push_back({"WXK_F23", WXK_F23});
+    constants_to_extract.push_back({"WXK_F24", WXK_F24});
+    constants_to_extract.push_back({"WXK_PAGEUP", WXK_PAGEUP});
+    constants_to_extract.push_back({"WXK_PAGEDOWN", WXK_PAGEDOWN});
+    constants_to_extract.push_back({"WXK_CANCEL", WXK_CANCEL});
+    constants_to_extract.push_back({"WXK_CLEAR", WXK_CLEAR});
+    constants_to_extract.push_back({"WXK_SHIFT", WXK_SHIFT});
+    constants_to_extract.push_back({"WXK_ALT", WXK_ALT});
+    constants_to_extract.push_back({"WXK_CONTROL", WXK_CONTROL});
+    constants_to_extract.push_back({"WXK_MENU", WXK_MENU});
+    constants_to_extract.push_back({"WXK_PAUSE", WXK_PAUSE});
+    constants_to_extract.push_back({"WXK_CAPITAL", WXK_CAPITAL});
+    constants_to_extract.push_back({"WXK_SELECT", WXK_SELECT});
+    constants_to_extract.push_back({"WXK_PRINT", WXK_PRINT});
+    constants_to_extract.push_back({"WXK_EXECUTE", WXK_EXECUTE});
+    constants_to_extract.push_back({"WXK_SNAPSHOT", WXK_SNAPSHOT});
+    constants_to_extract.push_back({"WXK_HELP", WXK_HELP});
+    constants_to_extract.push_back({"WXK_NUMPAD0", WXK_NUMPAD0});
+    constants_to_extract.push_back({"WXK_NUMPAD1", WXK_NUMPAD1});
+    constants_to_extract.push_back({"WXK_NUMPAD2", WXK_NUMPAD2});
+    constants_to_extract.push_back({"WXK_NUMPAD3", WXK_NUMPAD3});
+    constants_to_extract.push_back({"WXK_NUMPAD4", WXK_NUMPAD4});
+    constants_to_extract.push_back({"WXK_NUMPAD5", WXK_NUMPAD5});
+    constants_to_extract.push_back({"WXK_NUMPAD6", WXK_NUMPAD6});
+    constants_to_extract.push_back({"WXK_NUMPAD7", WXK_NUMPAD7});
+    constants_to_extract.push_back({"WXK_NUMPAD8", WXK_NUMPAD8});
+    constants_to_extract.push_back({"WXK_NUMPAD9", WXK_NUMPAD9});
+    constants_to_extract.push_back({"WXK_MULTIPLY", WXK_MULTIPLY});
+    constants_to_extract.push_back({"WXK_ADD", WXK_ADD});
+    constants_to_extract.push_back({"WXK_SEPARATOR", WXK_SEPARATOR});
+    constants_to_extract.push_back({"WXK_SUBTRACT", WXK_SUBTRACT});
+    constants_to_extract.push_back({"WXK_DECIMAL", WXK_DECIMAL});
+    constants_to_extract.push_back({"WXK_DIVIDE", WXK_DIVIDE});
+    constants_to_extract.push_back({"WXK_NUMLOCK", WXK_NUMLOCK});
+    constants_to_extract.push_back({"WXK_SCROLL", WXK_SCROLL});
+    constants_to_extract.push_back({"WXK_NUMPAD_SPACE", WXK_NUMPAD_SPACE});
+    constants_to_extract.push_back({"WXK_NUMPAD_TAB", WXK_NUMPAD_TAB});
+    constants_to_extract.push_back({"WXK_NUMPAD_ENTER", WXK_NUMPAD_ENTER});
+    constants_to_extract.push_back({"WXK_NUMPAD_F1", WXK_NUMPAD_F1});
+    constants_to_extract.push_back({"WXK_NUMPAD_F2", WXK_NUMPAD_F2});
+    constants_to_extract.push_back({"WXK_NUMPAD_F3", WXK_NUMPAD_F3});
+    constants_to_extract.push_back({"WXK_NUMPAD_F4", WXK_NUMPAD_F4});
+    constants_to_extract.push_back({"WXK_NUMPAD_HOME", WXK_NUMPAD_HOME});
+    constants_to_extract.push_back({"WXK_NUMPAD_LEFT", WXK_NUMPAD_LEFT});
+    constants_to_extract.push_back({"WXK_NUMPAD_UP", WXK_NUMPAD_UP});
+    constants_to_extract.push_back({"WXK_NUMPAD_RIGHT", WXK_NUMPAD_RIGHT});
+    constants_to_extract.push_back({"WXK_NUMPAD_DOWN", WXK_NUMPAD_DOWN});
+    constants_to_extract.push_back({"WXK_NUMPAD_PAGEUP", WXK_NUMPAD_PAGEUP});
+    constants_to_extract.push_back({"WXK_NUMPAD_PAGEDOWN", WXK_NUMPAD_PAGEDOWN});
+    constants_to_extract.push_back({"WXK_NUMPAD_END", WXK_NUMPAD_END});
+    constants_to_extract.push_back({"WXK_NUMPAD_BEGIN", WXK_NUMPAD_BEGIN});
+    constants_to_extract.push_back({"WXK_NUMPAD_INSERT", WXK_NUMPAD_INSERT});
+    constants_to_extract.push_back({"WXK_NUMPAD_DELETE", WXK_NUMPAD_DELETE});
+    constants_to_extract.push_back({"WXK_NUMPAD_EQUAL", WXK_NUMPAD_EQUAL});
+    constants_to_extract.push_back({"WXK_NUMPAD_MULTIPLY", WXK_NUMPAD_MULTIPLY});
+    constants_to_extract.push_back({"WXK_NUMPAD_ADD", WXK_NUMPAD_ADD});
+    constants_to_extract.push_back({"WXK_NUMPAD_SEPARATOR", WXK_NUMPAD_SEPARATOR});
+    constants_to_extract.push_back({"WXK_NUMPAD_SUBTRACT", WXK_NUMPAD_SUBTRACT});
+    constants_to_extract.push_back({"WXK_NUMPAD_DECIMAL", WXK_NUMPAD_DECIMAL});
+    constants_to_extract.push_back({"WXK_NUMPAD_DIVIDE", WXK_NUMPAD_DIVIDE});
+    constants_to_extract.push_back({"WXK_WINDOWS_LEFT", WXK_WINDOWS_LEFT});
+    constants_to_extract.push_back({"WXK_WINDOWS_RIGHT", WXK_WINDOWS_RIGHT});
+    constants_to_extract.push_back({"WXK_WINDOWS_MENU", WXK_WINDOWS_MENU});
+    constants_to_extract.push_back({"WXK_BROWSER_BACK", WXK_BROWSER_BACK});
+    constants_to_extract.push_back({"WXK_BROWSER_FORWARD", WXK_BROWSER_FORWARD});
+    constants_to_extract.push_back({"WXK_BROWSER_REFRESH", WXK_BROWSER_REFRESH});
+    constants_to_extract.push_back({"WXK_BROWSER_STOP", WXK_BROWSER_STOP});
+    constants_to_extract.push_back({"WXK_BROWSER_SEARCH", WXK_BROWSER_SEARCH});
+    constants_to_extract.push_back({"WXK_BROWSER_FAVORITES", WXK_BROWSER_FAVORITES});
+    constants_to_extract.push_back({"WXK_BROWSER_HOME", WXK_BROWSER_HOME});
+    constants_to_extract.push_back({"WXK_VOLUME_MUTE", WXK_VOLUME_MUTE});
+    constants_to_extract.push_back({"WXK_VOLUME_DOWN", WXK_VOLUME_DOWN});
+    constants_to_extract.push_back({"WXK_VOLUME_UP", WXK_VOLUME_UP});
+    constants_to_extract.push_back({"WXK_MEDIA_NEXT_TRACK", WXK_MEDIA_NEXT_TRACK});
+    constants_to_extract.push_back({"WXK_MEDIA_PREV_TRACK", WXK_MEDIA_PREV_TRACK});
+    constants_to_extract.push_back({"WXK_MEDIA_STOP", WXK_MEDIA_STOP});
+    constants_to_extract.push_back({"WXK_MEDIA_PLAY_PAUSE", WXK_MEDIA_PLAY_PAUSE});
+    constants_to_extract.push_back({"WXK_LAUNCH_MAIL", WXK_LAUNCH_MAIL});
+    constants_to_extract.push_back({"WXK_LAUNCH_APP1", WXK_LAUNCH_APP1});
+    constants_to_extract.push_back({"WXK_LAUNCH_APP2", WXK_LAUNCH_APP2});
+
+    // File history menu ids
+    constants_to_extract.push_back({"wxID_FILE1", wxID_FILE1});
+
     // Output the constants in the Rust pub const format
     for (const auto& pair : constants_to_extract) {
         std::string original_name = pair.first;
@@ -4,6 +4,7 @@ pub const WXD_DUMMY_MSW_CONST: i32 = 1;
 pub const WXD_NOT_FOUND: i64 = -1;
 // Add other OSX specific or common constants here
 pub const WXD_ID_ANY: i64 = -1;
+pub const WXD_ID_NONE: i64 = -3;
 pub const WXD_ID_HIGHEST: i64 = 6000;
 pub const WXD_ID_OK: i64 = 5100;
 pub const WXD_ID_CANCEL: i64 = 5101;
@@ -525,3 +526,137 @@ pub const WXD_FONTWEIGHT_SEMIBOLD: i64 = 600;
 pub const WXD_FONTWEIGHT_BOLD: i64 = 700;
 pub const WXD_FONTWEIGHT_EXTRABOLD: i64 = 800;
 pub const WXD_FONTWEIGHT_HEAVY: i64 = 900;
+pub const WXD_MOD_NONE: i64 = 0;
+pub const WXD_MOD_ALT: i64 = 1;
+pub const WXD_MOD_CONTROL: i64 = 2;
+pub const WXD_MOD_SHIFT: i64 = 4;
+pub const WXD_MOD_META: i64 = 8;
+pub const WXD_MOD_RAW_CONTROL: i64 = 2;
+pub const WXD_MOD_CMD: i64 = 2;
+pub const WXD_MOUSE_BTN_LEFT: i64 = 1;
+pub const WXD_MOUSE_BTN_MIDDLE: i64 = 2;
+pub const WXD_MOUSE_BTN_RIGHT: i64 = 3;
+pub const WXD_MOUSE_BTN_AUX1: i64 = 4;
+pub const WXD_MOUSE_BTN_AUX2: i64 = 5;
+pub const WXD_ACCEL_NORMAL: i64 = 0;
+pub const WXD_ACCEL_ALT: i64 = 1;
+pub const WXD_ACCEL_CTRL: i64 = 2;
+pub const WXD_ACCEL_SHIFT: i64 = 4;
+pub const WXD_ACCEL_RAW_CTRL: i64 = 2;
+pub const WXD_ACCEL_CMD: i64 = 2;
+pub const WXD_WXK_BACK: i64 = 8;
+pub const WXD_WXK_TAB: i64 = 9;
+pub const WXD_WXK_RETURN: i64 = 13;
+pub const WXD_WXK_ESCAPE: i64 = 27;
+pub const WXD_WXK_SPACE: i64 = 32;
+pub const WXD_WXK_DELETE: i64 = 127;
+pub const WXD_WXK_END: i64 = 312;
+pub const WXD_WXK_HOME: i64 = 313;
+pub const WXD_WXK_LEFT: i64 = 314;
+pub const WXD_WXK_UP: i64 = 315;
+pub const WXD_WXK_RIGHT: i64 = 316;
+pub const WXD_WXK_DOWN: i64 = 317;
+pub const WXD_WXK_INSERT: i64 = 322;
+pub const WXD_WXK_F1: i64 = 340;
+pub const WXD_WXK_F2: i64 = 341;
+pub const WXD_WXK_F3: i64 = 342;
+pub const WXD_WXK_F4: i64 = 343;
+pub const WXD_WXK_F5: i64 = 344;
+pub const WXD_WXK_F6: i64 = 345;
+pub const WXD_WXK_F7: i64 = 346;
+pub const WXD_WXK_F8: i64 = 347;
+pub const WXD_WXK_F9: i64 = 348;
+pub const WXD_WXK_F10: i64 = 349;
+pub const WXD_WXK_F11: i64 = 350;
+pub const WXD_WXK_F12: i64 = 351;
+pub const WXD_WXK_F13: i64 = 352;
+pub const WXD_WXK_F14: i64 = 353;
+pub const WXD_WXK_F15: i64 = 354;
+pub const WXD_WXK_F16: i64 = 355;
+pub const WXD_WXK_F17: i64 = 356;
+pub const WXD_WXK_F18: i64 = 357;
+pub const WXD_WXK_F19: i64 = 358;
+pub const WXD_WXK_F20: i64 = 359;
+pub const WXD_WXK_F21: i64 = 360;
+pub const WXD_WXK_F22: i64 = 361;
+pub const WXD_WXK_F23: i64 = 362;
+pub const WXD_WXK_F24: i64 = 363;
+pub const WXD_WXK_PAGEUP: i64 = 366;
+pub const WXD_WXK_PAGEDOWN: i64 = 367;
+pub const WXD_WXK_CANCEL: i64 = 303;
+pub const WXD_WXK_CLEAR: i64 = 305;
+pub const WXD_WXK_SHIFT: i64 = 306;
+pub const WXD_WXK_ALT: i64 = 307;
+pub const WXD_WXK_CONTROL: i64 = 308;
+pub const WXD_WXK_MENU: i64 = 309;
+pub const WXD_WXK_PAUSE: i64 = 310;
+pub const WXD_WXK_CAPITAL: i64 = 311;
+pub const WXD_WXK_SELECT: i64 = 318;
+pub const WXD_WXK_PRINT: i64 = 319;
+pub const WXD_WXK_EXECUTE: i64 = 320;
+pub const WXD_WXK_SNAPSHOT: i64 = 321;
+pub const WXD_WXK_HELP: i64 = 323;
+pub const WXD_WXK_NUMPAD0: i64 = 324;
+pub const WXD_WXK_NUMPAD1: i64 = 325;
+pub const WXD_WXK_NUMPAD2: i64 = 326;
+pub const WXD_WXK_NUMPAD3: i64 = 327;
+pub const WXD_WXK_NUMPAD4: i64 = 328;
+pub const WXD_WXK_NUMPAD5: i64 = 329;
+pub const WXD_WXK_NUMPAD6: i64 = 330;
+pub const WXD_WXK_NUMPAD7: i64 = 331;
+pub const WXD_WXK_NUMPAD8: i64 = 332;
+pub const WXD_WXK_NUMPAD9: i64 = 333;
+pub const WXD_WXK_MULTIPLY: i64 = 334;
+pub const WXD_WXK_ADD: i64 = 335;
+pub const WXD_WXK_SEPARATOR: i64 = 336;
+pub const WXD_WXK_SUBTRACT: i64 = 337;
+pub const WXD_WXK_DECIMAL: i64 = 338;
+pub const WXD_WXK_DIVIDE: i64 = 339;
+pub const WXD_WXK_NUMLOCK: i64 = 364;
+pub const WXD_WXK_SCROLL: i64 = 365;
+pub const WXD_WXK_NUMPAD_SPACE: i64 = 368;
+pub const WXD_WXK_NUMPAD_TAB: i64 = 369;
+pub const WXD_WXK_NUMPAD_ENTER: i64 = 370;
+pub const WXD_WXK_NUMPAD_F1: i64 = 371;
+pub const WXD_WXK_NUMPAD_F2: i64 = 372;
+pub const WXD_WXK_NUMPAD_F3: i64 = 373;
+pub const WXD_WXK_NUMPAD_F4: i64 = 374;
+pub const WXD_WXK_NUMPAD_HOME: i64 = 375;
+pub const WXD_WXK_NUMPAD_LEFT: i64 = 376;
+pub const WXD_WXK_NUMPAD_UP: i64 = 377;
+pub const WXD_WXK_NUMPAD_RIGHT: i64 = 378;
+pub const WXD_WXK_NUMPAD_DOWN: i64 = 379;
+pub const WXD_WXK_NUMPAD_PAGEUP: i64 = 380;
+pub const WXD_WXK_NUMPAD_PAGEDOWN: i64 = 381;
+pub const WXD_WXK_NUMPAD_END: i64 = 382;
+pub const WXD_WXK_NUMPAD_BEGIN: i64 = 383;
+pub const WXD_WXK_NUMPAD_INSERT: i64 = 384;
+pub const WXD_WXK_NUMPAD_DELETE: i64 = 385;
+pub const WXD_WXK_NUMPAD_EQUAL: i64 = 386;
+pub const WXD_WXK_NUMPAD_MULTIPLY: i64 = 387;
+pub const WXD_WXK_NUMPAD_ADD: i64 = 388;
+pub const WXD_WXK_NUMPAD_SEPARATOR: i64 = 389;
+pub const WXD_WXK_NUMPAD_SUBTRACT: i64 = 390;
+pub const WXD_WXK_NUMPAD_DECIMAL: i64 = 391;
+pub const WXD_WXK_NUMPAD_DIVIDE: i64 = 392;
+pub const WXD_WXK_WINDOWS_LEFT: i64 = 393;
+pub const WXD_WXK_WINDOWS_RIGHT: i64 = 394;
+pub const WXD_WXK_WINDOWS_MENU: i64 = 395;
+pub const WXD_WXK_BROWSER_BACK: i64 = 417;
+pub const WXD_WXK_BROWSER_FORWARD: i64 = 418;
+pub const WXD_WXK_BROWSER_REFRESH: i64 = 419;
+pub const WXD_WXK_BROWSER_STOP: i64 = 420;
+pub const WXD_WXK_BROWSER_SEARCH: i64 = 421;
+pub const WXD_WXK_BROWSER_FAVORITES: i64 = 422;
+pub const WXD_WXK_BROWSER_HOME: i64 = 423;
+pub const WXD_WXK_VOLUME_MUTE: i64 = 424;
+pub const WXD_WXK_VOLUME_DOWN: i64 = 425;
+pub const WXD_WXK_VOLUME_UP: i64 = 426;
+pub const WXD_WXK_MEDIA_NEXT_TRACK: i64 = 427;
+pub const WXD_WXK_MEDIA_PREV_TRACK: i64 = 428;
+pub const WXD_WXK_MEDIA_STOP: i64 = 429;
+pub const WXD_WXK_MEDIA_PLAY_PAUSE: i64 = 430;
+pub const WXD_WXK_LAUNCH_MAIL: i64 = 431;
+pub const WXD_WXK_LAUNCH_APP1: i64 = 432;
+pub const WXD_WXK_LAUNCH_APP2: i64 = 433;
+pub const WXD_ID_FILE1: i64 = 5050;
@@ -0,0 +1,302 @@
+//! Keyboard shortcuts that are not tied to menu items.
+//!
+//! An [`Accelerator`] maps a key combination to a command id. Installed on a window
+//! with [`WxWidget::set_accelerator_table`](crate::window::WxWidget::set_accelerator_table),
+//! pressing the combination sends a menu event with that id, so the same
+//! `EventType::MENU` handlers serve menu items, toolbar buttons and shortcuts:
+//!
+//! ```rust,no_run
+//! use wxdragon::prelude::*;
+//!
+//! const ID_SAVE_ALL: Id = ID_HIGHEST + 1;
+//! const ID_REFRESH: Id = ID_HIGHEST + 2;
+//!
+//! # fn setup(frame: &Frame) -> Result<(), AcceleratorParseError> {
+//! frame.set_accelerator_table(&[
+//!     Accelerator::parse("Ctrl+Shift+S", ID_SAVE_ALL)?,
+//!     Accelerator::parse("F5", ID_REFRESH)?,
+//! ]);
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Shortcut strings use `+` or `-` between parts, modifiers first, and are not case
+//! sensitive. [`Accelerator`]'s `Display` output parses back to the same accelerator.
+
+use crate::event::{KeyCode, Modifiers};
+use crate::id::Id;
+use std::fmt;
+use wxdragon_sys as ffi;
+
+/// Alternative key names accepted by the parser, mapped to [`KeyCode::name`]s.
+const KEY_ALIASES: &[(&str, &str)] = &[
+    ("Back", "Backspace"),
+    ("Return", "Enter"),
+    ("Escape", "Esc"),
+    ("Del", "Delete"),
+    ("Ins", "Insert"),
+    ("PgUp", "PageUp"),
+    ("PgDn", "PageDown"),
+];
+
+/// Error returned when a shortcut string cannot be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AcceleratorParseError {
+    /// The string is empty.
+    Empty,
+    /// The string ends with a separator, so there is no key.
+    MissingKey,
+    /// A part before the key is not a known modifier.
+    UnknownModifier(String),
+    /// The key is neither a single character nor a known key name.
+    UnknownKey(String),
+    /// The key is a modifier, as in `"Ctrl+Shift"`, which cannot trigger a shortcut
+    /// on its own.
+    ModifierAsKey(String),
+}
+
+impl fmt::Display for AcceleratorParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AcceleratorParseError::Empty => write!(f, "empty shortcut"),
+            AcceleratorParseError::MissingKey => write!(f, "shortcut has no key"),
+            AcceleratorParseError::UnknownModifier(name) => {
+                write!(f, "unknown modifier \"{name}\"")
+            }
+            AcceleratorParseError::UnknownKey(name) => write!(f, "unknown key \"{name}\""),
+            AcceleratorParseError::ModifierAsKey(name) => {
+                write!(f, "modifier \"{name}\" cannot be the key")
+            }
+        }
+    }
+}
+
+impl std::error::Error for AcceleratorParseError {}
+
+/// A key combination that triggers a command id.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Accelerator {
+    /// Modifier keys that must be held. [`Modifiers::META`] is not supported by
+    /// accelerator tables and is ignored.
+    pub modifiers: Modifiers,
+    /// The key to press.
+    pub key: KeyCode,
+    /// Id of the menu event sent when the combination is pressed.
+    pub id: Id,
+}
+
+impl Accelerator {
+    /// Creates an accelerator from its parts.
+    pub fn new(modifiers: Modifiers, key: KeyCode, id: Id) -> Self {
+        Self { modifiers, key, id }
+    }
+
+    /// Parses a shortcut string such as `"Ctrl+Shift+S"`, `"Alt-F4"` or `"Ctrl++"`.
+    ///
+    /// Accepted modifiers are `Ctrl`/`Control`, `Cmd`/`Command` (Command on macOS,
+    /// Control elsewhere), `Alt`/`Option`, `Shift` and `RawCtrl` (the physical Control
+    /// key on macOS). The key is a single character or a name such as `F5`, `Enter`,
+    /// `Esc`, `Space`, `Tab`, `Backspace`, `Delete`, `Insert`, `Home`, `End`, `PageUp`,
+    /// `PageDown` or an arrow key (`Left`, `Up`, `Right`, `Down`).
+    pub fn parse(shortcut: &str, id: Id) -> Result<Self, AcceleratorParseError> {
+        let shortcut = shortcut.trim();
+        if shortcut.is_empty() {
+            return Err(AcceleratorParseError::Empty);
+        }
+        let (modifier_part, key_part) = split_shortcut(shortcut);
+
+        let mut modifiers = Modifiers::empty();
+        for name in modifier_part.split(is_separator).map(str::trim) {
+            if name.is_empty() {
+                continue;
+            }
+            modifiers |= parse_modifier(name)
+                .ok_or_else(|| AcceleratorParseError::UnknownModifier(name.to_string()))?;
+        }
+
+        let key = parse_key(key_part.trim())?;
+        Ok(Self::new(modifiers, key, id))
+    }
+
+    fn to_raw(self) -> ffi::wxd_AcceleratorEntry {
+        let mut flags = ffi::WXD_ACCEL_NORMAL as i32;
+        if self.modifiers.contains(Modifiers::ALT) {
+            flags |= ffi::WXD_ACCEL_ALT as i32;
+        }
+        if self.modifiers.contains(Modifiers::CONTROL) {
+            flags |= ffi::WXD_ACCEL_CTRL as i32;
+        }
+        if self.modifiers.contains(Modifiers::SHIFT) {
+            flags |= ffi::WXD_ACCEL_SHIFT as i32;
+        }
+        if self.modifiers.contains(Modifiers::RAW_CONTROL) {
+            flags |= ffi::WXD_ACCEL_RAW_CTRL as i32;
+        }
+        ffi::wxd_AcceleratorEntry {
+            flags,
+            keyCode: self.key.to_raw(),
+            command: self.id,
+        }
+    }
+}
+
+impl fmt::Display for Accelerator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.modifiers.contains(Modifiers::CONTROL) {
+            write!(f, "Ctrl+")?;
+        }
+        // Same bit as CONTROL except on macOS
+        if self.modifiers.contains(Modifiers::RAW_CONTROL)
+            && Modifiers::RAW_CONTROL != Modifiers::CONTROL
+        {
+            write!(f, "RawCtrl+")?;
+        }
+        if self.modifiers.contains(Modifiers::ALT) {
+            write!(f, "Alt+")?;
+        }
+        if self.modifiers.contains(Modifiers::SHIFT) {
+            write!(f, "Shift+")?;
+        }
+        write!(f, "{}", self.key)
+    }
+}
+
+fn is_separator(c: char) -> bool {
+    c == '+' || c == '-'
+}
+
+/// Splits a shortcut into its modifiers and its key, which may itself be a
+/// separator character as in `"Ctrl++"`.
+fn split_shortcut(shortcut: &str) -> (&str, &str) {
+    if let Some(before) = shortcut.strip_suffix(is_separator) {
+        if before.is_empty() || before.ends_with(is_separator) {
+            let key_start = before.len();
+            return (
+                &before[..before.len().saturating_sub(1)],
+                &shortcut[key_start..],
+            );
+        }
+    }
+    match shortcut.rfind(is_separator) {
+        Some(pos) => (&shortcut[..pos], &shortcut[pos + 1..]),
+        None => ("", shortcut),
+    }
+}
+
+fn parse_modifier(name: &str) -> Option<Modifiers> {
+    match name.to_ascii_lowercase().as_str() {
+        "ctrl" | "control" => Some(Modifiers::CONTROL),
+        "cmd" | "command" => Some(Modifiers::CMD),
+        "alt" | "option" => Some(Modifiers::ALT),
+        "shift" => Some(Modifiers::SHIFT),
+        "rawctrl" | "rawcontrol" => Some(Modifiers::RAW_CONTROL),
+        _ => None,
+    }
+}
+
+fn parse_key(name: &str) -> Result<KeyCode, AcceleratorParseError> {
+    if name.is_empty() {
+        return Err(AcceleratorParseError::MissingKey);
+    }
+    let mut chars = name.chars();
+    if let (Some(c), None) = (chars.next(), chars.next()) {
+        return Ok(match c {
+            ' ' => KeyCode::Space,
+            c => KeyCode::Char(c.to_ascii_uppercase()),
+        });
+    }
+    if let Some(code) = name.strip_prefix('#').and_then(|code| code.parse().ok()) {
+        return Ok(KeyCode::Other(code));
+    }
+    if parse_modifier(name).is_some() {
+        return Err(AcceleratorParseError::ModifierAsKey(name.to_string()));
+    }
+    let name = KEY_ALIASES
+        .iter()
+        .find(|(alias, _)| alias.eq_ignore_ascii_case(name))
+        .map_or(name, |(_, canonical)| canonical);
+    match KeyCode::from_name(name) {
+        Some(key) if key.is_modifier() => {
+            Err(AcceleratorParseError::ModifierAsKey(name.to_string()))
+        }
+        Some(key) => Ok(key),
+        None => Err(AcceleratorParseError::UnknownKey(name.to_string())),
+    }
+}
+
+/// Installs `accelerators` on `window`, replacing any previous table.
+pub(crate) fn set_table(window: *mut ffi::wxd_Window_t, accelerators: &[Accelerator]) {
+    let entries: Vec<ffi::wxd_AcceleratorEntry> =
+        accelerators.iter().map(|accel| accel.to_raw()).collect();
+    unsafe { ffi::wxd_Window_SetAcceleratorTable(window, entries.as_ptr(), entries.len() as i32) };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(shortcut: &str) -> Result<Accelerator, AcceleratorParseError> {
+        Accelerator::parse(shortcut, 1)
+    }
+
+    #[test]
+    fn display_parses_back_to_the_same_accelerator() {
+        for shortcut in [
+            "Ctrl+Shift+S",
+            "Ctrl++",
+            "Ctrl+-",
+            "Alt-F4",
+            "#123",
+            "Esc",
+            "Escape",
+        ] {
+            let accel = parse(shortcut).unwrap();
+            assert_eq!(parse(&accel.to_string()), Ok(accel), "{shortcut}");
+        }
+    }
+
+    #[test]
+    fn parses_modifiers_and_keys() {
+        let accel = parse("ctrl-shift-s").unwrap();
+        assert_eq!(accel.modifiers, Modifiers::CONTROL | Modifiers::SHIFT);
+        assert_eq!(accel.key, KeyCode::Char('S'));
+        assert_eq!(accel.to_string(), "Ctrl+Shift+S");
+
+        assert_eq!(parse("Ctrl++").unwrap().key, KeyCode::Char('+'));
+        assert_eq!(parse("Alt-F4").unwrap().to_string(), "Alt+F4");
+        assert_eq!(parse("#123").unwrap().key, KeyCode::Other(123));
+        assert_eq!(parse("Escape"), parse("Esc"));
+        assert_eq!(parse("Return").unwrap().key, KeyCode::Return);
+        assert_eq!(parse("PgDn").unwrap().key, KeyCode::PageDown);
+        assert_eq!(parse("Ctrl+Space").unwrap().key, KeyCode::Space);
+    }
+
+    #[test]
+    fn rejects_invalid_shortcuts() {
+        assert_eq!(parse(""), Err(AcceleratorParseError::Empty));
+        assert_eq!(parse("  "), Err(AcceleratorParseError::Empty));
+        assert_eq!(parse("Ctrl+"), Err(AcceleratorParseError::MissingKey));
+        assert_eq!(
+            parse("Ctrl+Foo"),
+            Err(AcceleratorParseError::UnknownKey("Foo".to_string()))
+        );
+        assert_eq!(
+            parse("Ctrl+Shift"),
+            Err(AcceleratorParseError::ModifierAsKey("Shift".to_string()))
+        );
+        assert_eq!(
+            parse("Shift+Alt"),
+            Err(AcceleratorParseError::ModifierAsKey("Alt".to_string()))
+        );
+        assert_eq!(
+            parse("Alt+WindowsLeft"),
+            Err(AcceleratorParseError::ModifierAsKey(
+                "WindowsLeft".to_string()
+            ))
+        );
+        assert_eq!(
+            parse("Hyper+S"),
+            Err(AcceleratorParseError::UnknownModifier("Hyper".to_string()))
+        );
+    }
+}
@@ -2,10 +2,17 @@
 // Currently, the main application logic is driven by the C wxd_Main function.
 // This module might later contain wrappers for App-specific functions if needed.
 
+#[cfg(feature = "single-instance")]
+use crate::single_instance::{InstanceArgs, InstanceRole};
 use lazy_static::lazy_static;
+use std::cell::{Cell, RefCell};
 use std::collections::VecDeque;
-use std::ffi::{c_char, c_void, CString};
+use std::ffi::{c_char, c_void, CStr, CString};
+use std::fmt;
+use std::rc::Rc;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 use wxdragon_sys as ffi; // Import Window and WxWidget trait
 
 // Type alias to reduce complexity
@@ -16,6 +23,35 @@ lazy_static! {
     static ref MAIN_THREAD_QUEUE: CallbackQueue = Arc::new(Mutex::new(VecDeque::new()));
 }
 
+// Time the main thread may spend on queued callbacks per event loop iteration
+static MAIN_THREAD_BUDGET_MICROS: AtomicU64 = AtomicU64::new(8_000);
+
+thread_local! {
+    // Deadline of the queue processing currently in progress, if any
+    static QUEUE_DEADLINE: Cell<Option<Instant>> = const { Cell::new(None) };
+}
+
+/// Sets how long the main thread may spend running queued callbacks (from
+/// [`call_after`], [`ui_channel`](crate::ui_channel) and spawned tasks) before it
+/// returns to handling input and painting. The default is 8ms.
+///
+/// Callbacks still queued when the budget runs out are run in the next iteration.
+pub fn set_main_thread_time_budget(budget: Duration) {
+    let micros = budget.as_micros().clamp(1, u64::MAX as u128) as u64;
+    MAIN_THREAD_BUDGET_MICROS.store(micros, Ordering::Relaxed);
+}
+
+/// Returns the deadline of the current queue processing, or a fresh one when called
+/// outside of it.
+pub(crate) fn main_thread_deadline() -> Instant {
+    QUEUE_DEADLINE
+        .with(|deadline| deadline.get())
+        .unwrap_or_else(|| {
+            Instant::now()
+                + Duration::from_micros(MAIN_THREAD_BUDGET_MICROS.load(Ordering::Relaxed))
+        })
+}
+
 /// Schedules a callback to be executed on the main thread.
 ///
 /// This is useful when you need to update UI elements from a background thread.
@@ -35,8 +71,10 @@ pub fn call_after<F>(callback: Box<F>)
 where
     F: FnOnce() + Send + 'static,
 {
-    let mut queue = MAIN_THREAD_QUEUE.lock().unwrap();
-    queue.push_back(callback);
+    MAIN_THREAD_QUEUE.lock().unwrap().push_back(callback);
+
+    // Wake the event loop so the callback runs even if no other events arrive
+    unsafe { ffi::wxd_App_WakeUpIdle() };
 }
 
 /// Processes pending callbacks queued via `call_after`.
@@ -44,34 +82,34 @@ where
 /// This function is called automatically by the event loop.
 /// You do not need to call this function manually.
 ///
+/// Callbacks run until the queue is empty or the time budget set with
+/// [`set_main_thread_time_budget`] is used up.
+///
 /// Returns true if any callbacks were processed, false if the queue was empty.
 pub fn process_main_thread_queue() -> bool {
-    let mut callbacks = Vec::new();
+    // Nested processing (e.g. from a yield inside a callback) shares the outer deadline
+    let deadline = main_thread_deadline();
+    let outer = QUEUE_DEADLINE.with(|current| current.replace(Some(deadline)));
+    let mut processed = false;
 
-    // Move callbacks from the queue to our local vector to minimize lock time
-    {
-        let mut queue = MAIN_THREAD_QUEUE.lock().unwrap();
-        if queue.is_empty() {
-            return false;
-        }
+    loop {
+        // Pop one callback at a time so the lock is not held while it runs
+        let callback = MAIN_THREAD_QUEUE.lock().unwrap().pop_front();
+        let Some(callback) = callback else {
+            break;
+        };
+        processed = true;
 
-        // Move up to 10 callbacks at a time to prevent UI freezes
-        // if there are many callbacks pending
-        for _ in 0..10 {
-            if let Some(callback) = queue.pop_front() {
-                callbacks.push(callback);
-            } else {
-                break;
-            }
-        }
-    }
+        // A panicking callback does not drop the rest
+        crate::panic_policy::catch("call_after callback", callback);
 
-    // Execute callbacks outside of the lock
-    for callback in callbacks {
-        callback();
+        if Instant::now() >= deadline {
+            break;
+        }
     }
 
-    true // We processed some callbacks
+    QUEUE_DEADLINE.with(|current| current.set(outer));
+    processed
 }
 
 // This function is called from C++ to process pending callbacks
@@ -184,10 +222,13 @@ pub fn set_appearance(
 ///
 /// This function initializes wxWidgets and starts the event loop. It takes a closure
 /// `on_init` that will be called once after basic initialization but before the
-/// main event loop begins.
+/// main event loop begins. It is a shorthand for [`AppBuilder::new().run(on_init)`](AppBuilder::run)
+/// that treats a non-zero exit code as an error; use [`AppBuilder`] to set the app
+/// name or install lifecycle hooks.
 ///
-/// # Panics
-/// Panics if initialization fails or if the program name cannot be converted to a CString.
+/// # Errors
+/// Returns an [`AppError`] if initialization fails or the main loop was exited with a
+/// non-zero code (see [`EventLoop::exit`](crate::event_loop::EventLoop::exit)).
 ///
 /// # Example
 /// ```no_run
@@ -206,32 +247,374 @@ pub fn main<F>(on_init: F) -> Result<(), Box<dyn std::error::Error>>
 where
     F: FnOnce(()) + 'static,
 {
-    let on_init_boxed: Box<Box<dyn FnOnce(())>> = Box::new(Box::new(on_init));
-    let user_data_ptr = Box::into_raw(on_init_boxed) as *mut c_void;
-
-    // Prepare arguments for wxd_Main (using a default program name)
-    let exit_code = unsafe {
-        let prog_name = CString::new("wxRustApp").expect("Failed to create CString for app name");
-        let mut argv: [*mut c_char; 2] = [prog_name.into_raw(), std::ptr::null_mut()];
-        let argc: i32 = 1;
-
-        // Call the C entry point, passing the trampoline and the closure data
-        let result = ffi::wxd_Main(
-            argc,
-            argv.as_mut_ptr(),
-            Some(on_init_trampoline),
-            user_data_ptr,
-        );
-
-        let _ = CString::from_raw(argv[0]);
-        result
-    };
+    match AppBuilder::new().run(on_init)? {
+        0 => Ok(()),
+        code => Err(Box::new(AppError::ExitCode(code))),
+    }
+}
+
+/// Errors returned when running the application.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AppError {
+    /// An argument or name contained an interior NUL byte.
+    InvalidArgument(String),
+    /// wxWidgets failed to initialize, or the `on_init` closure panicked.
+    InitFailed,
+    /// The main loop exited with a non-zero code (only returned by [`main`]).
+    ExitCode(i32),
+    /// Another instance is running but did not accept the forwarded arguments
+    /// (see `AppBuilder::single_instance`, behind the `single-instance` feature).
+    InstanceNotResponding,
+}
 
-    if exit_code != 0 {
-        panic!("Application exited with code: {exit_code}");
+impl fmt::Display for AppError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AppError::InvalidArgument(arg) => {
+                write!(f, "Argument contains an interior NUL byte: {arg:?}")
+            }
+            AppError::InitFailed => write!(f, "Application initialization failed"),
+            AppError::ExitCode(code) => write!(f, "Application exited with code: {code}"),
+            AppError::InstanceNotResponding => {
+                write!(f, "Another instance is running but did not respond")
+            }
+        }
     }
+}
+
+impl std::error::Error for AppError {}
+
+type HookCallback<T> = RefCell<Option<Box<dyn FnMut(T) -> bool>>>;
+type ExceptionCallback = RefCell<Option<Box<dyn FnMut(&str) -> bool>>>;
+#[cfg(feature = "single-instance")]
+type InstanceCallback = Box<dyn FnMut(InstanceArgs)>;
 
-    Ok(())
+/// Lifecycle callbacks, shared with C++ for the duration of [`AppBuilder::run`].
+#[derive(Default)]
+struct AppHooks {
+    on_exit: RefCell<Option<Box<dyn FnOnce()>>>,
+    on_activate_app: HookCallback<bool>,
+    on_unhandled_exception: ExceptionCallback,
+    on_query_end_session: HookCallback<bool>,
+    on_end_session: HookCallback<()>,
+    // Set when another instance is running, so this launch exits right away
+    secondary: Rc<Cell<bool>>,
+    // Set when that instance did not accept the forwarded arguments
+    not_responding: Rc<Cell<bool>>,
+}
+
+/// Builder for configuring and running the application.
+///
+/// # Example
+/// ```no_run
+/// use wxdragon::prelude::*;
+/// use wxdragon::app::AppBuilder;
+///
+/// fn main() -> std::process::ExitCode {
+///     let result = AppBuilder::new()
+///         .with_app_name("my-editor")
+///         .with_vendor_name("Example Corp")
+///         .with_display_name("My Editor")
+///         .on_exit(|| println!("Bye"))
+///         .on_activate_app(|active| println!("Active: {active}"))
+///         .run(|_| {
+///             let frame = Frame::builder().with_title("My Editor").build();
+///             frame.show(true);
+///         });
+///     match result {
+///         Ok(code) => std::process::ExitCode::from(code as u8),
+///         Err(_) => std::process::ExitCode::FAILURE,
+///     }
+/// }
+/// ```
+pub struct AppBuilder {
+    app_name: Option<String>,
+    vendor_name: Option<String>,
+    display_name: Option<String>,
+    vendor_display_name: Option<String>,
+    args: Vec<String>,
+    #[cfg(feature = "single-instance")]
+    single_instance: Option<(String, InstanceCallback)>,
+    hooks: AppHooks,
+}
+
+impl Default for AppBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AppBuilder {
+    /// Creates a builder that forwards the process arguments (`std::env::args_os`).
+    pub fn new() -> Self {
+        Self {
+            app_name: None,
+            vendor_name: None,
+            display_name: None,
+            vendor_display_name: None,
+            args: std::env::args_os()
+                .map(|arg| arg.to_string_lossy().into_owned())
+                .collect(),
+            #[cfg(feature = "single-instance")]
+            single_instance: None,
+            hooks: AppHooks::default(),
+        }
+    }
+
+    /// Sets the application name, used for configuration file and standard paths.
+    /// Defaults to the executable name.
+    pub fn with_app_name(mut self, name: &str) -> Self {
+        self.app_name = Some(name.to_string());
+        self
+    }
+
+    /// Sets the vendor name, used for configuration paths on some platforms.
+    pub fn with_vendor_name(mut self, name: &str) -> Self {
+        self.vendor_name = Some(name.to_string());
+        self
+    }
+
+    /// Sets the human-readable application name shown in dialogs and the task bar.
+    pub fn with_display_name(mut self, name: &str) -> Self {
+        self.display_name = Some(name.to_string());
+        self
+    }
+
+    /// Sets the human-readable vendor name.
+    pub fn with_vendor_display_name(mut self, name: &str) -> Self {
+        self.vendor_display_name = Some(name.to_string());
+        self
+    }
+
+    /// Replaces the arguments passed to wxWidgets. The first one is the program name.
+    pub fn with_args<I, S>(mut self, args: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.args = args.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Called once after the main loop has finished, before wxWidgets shuts down.
+    pub fn on_exit<F>(self, callback: F) -> Self
+    where
+        F: FnOnce() + 'static,
+    {
+        *self.hooks.on_exit.borrow_mut() = Some(Box::new(callback));
+        self
+    }
+
+    /// Called with `true` when the application gains focus and `false` when it loses it.
+    pub fn on_activate_app<F>(self, mut callback: F) -> Self
+    where
+        F: FnMut(bool) + 'static,
+    {
+        *self.hooks.on_activate_app.borrow_mut() = Some(Box::new(move |active| {
+            callback(active);
+            false
+        }));
+        self
+    }
+
+    /// Called with the message of an exception that escaped an event handler.
+    ///
+    /// Return `true` to keep the application running. Returning `false` lets
+    /// wxWidgets apply its default behaviour, which terminates the application.
+    pub fn on_unhandled_exception<F>(self, callback: F) -> Self
+    where
+        F: FnMut(&str) -> bool + 'static,
+    {
+        *self.hooks.on_unhandled_exception.borrow_mut() = Some(Box::new(callback));
+        self
+    }
+
+    /// Called when the session is about to end (logout or shutdown).
+    ///
+    /// The argument tells whether the request can be vetoed; return `true` to veto it,
+    /// e.g. because there are unsaved documents.
+    pub fn on_query_end_session<F>(self, callback: F) -> Self
+    where
+        F: FnMut(bool) -> bool + 'static,
+    {
+        *self.hooks.on_query_end_session.borrow_mut() = Some(Box::new(callback));
+        self
+    }
+
+    /// Called when the session is ending. Top-level windows are closed afterwards.
+    pub fn on_end_session<F>(self, mut callback: F) -> Self
+    where
+        F: FnMut() + 'static,
+    {
+        *self.hooks.on_end_session.borrow_mut() = Some(Box::new(move |()| {
+            callback();
+            false
+        }));
+        self
+    }
+
+    /// Allows only one running instance per user for `name`.
+    ///
+    /// The check runs during initialization, before `on_init`. If another instance is
+    /// running, this launch forwards its working directory and arguments to it and
+    /// exits with code `0` without calling `on_init` or any other hook. In the running
+    /// instance, `on_args` is called on the main thread for every forwarded launch.
+    /// If the running instance does not answer within about a second, [`run`](Self::run)
+    /// returns [`AppError::InstanceNotResponding`] instead of starting a second one.
+    /// See [`single_instance`](crate::single_instance) for details.
+    ///
+    /// Requires the `single-instance` feature.
+    #[cfg(feature = "single-instance")]
+    pub fn single_instance<F>(mut self, name: &str, on_args: F) -> Self
+    where
+        F: FnMut(InstanceArgs) + 'static,
+    {
+        self.single_instance = Some((name.to_string(), Box::new(on_args)));
+        self
+    }
+
+    /// Initializes wxWidgets, calls `on_init` and runs the main loop.
+    ///
+    /// Returns the main loop's exit code, which is `0` unless the application ended
+    /// with [`EventLoop::exit`](crate::event_loop::EventLoop::exit).
+    ///
+    /// # Panics
+    /// Panics if [`spawn_local`](crate::task::spawn_local) was already called on
+    /// another thread.
+    pub fn run<F>(self, on_init: F) -> Result<i32, AppError>
+    where
+        F: FnOnce(()) + 'static,
+    {
+        crate::task::set_gui_thread();
+
+        let to_cstring = |value: &str| {
+            CString::new(value).map_err(|_| AppError::InvalidArgument(value.to_string()))
+        };
+        let to_opt_cstring = |value: &Option<String>| value.as_deref().map(to_cstring).transpose();
+
+        let mut args = self
+            .args
+            .iter()
+            .map(|arg| to_cstring(arg))
+            .collect::<Result<Vec<_>, _>>()?;
+        if args.is_empty() {
+            args.push(to_cstring("wxRustApp")?);
+        }
+        let app_name = to_opt_cstring(&self.app_name)?;
+        let vendor_name = to_opt_cstring(&self.vendor_name)?;
+        let display_name = to_opt_cstring(&self.display_name)?;
+        let vendor_display_name = to_opt_cstring(&self.vendor_display_name)?;
+
+        // wxWidgets may reorder the pointer array while consuming toolkit options, so it
+        // gets its own copy; the strings stay owned by `args`.
+        let mut argv: Vec<*mut c_char> = args
+            .iter()
+            .map(|arg| arg.as_ptr() as *mut c_char)
+            .chain(std::iter::once(std::ptr::null_mut()))
+            .collect();
+        let argc = args.len() as i32;
+
+        let on_init: Box<dyn FnOnce(())> = Box::new(on_init);
+        #[cfg(feature = "single-instance")]
+        let on_init: Box<dyn FnOnce(())> = match self.single_instance {
+            Some((name, on_args)) => {
+                let secondary = self.hooks.secondary.clone();
+                let not_responding = self.hooks.not_responding.clone();
+                Box::new(
+                    move |()| match crate::single_instance::claim(&name, on_args) {
+                        InstanceRole::Forwarded => {
+                            secondary.set(true);
+                            // The main loop is not running yet; leave it as soon as it starts
+                            call_after(Box::new(|| {
+                                crate::event_loop::EventLoop::exit(0);
+                            }));
+                        }
+                        InstanceRole::Busy(args) => {
+                            secondary.set(true);
+                            crate::single_instance::retry_forward(name, args, move |forwarded| {
+                                not_responding.set(!forwarded);
+                                crate::event_loop::EventLoop::exit(0);
+                            });
+                        }
+                        InstanceRole::Primary(instance) => {
+                            crate::single_instance::set_primary(instance);
+                            on_init(());
+                        }
+                    },
+                )
+            }
+            None => on_init,
+        };
+        let mut on_init_slot = Some(on_init);
+        let opt_ptr =
+            |value: &Option<CString>| value.as_ref().map_or(std::ptr::null(), |s| s.as_ptr());
+
+        let mut exit_code = 0;
+        let initialized = unsafe {
+            ffi::wxd_App_SetStartupNames(
+                opt_ptr(&app_name),
+                opt_ptr(&vendor_name),
+                opt_ptr(&display_name),
+                opt_ptr(&vendor_display_name),
+            );
+            ffi::wxd_App_SetHookCallback(
+                Some(app_hook_trampoline),
+                &self.hooks as *const AppHooks as *mut c_void,
+            );
+
+            let initialized = ffi::wxd_App_Run(
+                argc,
+                argv.as_mut_ptr(),
+                Some(on_init_trampoline),
+                &mut on_init_slot as *mut Option<Box<dyn FnOnce(())>> as *mut c_void,
+                &mut exit_code,
+            );
+
+            ffi::wxd_App_SetHookCallback(None, std::ptr::null_mut());
+            ffi::wxd_App_SetStartupNames(
+                std::ptr::null(),
+                std::ptr::null(),
+                std::ptr::null(),
+                std::ptr::null(),
+            );
+            initialized
+        };
+
+        if !initialized {
+            // wxWidgets is already cleaned up, so the IPC server must not be touched
+            #[cfg(feature = "single-instance")]
+            crate::single_instance::forget_primary();
+            Err(AppError::InitFailed)
+        } else if self.hooks.not_responding.get() {
+            Err(AppError::InstanceNotResponding)
+        } else {
+            Ok(exit_code)
+        }
+    }
+}
+
+/// Gets the application name (as used for configuration paths).
+/// Returns an empty string before the application has been created.
+pub fn app_name() -> String {
+    read_app_string(|buffer, len| unsafe { ffi::wxd_App_GetAppName(buffer, len) })
+}
+
+/// Gets the vendor name.
+/// Returns an empty string before the application has been created.
+pub fn vendor_name() -> String {
+    read_app_string(|buffer, len| unsafe { ffi::wxd_App_GetVendorName(buffer, len) })
+}
+
+fn read_app_string(get: impl Fn(*mut c_char, i32) -> i32) -> String {
+    let len = get(std::ptr::null_mut(), 0);
+    if len <= 0 {
+        return String::new();
+    }
+    let mut buffer: Vec<u8> = vec![0; len as usize + 1];
+    get(buffer.as_mut_ptr() as *mut c_char, buffer.len() as i32);
+    unsafe { CStr::from_ptr(buffer.as_ptr() as *const c_char) }
+        .to_string_lossy()
+        .into_owned()
 }
 
 // Trampoline function to call the Rust closure from C
@@ -240,20 +623,66 @@ unsafe extern "C" fn on_init_trampoline(user_data: *mut c_void) -> bool {
         return false;
     }
 
-    // Cast back to Box<dyn FnOnce(())>
-    let closure_box: Box<Box<dyn FnOnce(())>> = Box::from_raw(user_data as *mut _);
+    // Take the closure out of the slot owned by AppBuilder::run
+    let slot = &mut *(user_data as *mut Option<Box<dyn FnOnce(())>>);
+    let Some(closure) = slot.take() else {
+        return false;
+    };
 
-    // Call the closure, catching potential panics
-    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
-        (*closure_box)(()) // Call the closure itself
-    }));
+    // A panic fails initialization, after the panic policy has handled it
+    crate::panic_policy::catch("application init", || closure(())).is_some()
+}
 
-    // Process the result
-    match result {
-        Ok(_) => true, // Always return success if no panic occurred
-        Err(_) => {
-            eprintln!("Panic caught in Rust AppOnInit callback!");
-            false // Indicate failure on panic
-        }
+fn call_hook<T>(slot: &HookCallback<T>, value: T) -> bool {
+    // try_borrow_mut guards against a hook re-entering itself through a nested loop
+    match slot.try_borrow_mut() {
+        Ok(mut callback) => callback.as_mut().is_some_and(|callback| callback(value)),
+        Err(_) => false,
     }
 }
+
+// Dispatches lifecycle hooks from C++ to the closures registered on AppBuilder
+unsafe extern "C" fn app_hook_trampoline(
+    user_data: *mut c_void,
+    hook: ffi::wxd_AppHook,
+    arg: i32,
+    info: *const c_char,
+) -> i32 {
+    if user_data.is_null() {
+        return 0;
+    }
+    let hooks = &*(user_data as *const AppHooks);
+    if hooks.secondary.get() {
+        return 0;
+    }
+
+    let result = crate::panic_policy::catch("application lifecycle hook", || match hook {
+        ffi::wxd_AppHook_WXD_APP_HOOK_EXIT => {
+            if let Some(callback) = hooks.on_exit.borrow_mut().take() {
+                callback();
+            }
+            #[cfg(feature = "single-instance")]
+            crate::single_instance::release_primary();
+            false
+        }
+        ffi::wxd_AppHook_WXD_APP_HOOK_ACTIVATE => call_hook(&hooks.on_activate_app, arg != 0),
+        ffi::wxd_AppHook_WXD_APP_HOOK_UNHANDLED_EXCEPTION => {
+            let message = if info.is_null() {
+                String::new()
+            } else {
+                CStr::from_ptr(info).to_string_lossy().into_owned()
+            };
+            match hooks.on_unhandled_exception.try_borrow_mut() {
+                Ok(mut callback) => callback.as_mut().is_some_and(|callback| callback(&message)),
+                Err(_) => false,
+            }
+        }
+        ffi::wxd_AppHook_WXD_APP_HOOK_QUERY_END_SESSION => {
+            call_hook(&hooks.on_query_end_session, arg != 0)
+        }
+        ffi::wxd_AppHook_WXD_APP_HOOK_END_SESSION => call_hook(&hooks.on_end_session, ()),
+        _ => false,
+    });
+
+    result.map_or(0, |handled| handled as i32)
+}
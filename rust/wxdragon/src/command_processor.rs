@@ -0,0 +1,482 @@
+//! Undo/redo history built on `wxCommandProcessor`.
+//!
+//! Every undoable change is a [`Command`] submitted to a [`CommandProcessor`], which
+//! performs it and keeps it in the history. Attached to a frame, the processor handles
+//! the frame's `ID_UNDO`/`ID_REDO` menu items and keeps their labels current
+//! ("Undo Rename Layer"):
+//!
+//! ```rust,no_run
+//! use std::cell::RefCell;
+//! use std::rc::Rc;
+//! use wxdragon::prelude::*;
+//!
+//! struct Rename {
+//!     layers: Rc<RefCell<Vec<String>>>,
+//!     index: usize,
+//!     old: String,
+//!     new: String,
+//! }
+//!
+//! impl Command for Rename {
+//!     fn do_it(&mut self) -> bool {
+//!         self.layers.borrow_mut()[self.index] = self.new.clone();
+//!         true
+//!     }
+//!     fn undo_it(&mut self) -> bool {
+//!         self.layers.borrow_mut()[self.index] = self.old.clone();
+//!         true
+//!     }
+//!     fn name(&self) -> String {
+//!         "Rename Layer".to_string()
+//!     }
+//! }
+//!
+//! # fn setup(frame: &Frame, layers: Rc<RefCell<Vec<String>>>) {
+//! let history = CommandProcessor::new();
+//! history.attach_menus(frame);
+//! history.on_change(|history| {
+//!     // e.g. show "*" in the title while there are unsaved changes
+//!     let _modified = history.is_modified();
+//! });
+//!
+//! history.submit(Rename { layers, index: 0, old: "Layer 1".into(), new: "Sky".into() });
+//! # }
+//! ```
+//!
+//! A processor is usually created once and shared by the whole application by
+//! cloning it; clones refer to the same history.
+
+use crate::event::{EventBinding, EventType, WxEvtHandler};
+use crate::id::{ID_REDO, ID_UNDO};
+use crate::window::WxWidget;
+use std::cell::{Cell, RefCell};
+use std::ffi::{c_void, CString};
+use std::rc::{Rc, Weak};
+use wxdragon_sys as ffi;
+
+mod sealed {
+    use std::any::Any;
+
+    pub trait AsAny {
+        fn as_any(&self) -> &dyn Any;
+    }
+
+    impl<T: Any> AsAny for T {
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+    }
+}
+
+/// An undoable action managed by a [`CommandProcessor`].
+pub trait Command: sealed::AsAny {
+    /// Performs the command, or performs it again after it was undone. Returns
+    /// `false` if it could not be performed.
+    fn do_it(&mut self) -> bool;
+
+    /// Reverts the command. Returns `false` if it could not be reverted.
+    fn undo_it(&mut self) -> bool;
+
+    /// The name shown in the undo/redo menu items, e.g. `"Rename Layer"`.
+    fn name(&self) -> String {
+        String::new()
+    }
+
+    /// Whether the command can be undone. Commands that cannot are still kept in
+    /// the history, but block undoing past them.
+    fn can_undo(&self) -> bool {
+        true
+    }
+
+    /// Tries to absorb `next`, a command of the same kind submitted right after this
+    /// one and already performed, so both are undone in one step (e.g. consecutive
+    /// typing or nudges). Returns `true` if `next` was merged and should be discarded.
+    ///
+    /// Use `next.downcast_ref::<Self>()` to inspect `next`.
+    /// Commands are never merged across a save point.
+    fn merge(&mut self, next: &dyn Command) -> bool {
+        let _ = next;
+        false
+    }
+}
+
+impl dyn Command {
+    /// Returns the command as a `T` if it is one.
+    pub fn downcast_ref<T: Command + 'static>(&self) -> Option<&T> {
+        self.as_any().downcast_ref::<T>()
+    }
+}
+
+type CommandSlot = RefCell<Box<dyn Command>>;
+type ChangeHandler = Rc<RefCell<dyn FnMut(&CommandProcessor)>>;
+
+struct Inner {
+    ptr: *mut ffi::wxd_CommandProcessor_t,
+    handlers: RefCell<Vec<(u64, ChangeHandler)>>,
+    next_handler_id: Cell<u64>,
+}
+
+impl Drop for Inner {
+    fn drop(&mut self) {
+        unsafe { ffi::wxd_CommandProcessor_Destroy(self.ptr) };
+    }
+}
+
+/// An undo/redo history (`wxCommandProcessor`).
+///
+/// Cloning is cheap and yields a handle to the same history.
+#[derive(Clone)]
+pub struct CommandProcessor {
+    inner: Rc<Inner>,
+}
+
+impl Default for CommandProcessor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CommandProcessor {
+    /// Creates a processor with an unlimited history.
+    pub fn new() -> Self {
+        Self::with_ptr(unsafe { ffi::wxd_CommandProcessor_Create(-1) })
+    }
+
+    /// Creates a processor keeping at most `max_commands` commands; the oldest are
+    /// dropped when more are added.
+    pub fn with_max_commands(max_commands: usize) -> Self {
+        Self::with_ptr(unsafe { ffi::wxd_CommandProcessor_Create(clamp_max(max_commands)) })
+    }
+
+    fn with_ptr(ptr: *mut ffi::wxd_CommandProcessor_t) -> Self {
+        Self {
+            inner: Rc::new(Inner {
+                ptr,
+                handlers: RefCell::new(Vec::new()),
+                next_handler_id: Cell::new(1),
+            }),
+        }
+    }
+
+    /// Performs `command` and adds it to the history, discarding anything that
+    /// could be redone. If the command fails it is dropped and `false` is returned.
+    ///
+    /// The command is first offered to the previous one for [merging](Command::merge).
+    pub fn submit<C: Command + 'static>(&self, command: C) -> bool {
+        let mut command: Box<dyn Command> = Box::new(command);
+        let done = crate::panic_policy::catch("command", || command.do_it()).unwrap_or(false);
+        if !done {
+            return false;
+        }
+        if !self.try_merge(command.as_ref()) {
+            self.store_boxed(command);
+        }
+        self.notify();
+        true
+    }
+
+    /// Adds a command that has already been performed to the history, e.g. an edit
+    /// a control made itself. It is not merged with the previous command.
+    pub fn store<C: Command + 'static>(&self, command: C) {
+        self.store_boxed(Box::new(command));
+        self.notify();
+    }
+
+    /// Undoes the last command. Returns `false` if there was nothing to undo or the
+    /// command failed.
+    pub fn undo(&self) -> bool {
+        let undone = unsafe { ffi::wxd_CommandProcessor_Undo(self.inner.ptr) };
+        if undone {
+            self.notify();
+        }
+        undone
+    }
+
+    /// Redoes the last undone command. Returns `false` if there was nothing to redo
+    /// or the command failed.
+    pub fn redo(&self) -> bool {
+        let redone = unsafe { ffi::wxd_CommandProcessor_Redo(self.inner.ptr) };
+        if redone {
+            self.notify();
+        }
+        redone
+    }
+
+    /// Returns `true` if there is a command to undo.
+    pub fn can_undo(&self) -> bool {
+        unsafe { ffi::wxd_CommandProcessor_CanUndo(self.inner.ptr) }
+    }
+
+    /// Returns `true` if there is a command to redo.
+    pub fn can_redo(&self) -> bool {
+        unsafe { ffi::wxd_CommandProcessor_CanRedo(self.inner.ptr) }
+    }
+
+    /// Returns the name of the command [`undo`](Self::undo) would revert.
+    pub fn undo_name(&self) -> Option<String> {
+        command_name(unsafe { ffi::wxd_CommandProcessor_GetUndoCommandData(self.inner.ptr) })
+    }
+
+    /// Returns the name of the command [`redo`](Self::redo) would perform.
+    pub fn redo_name(&self) -> Option<String> {
+        command_name(unsafe { ffi::wxd_CommandProcessor_GetRedoCommandData(self.inner.ptr) })
+    }
+
+    /// Drops every command in the history.
+    pub fn clear(&self) {
+        unsafe { ffi::wxd_CommandProcessor_ClearCommands(self.inner.ptr) };
+        self.notify();
+    }
+
+    /// Returns the number of commands in the history, including undone ones.
+    pub fn command_count(&self) -> usize {
+        unsafe { ffi::wxd_CommandProcessor_GetCount(self.inner.ptr) }.max(0) as usize
+    }
+
+    /// Returns the history limit, or `None` if it is unlimited.
+    pub fn max_commands(&self) -> Option<usize> {
+        let max = unsafe { ffi::wxd_CommandProcessor_GetMaxCommands(self.inner.ptr) };
+        usize::try_from(max).ok()
+    }
+
+    /// Sets the history limit, dropping the oldest commands if there are more.
+    /// `None` makes the history unlimited.
+    pub fn set_max_commands(&self, max_commands: Option<usize>) {
+        let max = max_commands.map_or(-1, clamp_max);
+        unsafe { ffi::wxd_CommandProcessor_SetMaxCommands(self.inner.ptr, max) };
+        self.notify();
+    }
+
+    /// Records the current position in the history as saved.
+    pub fn mark_as_saved(&self) {
+        unsafe { ffi::wxd_CommandProcessor_MarkAsSaved(self.inner.ptr) };
+        self.notify();
+    }
+
+    /// Returns `true` if commands have been performed, undone or redone since the
+    /// last [`mark_as_saved`](Self::mark_as_saved).
+    pub fn is_modified(&self) -> bool {
+        unsafe { ffi::wxd_CommandProcessor_IsDirty(self.inner.ptr) }
+    }
+
+    /// Wires the `ID_UNDO`/`ID_REDO` menu items of `window` (usually a frame) to this
+    /// history: choosing them undoes or redoes, and their labels and enabled state
+    /// follow the history.
+    ///
+    /// Returns the bindings of the undo and redo handlers, in that order. Unbinding
+    /// them stops the items from acting on the history; their labels still follow it.
+    pub fn attach_menus<W>(&self, window: &W) -> Vec<EventBinding>
+    where
+        W: WxWidget + WxEvtHandler,
+    {
+        let processor = self.clone();
+        let undo = window.bind_with_id_internal(EventType::MENU, ID_UNDO, move |_| {
+            processor.undo();
+        });
+        let processor = self.clone();
+        let redo = window.bind_with_id_internal(EventType::MENU, ID_REDO, move |_| {
+            processor.redo();
+        });
+        unsafe { ffi::wxd_CommandProcessor_AttachWindow(self.inner.ptr, window.handle_ptr()) };
+        vec![undo, redo]
+    }
+
+    /// Registers `handler` to be called whenever the history or its saved state
+    /// changes, e.g. to update a window title or toolbar.
+    ///
+    /// Like the `on_*` event methods, the handler stays registered when the returned
+    /// [`ChangeBinding`] is dropped; keep it to remove the handler later.
+    pub fn on_change<F>(&self, handler: F) -> ChangeBinding
+    where
+        F: FnMut(&CommandProcessor) + 'static,
+    {
+        let id = self.inner.next_handler_id.get();
+        self.inner.next_handler_id.set(id + 1);
+        self.inner
+            .handlers
+            .borrow_mut()
+            .push((id, Rc::new(RefCell::new(handler))));
+        ChangeBinding {
+            processor: Rc::downgrade(&self.inner),
+            id,
+            auto_unbind: false,
+        }
+    }
+
+    fn try_merge(&self, next: &dyn Command) -> bool {
+        // Never merge into a command that can be redone over, or across a save point
+        if self.can_redo() || !self.is_modified() {
+            return false;
+        }
+        let data = unsafe { ffi::wxd_CommandProcessor_GetUndoCommandData(self.inner.ptr) };
+        if data.is_null() {
+            return false;
+        }
+        let slot = unsafe { &*(data as *const CommandSlot) };
+        let Ok(mut previous) = slot.try_borrow_mut() else {
+            return false;
+        };
+        let merged =
+            crate::panic_policy::catch("command", || previous.merge(next)).unwrap_or(false);
+        if merged {
+            let name = CString::new(previous.name()).unwrap_or_default();
+            unsafe {
+                ffi::wxd_CommandProcessor_SetUndoCommandName(self.inner.ptr, name.as_ptr());
+                ffi::wxd_CommandProcessor_UpdateMenus(self.inner.ptr);
+            }
+        }
+        merged
+    }
+
+    fn store_boxed(&self, command: Box<dyn Command>) {
+        let name = CString::new(command.name()).unwrap_or_default();
+        let can_undo = command.can_undo();
+        let slot: Box<CommandSlot> = Box::new(RefCell::new(command));
+        unsafe {
+            ffi::wxd_CommandProcessor_Store(
+                self.inner.ptr,
+                name.as_ptr(),
+                can_undo,
+                Some(command_do_trampoline),
+                Some(command_undo_trampoline),
+                Some(command_drop_trampoline),
+                Box::into_raw(slot) as *mut c_void,
+            )
+        };
+    }
+
+    fn notify(&self) {
+        // Handlers may register or remove handlers, so call a snapshot and skip the
+        // ones removed meanwhile, or still running further up the stack
+        let handlers = self.inner.handlers.borrow().clone();
+        for (id, handler) in handlers {
+            if !is_registered(&self.inner, id) {
+                continue;
+            }
+            if let Ok(mut handler) = handler.try_borrow_mut() {
+                crate::panic_policy::catch("command processor change handler", || (*handler)(self));
+            }
+        }
+    }
+}
+
+fn is_registered(inner: &Inner, id: u64) -> bool {
+    inner
+        .handlers
+        .borrow()
+        .iter()
+        .any(|(handler_id, _)| *handler_id == id)
+}
+
+/// A token identifying a handler registered with [`CommandProcessor::on_change`].
+///
+/// As with [`EventBinding`](crate::event::EventBinding), dropping the token does not
+/// remove the handler unless [`unbind_on_drop`](Self::unbind_on_drop) was called.
+#[derive(Debug)]
+pub struct ChangeBinding {
+    processor: Weak<Inner>,
+    id: u64,
+    auto_unbind: bool,
+}
+
+impl ChangeBinding {
+    /// Returns the handler's id, unique among the handlers of its processor.
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    /// Returns `true` if the handler is still registered.
+    ///
+    /// This becomes `false` after [`unbind`](Self::unbind) or once every handle to
+    /// the processor has been dropped.
+    pub fn is_bound(&self) -> bool {
+        self.processor
+            .upgrade()
+            .is_some_and(|inner| is_registered(&inner, self.id))
+    }
+
+    /// Removes the handler and drops its closure.
+    ///
+    /// It is safe to call this from inside the handler itself; the closure is then
+    /// dropped once it returns. Returns `false` if the handler was already removed.
+    pub fn unbind(mut self) -> bool {
+        self.auto_unbind = false;
+        self.remove()
+    }
+
+    /// Makes this binding remove its handler when dropped, turning it into a scope guard.
+    pub fn unbind_on_drop(mut self) -> Self {
+        self.auto_unbind = true;
+        self
+    }
+
+    /// Returns `true` if the handler will be removed when this token is dropped.
+    pub fn is_unbind_on_drop(&self) -> bool {
+        self.auto_unbind
+    }
+
+    fn remove(&self) -> bool {
+        let Some(inner) = self.processor.upgrade() else {
+            return false;
+        };
+        let removed = {
+            let mut handlers = inner.handlers.borrow_mut();
+            handlers
+                .iter()
+                .position(|(id, _)| *id == self.id)
+                .map(|index| handlers.remove(index))
+        };
+        // Dropped outside the borrow, as the closure may own processor handles
+        removed.is_some()
+    }
+}
+
+impl Drop for ChangeBinding {
+    fn drop(&mut self) {
+        if self.auto_unbind {
+            self.remove();
+        }
+    }
+}
+
+fn clamp_max(max_commands: usize) -> i32 {
+    max_commands.clamp(1, i32::MAX as usize) as i32
+}
+
+fn command_name(data: *mut c_void) -> Option<String> {
+    if data.is_null() {
+        return None;
+    }
+    let slot = unsafe { &*(data as *const CommandSlot) };
+    slot.try_borrow().ok().map(|command| command.name())
+}
+
+unsafe extern "C" fn command_do_trampoline(user_data: *mut c_void) -> bool {
+    if user_data.is_null() {
+        return false;
+    }
+    let slot = &*(user_data as *const CommandSlot);
+    let Ok(mut command) = slot.try_borrow_mut() else {
+        return false;
+    };
+    crate::panic_policy::catch("command", || command.do_it()).unwrap_or(false)
+}
+
+unsafe extern "C" fn command_undo_trampoline(user_data: *mut c_void) -> bool {
+    if user_data.is_null() {
+        return false;
+    }
+    let slot = &*(user_data as *const CommandSlot);
+    let Ok(mut command) = slot.try_borrow_mut() else {
+        return false;
+    };
+    crate::panic_policy::catch("command", || command.undo_it()).unwrap_or(false)
+}
+
+unsafe extern "C" fn command_drop_trampoline(user_data: *mut c_void) {
+    if user_data.is_null() {
+        return;
+    }
+    let slot = Box::from_raw(user_data as *mut CommandSlot);
+    crate::panic_policy::catch("command", || drop(slot));
+}
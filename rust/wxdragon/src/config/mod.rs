@@ -0,0 +1,383 @@
+//! Persistent application settings (`wxConfig`/`wxFileConfig`).
+//!
+//! A [`Config`] is a tree of groups holding named entries, addressed with
+//! `/`-separated paths that are either absolute or relative to the current path.
+//! Values are read and written with the typed [`get`](Config::get) and
+//! [`set`](Config::set):
+//!
+//! ```rust,no_run
+//! use wxdragon::prelude::*;
+//!
+//! let config = Config::new("MyApp", "MyCompany");
+//! config.set("Window/Width", 800);
+//! config.set("Window/Maximized", false);
+//! config.set("Editor/Font", &Font::new());
+//!
+//! let width: i32 = config.get_or("Window/Width", 640);
+//! let theme: Option<String> = config.get("Appearance/Theme");
+//! config.flush();
+//! ```
+//!
+//! [`Config::new`] uses the platform's native storage: the registry on Windows, a
+//! preferences file on macOS and a file in the user's config directory elsewhere.
+//! [`Config::from_file`] always uses a file, e.g. one located with
+//! [`StandardPaths`](crate::standard_paths::StandardPaths).
+//!
+//! With the `serde` feature, whole structs can be stored with
+//! [`Config::save_struct`] and read back with [`Config::load_struct`].
+
+use crate::color::Colour;
+use crate::font::Font;
+use crate::utils::WxdArrayString;
+use std::ffi::{CStr, CString};
+use std::os::raw::c_char;
+use wxdragon_sys as ffi;
+
+#[cfg(feature = "serde")]
+mod serde_support;
+#[cfg(feature = "serde")]
+pub use serde_support::ConfigError;
+
+/// A value that can be read from a [`Config`] entry.
+pub trait FromConfig: Sized {
+    #[doc(hidden)]
+    fn read(config: &Config, key: &CStr) -> Option<Self>;
+}
+
+/// A value that can be written to a [`Config`] entry.
+pub trait ToConfig {
+    #[doc(hidden)]
+    fn write(&self, config: &Config, key: &CStr) -> bool;
+}
+
+/// Application settings storage.
+///
+/// A config created with [`new`](Self::new) or [`from_file`](Self::from_file) is
+/// destroyed when dropped; call [`flush`](Self::flush) first to be sure changes
+/// are written.
+pub struct Config {
+    // Null for the handle returned by `global`, which looks the global config up on
+    // every call so it never refers to one replaced by `set_global`
+    ptr: *mut ffi::wxd_Config_t,
+}
+
+impl Config {
+    /// Creates the platform's native settings storage for the given application.
+    pub fn new(app_name: &str, vendor_name: &str) -> Self {
+        let app_c = CString::new(app_name).unwrap_or_default();
+        let vendor_c = CString::new(vendor_name).unwrap_or_default();
+        Self {
+            ptr: unsafe { ffi::wxd_Config_Create(app_c.as_ptr(), vendor_c.as_ptr()) },
+        }
+    }
+
+    /// Creates settings stored in the INI-style file at `path`. The file is created
+    /// when the settings are first flushed.
+    pub fn from_file(path: &str) -> Self {
+        let path_c = CString::new(path).unwrap_or_default();
+        Self {
+            ptr: unsafe { ffi::wxd_Config_CreateFile(path_c.as_ptr()) },
+        }
+    }
+
+    /// Returns the application's global config, creating a native one named after
+    /// the application if none was set. It is used by e.g.
+    /// [`FileHistory::load`](crate::file_history::FileHistory::load).
+    ///
+    /// The returned handle does not own the config. It always refers to the current
+    /// global config, also after [`set_global`](Self::set_global) replaced it.
+    pub fn global() -> Option<Self> {
+        let ptr = unsafe { ffi::wxd_Config_GetGlobal(true) };
+        (!ptr.is_null()).then_some(Self {
+            ptr: std::ptr::null_mut(),
+        })
+    }
+
+    /// Makes `config` the application's global config, destroying the previous
+    /// one. wxWidgets flushes and destroys it when the application exits.
+    pub fn set_global(config: Config) {
+        let ptr = config.ptr;
+        // Ownership passes to wxWidgets
+        std::mem::forget(config);
+        if !ptr.is_null() {
+            unsafe { ffi::wxd_Config_SetGlobal(ptr) };
+        }
+    }
+
+    fn as_ptr(&self) -> *mut ffi::wxd_Config_t {
+        if self.ptr.is_null() {
+            unsafe { ffi::wxd_Config_GetGlobal(true) }
+        } else {
+            self.ptr
+        }
+    }
+
+    /// Sets the current path. Relative keys are resolved against it; a path
+    /// starting with `/` is absolute.
+    pub fn set_path(&self, path: &str) {
+        let path_c = CString::new(path).unwrap_or_default();
+        unsafe { ffi::wxd_Config_SetPath(self.as_ptr(), path_c.as_ptr()) };
+    }
+
+    /// Returns the current path.
+    pub fn path(&self) -> String {
+        take_string(unsafe { ffi::wxd_Config_GetPath(self.as_ptr()) }).unwrap_or_default()
+    }
+
+    /// Runs `f` with the current path changed to `group`, then restores it, also
+    /// if `f` panics.
+    pub fn with_group<R>(&self, group: &str, f: impl FnOnce(&Config) -> R) -> R {
+        let _restore = PathGuard {
+            config: self,
+            old_path: self.path(),
+        };
+        self.set_path(group);
+        f(self)
+    }
+
+    /// Returns `true` if the entry exists.
+    pub fn has_entry(&self, key: &str) -> bool {
+        let key_c = CString::new(key).unwrap_or_default();
+        unsafe { ffi::wxd_Config_HasEntry(self.as_ptr(), key_c.as_ptr()) }
+    }
+
+    /// Returns `true` if the group exists.
+    pub fn has_group(&self, key: &str) -> bool {
+        let key_c = CString::new(key).unwrap_or_default();
+        unsafe { ffi::wxd_Config_HasGroup(self.as_ptr(), key_c.as_ptr()) }
+    }
+
+    /// Reads an entry, or returns `None` if it is missing or cannot be converted to `T`.
+    pub fn get<T: FromConfig>(&self, key: &str) -> Option<T> {
+        let key_c = CString::new(key).ok()?;
+        T::read(self, &key_c)
+    }
+
+    /// Reads an entry, or returns `default` if it is missing or cannot be converted.
+    pub fn get_or<T: FromConfig>(&self, key: &str, default: T) -> T {
+        self.get(key).unwrap_or(default)
+    }
+
+    /// Writes an entry, creating its groups as needed. Returns `false` on failure.
+    pub fn set<T: ToConfig>(&self, key: &str, value: T) -> bool {
+        match CString::new(key) {
+            Ok(key_c) => value.write(self, &key_c),
+            Err(_) => false,
+        }
+    }
+
+    /// Returns the names of the entries in the current group.
+    pub fn entries(&self) -> Vec<String> {
+        let names = WxdArrayString::new();
+        unsafe { ffi::wxd_Config_GetEntries(self.as_ptr(), names.as_ptr()) };
+        names.get_strings()
+    }
+
+    /// Returns the names of the subgroups of the current group.
+    pub fn groups(&self) -> Vec<String> {
+        let names = WxdArrayString::new();
+        unsafe { ffi::wxd_Config_GetGroups(self.as_ptr(), names.as_ptr()) };
+        names.get_strings()
+    }
+
+    /// Renames an entry of the current group. Fails if `new_name` exists.
+    pub fn rename_entry(&self, old_name: &str, new_name: &str) -> bool {
+        let old_c = CString::new(old_name).unwrap_or_default();
+        let new_c = CString::new(new_name).unwrap_or_default();
+        unsafe { ffi::wxd_Config_RenameEntry(self.as_ptr(), old_c.as_ptr(), new_c.as_ptr()) }
+    }
+
+    /// Renames a subgroup of the current group. Fails if `new_name` exists.
+    pub fn rename_group(&self, old_name: &str, new_name: &str) -> bool {
+        let old_c = CString::new(old_name).unwrap_or_default();
+        let new_c = CString::new(new_name).unwrap_or_default();
+        unsafe { ffi::wxd_Config_RenameGroup(self.as_ptr(), old_c.as_ptr(), new_c.as_ptr()) }
+    }
+
+    /// Deletes an entry, and its group too if that becomes empty.
+    pub fn delete_entry(&self, key: &str) -> bool {
+        let key_c = CString::new(key).unwrap_or_default();
+        unsafe { ffi::wxd_Config_DeleteEntry(self.as_ptr(), key_c.as_ptr(), true) }
+    }
+
+    /// Deletes a group with all its entries and subgroups.
+    pub fn delete_group(&self, key: &str) -> bool {
+        let key_c = CString::new(key).unwrap_or_default();
+        unsafe { ffi::wxd_Config_DeleteGroup(self.as_ptr(), key_c.as_ptr()) }
+    }
+
+    /// Deletes everything, including the backing file or registry key.
+    pub fn delete_all(&self) -> bool {
+        unsafe { ffi::wxd_Config_DeleteAll(self.as_ptr()) }
+    }
+
+    /// Writes pending changes to permanent storage.
+    pub fn flush(&self) -> bool {
+        unsafe { ffi::wxd_Config_Flush(self.as_ptr()) }
+    }
+}
+
+impl Drop for Config {
+    fn drop(&mut self) {
+        if !self.ptr.is_null() {
+            unsafe { ffi::wxd_Config_Destroy(self.as_ptr()) };
+        }
+    }
+}
+
+/// Restores the current path of a config when dropped.
+struct PathGuard<'a> {
+    config: &'a Config,
+    old_path: String,
+}
+
+impl Drop for PathGuard<'_> {
+    fn drop(&mut self) {
+        // Absolute, so it cannot be misread as relative to the group
+        let path = format!("/{}", self.old_path.trim_start_matches('/'));
+        self.config.set_path(&path);
+    }
+}
+
+fn take_string(ptr: *mut c_char) -> Option<String> {
+    if ptr.is_null() {
+        return None;
+    }
+    let value = unsafe { CStr::from_ptr(ptr) }
+        .to_string_lossy()
+        .into_owned();
+    unsafe { ffi::wxd_free_string(ptr) };
+    Some(value)
+}
+
+// --- Value conversions ---
+
+impl FromConfig for String {
+    fn read(config: &Config, key: &CStr) -> Option<Self> {
+        take_string(unsafe { ffi::wxd_Config_ReadString(config.as_ptr(), key.as_ptr()) })
+    }
+}
+
+impl FromConfig for i64 {
+    fn read(config: &Config, key: &CStr) -> Option<Self> {
+        let mut value = 0i64;
+        unsafe { ffi::wxd_Config_ReadLong(config.as_ptr(), key.as_ptr(), &mut value) }
+            .then_some(value)
+    }
+}
+
+impl FromConfig for i32 {
+    fn read(config: &Config, key: &CStr) -> Option<Self> {
+        i64::read(config, key).and_then(|value| i32::try_from(value).ok())
+    }
+}
+
+impl FromConfig for u32 {
+    fn read(config: &Config, key: &CStr) -> Option<Self> {
+        i64::read(config, key).and_then(|value| u32::try_from(value).ok())
+    }
+}
+
+impl FromConfig for f64 {
+    fn read(config: &Config, key: &CStr) -> Option<Self> {
+        let mut value = 0f64;
+        unsafe { ffi::wxd_Config_ReadDouble(config.as_ptr(), key.as_ptr(), &mut value) }
+            .then_some(value)
+    }
+}
+
+impl FromConfig for f32 {
+    fn read(config: &Config, key: &CStr) -> Option<Self> {
+        f64::read(config, key).map(|value| value as f32)
+    }
+}
+
+impl FromConfig for bool {
+    fn read(config: &Config, key: &CStr) -> Option<Self> {
+        let mut value = false;
+        unsafe { ffi::wxd_Config_ReadBool(config.as_ptr(), key.as_ptr(), &mut value) }
+            .then_some(value)
+    }
+}
+
+impl FromConfig for Colour {
+    fn read(config: &Config, key: &CStr) -> Option<Self> {
+        let mut value = Colour::rgb(0, 0, 0).to_raw();
+        unsafe { ffi::wxd_Config_ReadColour(config.as_ptr(), key.as_ptr(), &mut value) }
+            .then(|| Colour::from(value))
+    }
+}
+
+impl FromConfig for Font {
+    fn read(config: &Config, key: &CStr) -> Option<Self> {
+        let ptr = unsafe { ffi::wxd_Config_ReadFont(config.as_ptr(), key.as_ptr()) };
+        (!ptr.is_null()).then(|| unsafe { Font::from_ptr(ptr, true) })
+    }
+}
+
+impl<T: ToConfig + ?Sized> ToConfig for &T {
+    fn write(&self, config: &Config, key: &CStr) -> bool {
+        (**self).write(config, key)
+    }
+}
+
+impl ToConfig for str {
+    fn write(&self, config: &Config, key: &CStr) -> bool {
+        let value = CString::new(self).unwrap_or_default();
+        unsafe { ffi::wxd_Config_WriteString(config.as_ptr(), key.as_ptr(), value.as_ptr()) }
+    }
+}
+
+impl ToConfig for String {
+    fn write(&self, config: &Config, key: &CStr) -> bool {
+        self.as_str().write(config, key)
+    }
+}
+
+impl ToConfig for i64 {
+    fn write(&self, config: &Config, key: &CStr) -> bool {
+        unsafe { ffi::wxd_Config_WriteLong(config.as_ptr(), key.as_ptr(), *self) }
+    }
+}
+
+impl ToConfig for i32 {
+    fn write(&self, config: &Config, key: &CStr) -> bool {
+        i64::from(*self).write(config, key)
+    }
+}
+
+impl ToConfig for u32 {
+    fn write(&self, config: &Config, key: &CStr) -> bool {
+        i64::from(*self).write(config, key)
+    }
+}
+
+impl ToConfig for f64 {
+    fn write(&self, config: &Config, key: &CStr) -> bool {
+        unsafe { ffi::wxd_Config_WriteDouble(config.as_ptr(), key.as_ptr(), *self) }
+    }
+}
+
+impl ToConfig for f32 {
+    fn write(&self, config: &Config, key: &CStr) -> bool {
+        f64::from(*self).write(config, key)
+    }
+}
+
+impl ToConfig for bool {
+    fn write(&self, config: &Config, key: &CStr) -> bool {
+        unsafe { ffi::wxd_Config_WriteBool(config.as_ptr(), key.as_ptr(), *self) }
+    }
+}
+
+impl ToConfig for Colour {
+    fn write(&self, config: &Config, key: &CStr) -> bool {
+        unsafe { ffi::wxd_Config_WriteColour(config.as_ptr(), key.as_ptr(), self.to_raw()) }
+    }
+}
+
+impl ToConfig for Font {
+    fn write(&self, config: &Config, key: &CStr) -> bool {
+        unsafe { ffi::wxd_Config_WriteFont(config.as_ptr(), key.as_ptr(), self.as_ptr()) }
+    }
+}
@@ -0,0 +1,387 @@
+//! Storing whole structs in a [`Config`] through serde.
+//!
+//! Each field becomes an entry of the group, nested structs and maps become
+//! subgroups, and sequences are stored as JSON text. Loading starts from the
+//! type's `Default` value and replaces the fields found in the config, so settings
+//! written by an older version of the application still load after fields are added.
+//!
+//! Where the default value does not tell the stored type, e.g. for fields that are
+//! `None` by default or map entries, entries are read as text and converted to the
+//! type the field asks for when deserializing.
+
+use super::Config;
+use serde::de::value::{MapDeserializer, SeqDeserializer};
+use serde::de::{DeserializeOwned, Deserializer, IntoDeserializer, Visitor};
+use serde::Serialize;
+use serde_json::{Map, Number, Value};
+use std::fmt;
+
+/// Error returned by [`Config::save_struct`] and [`Config::load_struct`].
+#[derive(Debug)]
+pub enum ConfigError {
+    /// The value could not be converted to or from its stored form.
+    Serde(serde_json::Error),
+    /// The value does not serialize to a struct or map, so it has no named fields.
+    NotAStruct,
+    /// Writing the named entry failed.
+    Write(String),
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigError::Serde(err) => write!(f, "config conversion failed: {err}"),
+            ConfigError::NotAStruct => write!(f, "only structs and maps can be stored in a config"),
+            ConfigError::Write(key) => write!(f, "failed to write config entry \"{key}\""),
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ConfigError::Serde(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<serde_json::Error> for ConfigError {
+    fn from(err: serde_json::Error) -> Self {
+        ConfigError::Serde(err)
+    }
+}
+
+impl Config {
+    /// Stores the fields of `value` as entries of `group`. Fields that are `None`
+    /// are removed. Call [`flush`](Self::flush) to write them out.
+    pub fn save_struct<T: Serialize>(&self, group: &str, value: &T) -> Result<(), ConfigError> {
+        let Value::Object(fields) = serde_json::to_value(value)? else {
+            return Err(ConfigError::NotAStruct);
+        };
+        self.with_group(group, |config| write_fields(config, &fields))
+    }
+
+    /// Reads a `T` stored with [`save_struct`](Self::save_struct) from `group`.
+    ///
+    /// Fields missing from the config, or stored with a different type, keep their
+    /// default values.
+    pub fn load_struct<T>(&self, group: &str) -> Result<T, ConfigError>
+    where
+        T: Serialize + DeserializeOwned + Default,
+    {
+        let Value::Object(mut fields) = serde_json::to_value(T::default())? else {
+            return Err(ConfigError::NotAStruct);
+        };
+        // Entering a missing group would create it
+        if self.has_group(group) {
+            self.with_group(group, |config| read_fields(config, &mut fields));
+        }
+        Ok(T::deserialize(Lenient(Value::Object(fields)))?)
+    }
+}
+
+fn write_fields(config: &Config, fields: &Map<String, Value>) -> Result<(), ConfigError> {
+    for (name, value) in fields {
+        let written = match value {
+            Value::Object(inner) => {
+                config.with_group(name, |config| write_fields(config, inner))?;
+                true
+            }
+            Value::Bool(value) => config.set(name, *value),
+            Value::Number(number) => write_number(config, name, number),
+            Value::String(text) => config.set(name, text),
+            Value::Array(_) => config.set(name, value.to_string()),
+            Value::Null => {
+                // A `Some` struct or map was stored as a group
+                if config.has_group(name) {
+                    config.delete_group(name);
+                }
+                if config.has_entry(name) {
+                    config.delete_entry(name);
+                }
+                true
+            }
+        };
+        if !written {
+            return Err(ConfigError::Write(name.clone()));
+        }
+    }
+    Ok(())
+}
+
+fn write_number(config: &Config, name: &str, number: &Number) -> bool {
+    if let Some(value) = number.as_i64() {
+        config.set(name, value)
+    } else if number.is_f64() {
+        config.set(name, number.as_f64().unwrap_or_default())
+    } else {
+        // u64 beyond i64::MAX
+        config.set(name, number.to_string())
+    }
+}
+
+/// Replaces the values in `fields` with the entries found in the current group,
+/// using each default value's type to decide how to read the entry. Entries and
+/// groups without a default value, e.g. those of maps, are added as text.
+fn read_fields(config: &Config, fields: &mut Map<String, Value>) {
+    for name in config.entries() {
+        if !fields.contains_key(&name) {
+            fields.insert(name, Value::Null);
+        }
+    }
+    for name in config.groups() {
+        if !fields.contains_key(&name) {
+            fields.insert(name, Value::Null);
+        }
+    }
+    for (name, slot) in fields.iter_mut() {
+        let value = match slot {
+            Value::Object(inner) => {
+                if config.has_group(name) {
+                    config.with_group(name, |config| read_fields(config, inner));
+                }
+                continue;
+            }
+            Value::Bool(_) => config.get::<bool>(name).map(Value::Bool),
+            Value::Number(number) => read_number(config, name, number),
+            Value::String(_) => config.get::<String>(name).map(Value::String),
+            Value::Array(_) => config
+                .get::<String>(name)
+                .and_then(|text| serde_json::from_str(&text).ok()),
+            Value::Null if config.has_group(name) => Some(config.with_group(name, read_group)),
+            Value::Null => config.get::<String>(name).map(Value::String),
+        };
+        if let Some(value) = value {
+            *slot = value;
+        }
+    }
+}
+
+fn read_number(config: &Config, name: &str, default: &Number) -> Option<Value> {
+    if default.is_f64() {
+        config
+            .get::<f64>(name)
+            .and_then(Number::from_f64)
+            .map(Value::Number)
+    } else if default.is_i64() {
+        config.get::<i64>(name).map(Value::from)
+    } else {
+        config
+            .get::<String>(name)
+            .and_then(|text| text.parse::<u64>().ok())
+            .map(Value::from)
+    }
+}
+
+/// Reads the entries and subgroups of the current group without knowing their types.
+fn read_group(config: &Config) -> Value {
+    let mut fields = Map::new();
+    read_fields(config, &mut fields);
+    Value::Object(fields)
+}
+
+/// Deserializes the fields read from a config. Entries read as text are converted
+/// to the type the target asks for, e.g. `"1"` to `true` for a `bool`, since wx
+/// stores booleans as numbers.
+struct Lenient(Value);
+
+impl Lenient {
+    /// Parses entry text as JSON, for targets that are not strings.
+    fn parsed(self) -> Value {
+        match self.0 {
+            Value::String(text) => serde_json::from_str(&text).unwrap_or(Value::String(text)),
+            value => value,
+        }
+    }
+
+    fn visit_map<'de, V: Visitor<'de>>(
+        map: Map<String, Value>,
+        visitor: V,
+    ) -> Result<V::Value, serde_json::Error> {
+        let mut access = MapDeserializer::new(map.into_iter().map(|(k, v)| (k, Lenient(v))));
+        let value = visitor.visit_map(&mut access)?;
+        access.end()?;
+        Ok(value)
+    }
+
+    fn visit_seq<'de, V: Visitor<'de>>(
+        items: Vec<Value>,
+        visitor: V,
+    ) -> Result<V::Value, serde_json::Error> {
+        let mut access = SeqDeserializer::new(items.into_iter().map(Lenient));
+        let value = visitor.visit_seq(&mut access)?;
+        access.end()?;
+        Ok(value)
+    }
+}
+
+impl<'de> IntoDeserializer<'de, serde_json::Error> for Lenient {
+    type Deserializer = Self;
+
+    fn into_deserializer(self) -> Self {
+        self
+    }
+}
+
+macro_rules! forward_parsed {
+    ($($method:ident)*) => {$(
+        fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+            self.parsed().$method(visitor)
+        }
+    )*};
+}
+
+impl<'de> Deserializer<'de> for Lenient {
+    type Error = serde_json::Error;
+
+    forward_parsed! {
+        deserialize_i8 deserialize_i16 deserialize_i32 deserialize_i64
+        deserialize_u8 deserialize_u16 deserialize_u32 deserialize_u64
+        deserialize_f32 deserialize_f64 deserialize_unit deserialize_bytes
+        deserialize_byte_buf deserialize_identifier deserialize_ignored_any
+    }
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        match self.parsed() {
+            Value::Object(map) => Self::visit_map(map, visitor),
+            Value::Array(items) => Self::visit_seq(items, visitor),
+            value => value.deserialize_any(visitor),
+        }
+    }
+
+    fn deserialize_bool<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        match self.parsed() {
+            Value::Number(number) => visitor.visit_bool(number.as_f64() != Some(0.0)),
+            value => value.deserialize_bool(visitor),
+        }
+    }
+
+    fn deserialize_char<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        self.deserialize_string(visitor)
+    }
+
+    fn deserialize_str<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        self.deserialize_string(visitor)
+    }
+
+    fn deserialize_string<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        match self.0 {
+            Value::String(text) => visitor.visit_string(text),
+            value @ (Value::Bool(_) | Value::Number(_)) => visitor.visit_string(value.to_string()),
+            value => value.deserialize_string(visitor),
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        match self.0 {
+            Value::Null => visitor.visit_none(),
+            value => visitor.visit_some(Lenient(value)),
+        }
+    }
+
+    fn deserialize_unit_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        self.deserialize_unit(visitor)
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        match self.parsed() {
+            Value::Array(items) => Self::visit_seq(items, visitor),
+            value => value.deserialize_seq(visitor),
+        }
+    }
+
+    fn deserialize_tuple<V: Visitor<'de>>(
+        self,
+        _len: usize,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_tuple_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _len: usize,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_map<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        match self.parsed() {
+            Value::Object(map) => Self::visit_map(map, visitor),
+            value => value.deserialize_map(visitor),
+        }
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        self.deserialize_map(visitor)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        self.parsed().deserialize_enum(name, variants, visitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use std::collections::HashMap;
+
+    fn load<T: DeserializeOwned>(value: Value) -> T {
+        T::deserialize(Lenient(value)).unwrap()
+    }
+
+    #[test]
+    fn text_converts_to_requested_type() {
+        assert!(load::<bool>(json!("1")));
+        assert!(!load::<bool>(json!("0")));
+        assert_eq!(load::<i32>(json!("-7")), -7);
+        assert_eq!(load::<f64>(json!("1.5")), 1.5);
+        assert_eq!(load::<String>(json!("123")), "123");
+        assert_eq!(load::<String>(json!("true")), "true");
+        assert_eq!(load::<Vec<u8>>(json!("[1,2]")), vec![1, 2]);
+    }
+
+    #[test]
+    fn options_and_groups() {
+        assert_eq!(load::<Option<i32>>(Value::Null), None);
+        assert_eq!(load::<Option<i32>>(json!("5")), Some(5));
+        assert_eq!(
+            load::<Option<String>>(json!("null")),
+            Some("null".to_string())
+        );
+
+        // An `Option` of a struct or map, read back from a group of text entries
+        let stored = json!({ "shown": "1", "size": { "w": "640", "h": "480" } });
+        let value: HashMap<String, Option<HashMap<String, u32>>> =
+            load(json!({ "size": stored["size"].clone() }));
+        assert_eq!(value["size"].as_ref().unwrap()["w"], 640);
+        let shown: HashMap<String, bool> = load(json!({ "shown": stored["shown"].clone() }));
+        assert!(shown["shown"]);
+    }
+}
@@ -1,6 +1,10 @@
 use std::ffi::CString;
 
-use crate::{color::Colour, dialogs::Dialog, window::WxWidget};
+use crate::{
+    color::Colour,
+    dialogs::{Dialog, WindowModalFuture},
+    window::WxWidget,
+};
 use wxdragon_sys as ffi;
 
 /// Wrapper for wxColourDialog.
@@ -48,6 +52,13 @@ impl ColourDialog {
         self.dialog_base.show_modal()
     }
 
+    /// Shows the dialog modal to its parent window only; see [`Dialog::show_window_modal_async`].
+    /// If the result is [`DialogResult::Ok`](crate::dialogs::DialogResult::Ok), call
+    /// get_colour() to retrieve the selected colour.
+    pub fn show_window_modal_async(&self) -> WindowModalFuture {
+        self.dialog_base.show_window_modal_async()
+    }
+
     /// Get the selected colour from the dialog.
     /// Returns None if the colour data is not available.
     pub fn get_colour(&self) -> Option<Colour> {
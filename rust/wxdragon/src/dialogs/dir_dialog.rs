@@ -1,3 +1,4 @@
+use crate::dialogs::{Dialog, WindowModalFuture};
 use crate::geometry::{Point, Size, DEFAULT_POSITION, DEFAULT_SIZE};
 use crate::window::WxWidget;
 use std::ffi::{CStr, CString};
@@ -39,6 +40,14 @@ impl DirDialog {
         unsafe { ffi::wxd_Dialog_ShowModal(self.ptr as *mut ffi::wxd_Dialog_t) }
     }
 
+    /// Shows the dialog modal to its parent window only; see [`Dialog::show_window_modal_async`].
+    /// If the result is [`DialogResult::Ok`](crate::dialogs::DialogResult::Ok), call
+    /// get_path() to retrieve the chosen directory.
+    pub fn show_window_modal_async(&self) -> WindowModalFuture {
+        let dialog = unsafe { Dialog::from_ptr(self.ptr as *mut ffi::wxd_Dialog_t) };
+        dialog.show_window_modal_async()
+    }
+
     /// Gets the path selected by the user.
     pub fn get_path(&self) -> Option<String> {
         unsafe {
@@ -1,4 +1,4 @@
-use crate::dialogs::Dialog;
+use crate::dialogs::{Dialog, WindowModalFuture};
 use crate::geometry::{Point, Size, DEFAULT_POSITION, DEFAULT_SIZE};
 use crate::utils::WxdArrayString;
 use crate::widget_style_enum;
@@ -58,6 +58,13 @@ impl FileDialog {
         self.dialog_base.show_modal()
     }
 
+    /// Shows the dialog modal to its parent window only; see [`Dialog::show_window_modal_async`].
+    /// If the result is [`DialogResult::Ok`](crate::dialogs::DialogResult::Ok), call
+    /// get_path() or get_paths() to retrieve the selection.
+    pub fn show_window_modal_async(&self) -> WindowModalFuture {
+        self.dialog_base.show_window_modal_async()
+    }
+
     /// Gets the full path of the selected file.
     /// Returns `None` if the dialog was cancelled or an error occurred.
     pub fn get_path(&self) -> Option<String> {
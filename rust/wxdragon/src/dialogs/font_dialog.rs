@@ -1,6 +1,11 @@
 use std::ffi::CString;
 
-use crate::{dialogs::Dialog, font::Font, font_data::FontData, window::WxWidget};
+use crate::{
+    dialogs::{Dialog, WindowModalFuture},
+    font::Font,
+    font_data::FontData,
+    window::WxWidget,
+};
 use wxdragon_sys as ffi;
 
 /// Wrapper for wxFontDialog.
@@ -48,6 +53,13 @@ impl FontDialog {
         self.dialog_base.show_modal()
     }
 
+    /// Shows the dialog modal to its parent window only; see [`Dialog::show_window_modal_async`].
+    /// If the result is [`DialogResult::Ok`](crate::dialogs::DialogResult::Ok), call
+    /// get_font() to retrieve the selected font.
+    pub fn show_window_modal_async(&self) -> WindowModalFuture {
+        self.dialog_base.show_window_modal_async()
+    }
+
     /// Get the selected font from the dialog.
     /// This will create a new Font object that you own.
     /// Returns None if the font is not available or invalid.
@@ -1,4 +1,4 @@
-use crate::dialogs::Dialog; // Use the Dialog from the parent module
+use crate::dialogs::{Dialog, WindowModalFuture}; // Use the Dialog from the parent module
 use crate::widget_style_enum;
 use crate::window::WxWidget; // Keep this
 use std::ffi::CString;
@@ -60,6 +60,11 @@ impl MessageDialog {
         self.dialog_base.show_modal()
     }
 
+    /// Shows the dialog modal to its parent window only; see [`Dialog::show_window_modal_async`].
+    pub fn show_window_modal_async(&self) -> WindowModalFuture {
+        self.dialog_base.show_window_modal_async()
+    }
+
     pub fn as_ptr(&self) -> MessageDialogPtr {
         self.dialog_base.as_ptr() as MessageDialogPtr
     }
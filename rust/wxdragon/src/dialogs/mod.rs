@@ -1,8 +1,14 @@
-use crate::event::WindowEvents;
+use crate::event::{Event, EventBinding, EventType, WindowEvents, WxEvtHandler};
+use crate::id::{Id, ID_APPLY, ID_CANCEL, ID_CLOSE, ID_HELP, ID_NO, ID_OK, ID_YES};
 use crate::widget_style_enum;
 use crate::window::{Window, WxWidget};
+use std::cell::RefCell;
 use std::ffi::CString;
+use std::future::Future;
 use std::marker::PhantomData;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::task::{Context, Poll, Waker};
 use wxdragon_sys as ffi;
 
 pub mod colour_dialog;
@@ -94,6 +100,45 @@ impl Dialog {
         }
     }
 
+    /// Shows the dialog modal to its parent window only and returns a future that
+    /// resolves when the dialog is closed.
+    ///
+    /// The dialog is shown in the event loop iteration after the future is first
+    /// polled, typically from a [`spawn_local`](crate::spawn_local) task, so polling
+    /// never blocks. On macOS this uses a native sheet. Elsewhere the dialog is shown
+    /// modelessly while its parent is disabled; only native common dialogs (message,
+    /// file, directory, colour and font dialogs), which cannot be shown that way, run
+    /// a modal loop there. The dialog is not destroyed when the future completes.
+    ///
+    /// ```rust,no_run
+    /// use wxdragon::prelude::*;
+    ///
+    /// # fn demo(frame: Frame) {
+    /// wxdragon::spawn_local(async move {
+    ///     let dialog = MessageDialog::builder(&frame, "Save changes?", "Editor")
+    ///         .with_style(MessageDialogStyle::YesNo | MessageDialogStyle::IconQuestion)
+    ///         .build();
+    ///     if dialog.show_window_modal_async().await == DialogResult::Yes {
+    ///         // save...
+    ///     }
+    ///     dialog.destroy();
+    /// });
+    /// # }
+    /// ```
+    pub fn show_window_modal_async(&self) -> WindowModalFuture {
+        WindowModalFuture::new(self.clone())
+    }
+
+    /// Returns the code the dialog was last closed with (see [`DialogResult`]).
+    pub fn get_return_code(&self) -> i32 {
+        unsafe { ffi::wxd_Dialog_GetReturnCode(self.as_ptr()) }
+    }
+
+    /// Returns `true` if the dialog is currently shown with [`show_modal`](Self::show_modal).
+    pub fn is_modal(&self) -> bool {
+        unsafe { ffi::wxd_Dialog_IsModal(self.as_ptr()) }
+    }
+
     /// Returns the raw underlying dialog pointer.
     pub fn as_ptr(&self) -> *mut ffi::wxd_Dialog_t {
         self.window.as_ptr() as *mut ffi::wxd_Dialog_t
@@ -128,6 +173,188 @@ impl_xrc_support!(Dialog, {
 // will be wrapped, and their Drop will call wxd_Window_Destroy on the pointer,
 // which is appropriate as wxDialog inherits from wxWindow.
 
+// --- DialogResult ---
+/// The outcome of a dialog, decoded from its return code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DialogResult {
+    /// Closed with `ID_OK`.
+    Ok,
+    /// Closed with `ID_CANCEL`, or the dialog was destroyed before it was closed.
+    Cancel,
+    /// Closed with `ID_YES`.
+    Yes,
+    /// Closed with `ID_NO`.
+    No,
+    /// Closed with `ID_APPLY`.
+    Apply,
+    /// Closed with `ID_CLOSE`.
+    Close,
+    /// Closed with `ID_HELP`.
+    Help,
+    /// Closed with any other id, e.g. a custom button.
+    Other(Id),
+}
+
+impl DialogResult {
+    /// Returns the return code this result corresponds to.
+    pub fn id(self) -> Id {
+        match self {
+            DialogResult::Ok => ID_OK,
+            DialogResult::Cancel => ID_CANCEL,
+            DialogResult::Yes => ID_YES,
+            DialogResult::No => ID_NO,
+            DialogResult::Apply => ID_APPLY,
+            DialogResult::Close => ID_CLOSE,
+            DialogResult::Help => ID_HELP,
+            DialogResult::Other(id) => id,
+        }
+    }
+
+    /// Returns `true` for [`Ok`](Self::Ok) and [`Yes`](Self::Yes).
+    pub fn is_affirmative(self) -> bool {
+        matches!(self, DialogResult::Ok | DialogResult::Yes)
+    }
+}
+
+impl From<Id> for DialogResult {
+    fn from(code: Id) -> Self {
+        match code {
+            ID_OK => DialogResult::Ok,
+            ID_CANCEL => DialogResult::Cancel,
+            ID_YES => DialogResult::Yes,
+            ID_NO => DialogResult::No,
+            ID_APPLY => DialogResult::Apply,
+            ID_CLOSE => DialogResult::Close,
+            ID_HELP => DialogResult::Help,
+            other => DialogResult::Other(other),
+        }
+    }
+}
+
+impl From<DialogResult> for Id {
+    fn from(result: DialogResult) -> Self {
+        result.id()
+    }
+}
+
+// --- WindowModalFuture ---
+#[derive(Default)]
+struct WindowModalState {
+    result: Option<DialogResult>,
+    waker: Option<Waker>,
+}
+
+impl WindowModalState {
+    fn finish(state: &RefCell<WindowModalState>, result: DialogResult) {
+        let waker = {
+            let mut state = state.borrow_mut();
+            if state.result.is_some() {
+                return;
+            }
+            state.result = Some(result);
+            state.waker.take()
+        };
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
+}
+
+/// Resolves the future as cancelled if the close handler is dropped without firing,
+/// which happens when the dialog is destroyed while still shown.
+struct CloseGuard(Rc<RefCell<WindowModalState>>);
+
+impl Drop for CloseGuard {
+    fn drop(&mut self) {
+        WindowModalState::finish(&self.0, DialogResult::Cancel);
+    }
+}
+
+/// Future returned by [`Dialog::show_window_modal_async`].
+///
+/// Must be polled on the GUI thread. Dropping it before completion stops listening
+/// for the close event but does not hide the dialog.
+pub struct WindowModalFuture {
+    dialog: Dialog,
+    state: Rc<RefCell<WindowModalState>>,
+    binding: Option<EventBinding>,
+    started: bool,
+}
+
+impl WindowModalFuture {
+    fn new(dialog: Dialog) -> Self {
+        Self {
+            dialog,
+            state: Rc::new(RefCell::new(WindowModalState::default())),
+            binding: None,
+            started: false,
+        }
+    }
+
+    fn start(&mut self) {
+        self.started = true;
+        let dialog_ptr = self.dialog.as_ptr();
+        if dialog_ptr.is_null() {
+            WindowModalState::finish(&self.state, DialogResult::Cancel);
+            return;
+        }
+
+        let guard = CloseGuard(self.state.clone());
+        let binding = self.dialog.bind_internal(
+            EventType::WINDOW_MODAL_DIALOG_CLOSED,
+            move |event: Event| {
+                let code = unsafe { ffi::wxd_Dialog_GetReturnCode(dialog_ptr) };
+                WindowModalState::finish(&guard.0, DialogResult::from(code));
+                event.skip(true);
+            },
+        );
+        self.binding = Some(binding);
+
+        // Only queues the dialog to be shown, so the handler never fires from here
+        unsafe { ffi::wxd_Dialog_ShowWindowModal(dialog_ptr) };
+    }
+}
+
+impl Future for WindowModalFuture {
+    type Output = DialogResult;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<DialogResult> {
+        let this = self.get_mut();
+        this.state.borrow_mut().waker = Some(cx.waker().clone());
+        if !this.started {
+            this.start();
+        }
+
+        let result = this.state.borrow().result;
+        match result {
+            Some(result) => {
+                if let Some(binding) = this.binding.take() {
+                    binding.unbind();
+                }
+                Poll::Ready(result)
+            }
+            None => Poll::Pending,
+        }
+    }
+}
+
+impl Drop for WindowModalFuture {
+    fn drop(&mut self) {
+        if let Some(binding) = self.binding.take() {
+            binding.unbind();
+        }
+    }
+}
+
+impl std::fmt::Debug for WindowModalFuture {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WindowModalFuture")
+            .field("started", &self.started)
+            .field("result", &self.state.borrow().result)
+            .finish()
+    }
+}
+
 // --- DialogBuilder ---
 /// Builder for creating generic Dialog instances.
 pub struct DialogBuilder<'a> {
@@ -1,4 +1,4 @@
-use crate::dialogs::Dialog;
+use crate::dialogs::{Dialog, WindowModalFuture};
 use crate::geometry::{Point, Size, DEFAULT_POSITION, DEFAULT_SIZE};
 use crate::utils::WxdArrayString;
 use crate::window::WxWidget;
@@ -58,6 +58,11 @@ impl MultiChoiceDialog {
         self.dialog_base.show_modal()
     }
 
+    /// Shows the dialog modal to its parent window only; see [`Dialog::show_window_modal_async`].
+    pub fn show_window_modal_async(&self) -> WindowModalFuture {
+        self.dialog_base.show_window_modal_async()
+    }
+
     /// Gets the indices of the selections made by the user.
     /// Returns an empty vector if no selections were made or the dialog was cancelled.
     pub fn get_selections(&self) -> Vec<i32> {
@@ -1,4 +1,4 @@
-use crate::dialogs::Dialog;
+use crate::dialogs::{Dialog, WindowModalFuture};
 use crate::geometry::{Point, Size, DEFAULT_POSITION, DEFAULT_SIZE};
 use crate::window::WxWidget;
 use std::ffi::{CStr, CString};
@@ -59,6 +59,13 @@ impl TextEntryDialog {
         self.dialog_base.show_modal()
     }
 
+    /// Shows the dialog modal to its parent window only; see [`Dialog::show_window_modal_async`].
+    /// If the result is [`DialogResult::Ok`](crate::dialogs::DialogResult::Ok), call
+    /// get_value() to retrieve the entered text.
+    pub fn show_window_modal_async(&self) -> WindowModalFuture {
+        self.dialog_base.show_window_modal_async()
+    }
+
     /// Gets the text entered by the user.
     /// Returns `None` if the dialog was cancelled or an error occurred retrieving the value.
     pub fn get_value(&self) -> Option<String> {
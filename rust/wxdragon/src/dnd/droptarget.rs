@@ -311,8 +311,11 @@ extern "C" fn text_on_enter_trampoline(
     let callbacks = unsafe { &mut *(data_ptr as *mut TextDropTargetCallbacks) };
 
     if let Some(ref mut callback) = callbacks.on_enter {
-        let rust_result = callback(x, y, DragResult::from(def_result as i32));
-        rust_result as i32 as ffi::wxd_DragResult
+        // A panicking callback falls back to the default result
+        crate::panic_policy::catch("drop target", || {
+            callback(x, y, DragResult::from(def_result as i32)) as i32 as ffi::wxd_DragResult
+        })
+        .unwrap_or(def_result)
     } else {
         def_result
     }
@@ -331,8 +334,11 @@ extern "C" fn text_on_drag_over_trampoline(
     let callbacks = unsafe { &mut *(data_ptr as *mut TextDropTargetCallbacks) };
 
     if let Some(ref mut callback) = callbacks.on_drag_over {
-        let rust_result = callback(x, y, DragResult::from(def_result as i32));
-        rust_result as i32 as ffi::wxd_DragResult
+        // A panicking callback falls back to the default result
+        crate::panic_policy::catch("drop target", || {
+            callback(x, y, DragResult::from(def_result as i32)) as i32 as ffi::wxd_DragResult
+        })
+        .unwrap_or(def_result)
     } else {
         def_result
     }
@@ -346,7 +352,7 @@ extern "C" fn text_on_leave_trampoline(data_ptr: *mut c_void) {
     let callbacks = unsafe { &mut *(data_ptr as *mut TextDropTargetCallbacks) };
 
     if let Some(ref mut callback) = callbacks.on_leave {
-        callback();
+        crate::panic_policy::catch("drop target", callback);
     }
 }
 
@@ -358,7 +364,7 @@ extern "C" fn text_on_drop_trampoline(x: i32, y: i32, data_ptr: *mut c_void) ->
     let callbacks = unsafe { &mut *(data_ptr as *mut TextDropTargetCallbacks) };
 
     if let Some(ref mut callback) = callbacks.on_drop {
-        callback(x, y)
+        crate::panic_policy::catch("drop target", || callback(x, y)).unwrap_or(false)
     } else {
         true // Default to accepting the drop
     }
@@ -377,8 +383,11 @@ extern "C" fn text_on_data_trampoline(
     let callbacks = unsafe { &mut *(data_ptr as *mut TextDropTargetCallbacks) };
 
     if let Some(ref mut callback) = callbacks.on_data {
-        let rust_result = callback(x, y, DragResult::from(def_result as i32));
-        rust_result as i32 as ffi::wxd_DragResult
+        // A panicking callback falls back to the default result
+        crate::panic_policy::catch("drop target", || {
+            callback(x, y, DragResult::from(def_result as i32)) as i32 as ffi::wxd_DragResult
+        })
+        .unwrap_or(def_result)
     } else {
         def_result
     }
@@ -397,7 +406,8 @@ extern "C" fn text_on_drop_text_trampoline(
     let text_str = unsafe { CStr::from_ptr(text).to_string_lossy().into_owned() };
     let callbacks = unsafe { &mut *(data_ptr as *mut TextDropTargetCallbacks) };
 
-    (callbacks.on_drop_text)(&text_str, x, y)
+    crate::panic_policy::catch("drop target", || (callbacks.on_drop_text)(&text_str, x, y))
+        .unwrap_or(false)
 }
 
 // --- Callback trampolines for FileDropTarget ---
@@ -415,8 +425,11 @@ extern "C" fn file_on_enter_trampoline(
     let callbacks = unsafe { &mut *(data_ptr as *mut FileDropTargetCallbacks) };
 
     if let Some(ref mut callback) = callbacks.on_enter {
-        let rust_result = callback(x, y, DragResult::from(def_result as i32));
-        rust_result as i32 as ffi::wxd_DragResult
+        // A panicking callback falls back to the default result
+        crate::panic_policy::catch("drop target", || {
+            callback(x, y, DragResult::from(def_result as i32)) as i32 as ffi::wxd_DragResult
+        })
+        .unwrap_or(def_result)
     } else {
         def_result
     }
@@ -435,8 +448,11 @@ extern "C" fn file_on_drag_over_trampoline(
     let callbacks = unsafe { &mut *(data_ptr as *mut FileDropTargetCallbacks) };
 
     if let Some(ref mut callback) = callbacks.on_drag_over {
-        let rust_result = callback(x, y, DragResult::from(def_result as i32));
-        rust_result as i32 as ffi::wxd_DragResult
+        // A panicking callback falls back to the default result
+        crate::panic_policy::catch("drop target", || {
+            callback(x, y, DragResult::from(def_result as i32)) as i32 as ffi::wxd_DragResult
+        })
+        .unwrap_or(def_result)
     } else {
         def_result
     }
@@ -450,7 +466,7 @@ extern "C" fn file_on_leave_trampoline(data_ptr: *mut c_void) {
     let callbacks = unsafe { &mut *(data_ptr as *mut FileDropTargetCallbacks) };
 
     if let Some(ref mut callback) = callbacks.on_leave {
-        callback();
+        crate::panic_policy::catch("drop target", callback);
     }
 }
 
@@ -462,7 +478,7 @@ extern "C" fn file_on_drop_trampoline(x: i32, y: i32, data_ptr: *mut c_void) ->
     let callbacks = unsafe { &mut *(data_ptr as *mut FileDropTargetCallbacks) };
 
     if let Some(ref mut callback) = callbacks.on_drop {
-        callback(x, y)
+        crate::panic_policy::catch("drop target", || callback(x, y)).unwrap_or(false)
     } else {
         true // Default to accepting the drop
     }
@@ -481,8 +497,11 @@ extern "C" fn file_on_data_trampoline(
     let callbacks = unsafe { &mut *(data_ptr as *mut FileDropTargetCallbacks) };
 
     if let Some(ref mut callback) = callbacks.on_data {
-        let rust_result = callback(x, y, DragResult::from(def_result as i32));
-        rust_result as i32 as ffi::wxd_DragResult
+        // A panicking callback falls back to the default result
+        crate::panic_policy::catch("drop target", || {
+            callback(x, y, DragResult::from(def_result as i32)) as i32 as ffi::wxd_DragResult
+        })
+        .unwrap_or(def_result)
     } else {
         def_result
     }
@@ -525,5 +544,6 @@ extern "C" fn file_on_drop_files_trampoline(
 
     let callbacks = unsafe { &mut *(data_ptr as *mut FileDropTargetCallbacks) };
 
-    (callbacks.on_drop_files)(filenames, x, y)
+    crate::panic_policy::catch("drop target", || (callbacks.on_drop_files)(filenames, x, y))
+        .unwrap_or(false)
 }
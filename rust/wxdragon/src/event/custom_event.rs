@@ -0,0 +1,289 @@
+//! User-defined event types carrying arbitrary Rust payloads.
+//!
+//! Custom event types are allocated at runtime with [`EventType::new_custom`] and
+//! delivered through the normal wxWidgets event machinery, so they propagate up the
+//! window hierarchy like command events and can be handled by any parent.
+//!
+//! # Example
+//! ```rust,no_run
+//! use std::sync::OnceLock;
+//! use wxdragon::prelude::*;
+//!
+//! fn download_finished() -> EventType {
+//!     static TYPE: OnceLock<EventType> = OnceLock::new();
+//!     *TYPE.get_or_init(EventType::new_custom)
+//! }
+//!
+//! # fn demo(frame: &Frame) {
+//! frame.on_custom_event(download_finished(), |event: CustomEventData<String>| {
+//!     if let Some(path) = event.payload() {
+//!         println!("Downloaded {path}");
+//!     }
+//! });
+//!
+//! // Worker threads post through a `Send` sink
+//! let sink = frame.event_sink();
+//! std::thread::spawn(move || {
+//!     sink.queue_event(CustomEvent::new(download_finished(), "file.zip".to_string()));
+//! });
+//! # }
+//! ```
+
+use crate::event::{Event, EventType, WxEvtHandler};
+use crate::window::Window;
+use std::any::Any;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::ffi::c_void;
+use std::marker::PhantomData;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, OnceLock};
+use wxdragon_sys as ffi;
+
+type Payload = Box<dyn Any + Send + 'static>;
+
+/// A user-defined event of a custom [`EventType`] carrying a payload of type `T`.
+#[derive(Debug, Clone)]
+pub struct CustomEvent<T> {
+    event_type: EventType,
+    id: i32,
+    payload: T,
+}
+
+impl<T: Send + 'static> CustomEvent<T> {
+    /// Creates a new event of the given custom type with the given payload.
+    pub fn new(event_type: EventType, payload: T) -> Self {
+        Self {
+            event_type,
+            id: ffi::WXD_ID_ANY as i32,
+            payload,
+        }
+    }
+
+    /// Sets the id the event is sent with, so handlers can tell sources apart.
+    pub fn with_id(mut self, id: i32) -> Self {
+        self.id = id;
+        self
+    }
+
+    /// Gets the event type.
+    pub fn get_event_type(&self) -> EventType {
+        self.event_type
+    }
+
+    /// Gets the id the event will be sent with.
+    pub fn get_id(&self) -> i32 {
+        self.id
+    }
+
+    /// Gets a reference to the payload.
+    pub fn payload(&self) -> &T {
+        &self.payload
+    }
+
+    /// Consumes the event and returns its payload.
+    pub fn into_payload(self) -> T {
+        self.payload
+    }
+}
+
+/// Event data passed to handlers bound with [`WxEvtHandler::on_custom_event`].
+#[derive(Debug)]
+pub struct CustomEventData<T> {
+    pub event: Event,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T: 'static> CustomEventData<T> {
+    pub fn new(event: Event) -> Self {
+        Self {
+            event,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Gets the id the event was sent with.
+    pub fn get_id(&self) -> i32 {
+        self.event.get_id()
+    }
+
+    /// Gets the handler object the event was originally sent to, if it is a window.
+    pub fn get_event_object(&self) -> Option<Window> {
+        self.event.get_event_object()
+    }
+
+    /// Gets the payload, or `None` if the event carries a payload of a different type.
+    pub fn payload(&self) -> Option<&T> {
+        custom_event_payload(&self.event)
+    }
+
+    /// Controls whether the event continues to propagate to parent windows.
+    pub fn skip(&self, skip: bool) {
+        self.event.skip(skip);
+    }
+}
+
+/// Returns the payload of a custom event if it is of type `T`.
+pub(crate) fn custom_event_payload<T: 'static>(event: &Event) -> Option<&T> {
+    if event.is_null() {
+        return None;
+    }
+    let ptr = unsafe { ffi::wxd_CustomEvent_GetPayload(event.0) };
+    if ptr.is_null() {
+        return None;
+    }
+    // The payload lives as long as the wx event, which outlives the handler call.
+    let payload = unsafe { &*(ptr as *const Payload) };
+    payload.downcast_ref::<T>()
+}
+
+/// Queues `event` on `handler`. Must be called on the main thread with a live handler.
+pub(crate) unsafe fn queue_custom_event<T: Send + 'static>(
+    handler: *mut ffi::wxd_EvtHandler_t,
+    event: CustomEvent<T>,
+) -> bool {
+    if handler.is_null() || !event.event_type.is_custom() {
+        return false;
+    }
+    let payload: Box<Payload> = Box::new(Box::new(event.payload));
+    let payload_ptr = Box::into_raw(payload) as *mut c_void;
+    // Ownership of the payload passes to C++, which drops it on failure too.
+    ffi::wxd_EvtHandler_QueueCustomEvent(
+        handler,
+        event.event_type.as_c_enum(),
+        event.id,
+        payload_ptr,
+    )
+}
+
+/// Function called by C++ to drop a custom event payload.
+///
+/// # Safety
+/// `ptr` must have been created by [`queue_custom_event`] and must not be used afterwards.
+#[no_mangle]
+pub unsafe extern "C" fn drop_rust_custom_event_payload(ptr: *mut c_void) {
+    if !ptr.is_null() {
+        let _ = Box::from_raw(ptr as *mut Payload);
+    }
+}
+
+// --- EventSink ---
+
+/// Raw handler pointer that may be moved between threads.
+/// It is only ever dereferenced on the main thread.
+#[derive(Debug, Clone, Copy)]
+struct HandlerPtr(*mut ffi::wxd_EvtHandler_t);
+
+unsafe impl Send for HandlerPtr {}
+unsafe impl Sync for HandlerPtr {}
+
+impl HandlerPtr {
+    fn as_ptr(self) -> *mut ffi::wxd_EvtHandler_t {
+        self.0
+    }
+}
+
+/// A thread-safe handle for sending custom events to an event handler.
+///
+/// Obtain one with [`WxEvtHandler::event_sink`] on the main thread, then move or
+/// clone it into worker threads. Events are handed to the main thread through the
+/// [`call_after`](crate::call_after) queue and then queued on the handler; if the
+/// handler has been destroyed in the meantime the event is silently dropped.
+#[derive(Debug, Clone)]
+pub struct EventSink {
+    handler: HandlerPtr,
+    alive: Arc<AtomicBool>,
+}
+
+/// Sets the shared liveness flag to false when the handler's closures are dropped.
+struct AliveGuard {
+    handler: usize,
+    alive: Arc<AtomicBool>,
+}
+
+impl Drop for AliveGuard {
+    fn drop(&mut self) {
+        self.alive.store(false, Ordering::Release);
+        let _ = SINK_FLAGS.try_with(|flags| {
+            flags.borrow_mut().remove(&self.handler);
+        });
+    }
+}
+
+thread_local! {
+    // Liveness flags per handler, so repeated event_sink() calls share one guard.
+    static SINK_FLAGS: RefCell<HashMap<usize, Arc<AtomicBool>>> = RefCell::new(HashMap::new());
+}
+
+/// Private event type whose only purpose is to own the liveness guards.
+fn sink_guard_event_type() -> EventType {
+    static TYPE: OnceLock<EventType> = OnceLock::new();
+    *TYPE.get_or_init(EventType::new_custom)
+}
+
+impl EventSink {
+    pub(crate) fn new<H: WxEvtHandler + ?Sized>(handler: &H) -> Self {
+        let handler_ptr = unsafe { handler.get_event_handler_ptr() };
+        if handler_ptr.is_null() {
+            return Self {
+                handler: HandlerPtr(handler_ptr),
+                alive: Arc::new(AtomicBool::new(false)),
+            };
+        }
+
+        let key = handler_ptr as usize;
+        let existing = SINK_FLAGS.with(|flags| flags.borrow().get(&key).cloned());
+        let alive = match existing {
+            Some(alive) => alive,
+            None => {
+                let alive = Arc::new(AtomicBool::new(true));
+                let guard = AliveGuard {
+                    handler: key,
+                    alive: alive.clone(),
+                };
+                // The closure never runs; it is dropped together with the handler's
+                // other closures when the handler is destroyed.
+                handler.bind_internal(sink_guard_event_type(), move |_event| {
+                    let _ = &guard;
+                });
+                SINK_FLAGS.with(|flags| {
+                    flags.borrow_mut().insert(key, alive.clone());
+                });
+                alive
+            }
+        };
+
+        Self {
+            handler: HandlerPtr(handler_ptr),
+            alive,
+        }
+    }
+
+    /// Returns `false` once the target handler has been destroyed.
+    pub fn is_alive(&self) -> bool {
+        self.alive.load(Ordering::Acquire)
+    }
+
+    /// Queues `event` for delivery to the target handler. Can be called from any thread.
+    ///
+    /// Returns `false` if the handler is already known to be destroyed.
+    pub fn queue_event<T: Send + 'static>(&self, event: CustomEvent<T>) -> bool {
+        if !self.is_alive() {
+            return false;
+        }
+        let handler = self.handler;
+        let alive = self.alive.clone();
+        crate::app::call_after(Box::new(move || {
+            // Checked again on the main thread, where handlers are destroyed.
+            if alive.load(Ordering::Acquire) {
+                unsafe { queue_custom_event(handler.as_ptr(), event) };
+            }
+        }));
+        true
+    }
+
+    /// Posts a copy of `event` to the target handler. Can be called from any thread.
+    pub fn post_event<T: Clone + Send + 'static>(&self, event: &CustomEvent<T>) -> bool {
+        self.queue_event(event.clone())
+    }
+}
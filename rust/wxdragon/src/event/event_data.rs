@@ -1,5 +1,6 @@
-use crate::event::Event;
+use crate::event::{Event, KeyCode, Modifiers, MouseButton, MouseButtons, WheelAxis};
 use crate::geometry::Point;
+use wxdragon_sys as ffi;
 
 /// Common data for command events (button clicks, menu selections, etc.)
 #[derive(Debug)]
@@ -33,6 +34,50 @@ impl CommandEventData {
     }
 }
 
+/// Implements `get_modifiers` and the `*_down` queries for event data types, given
+/// the FFI function reading the event's modifier state.
+macro_rules! impl_modifier_accessors {
+    ($($data:ty => $get_modifiers:ident),+) => {$(
+        impl $data {
+            /// Gets the modifier keys held during the event.
+            pub fn get_modifiers(&self) -> Modifiers {
+                Modifiers::from_bits_truncate(unsafe { ffi::$get_modifiers(self.event.0) })
+            }
+
+            /// Returns true if Control (Command on macOS) was held.
+            pub fn ctrl_down(&self) -> bool {
+                self.get_modifiers().contains(Modifiers::CONTROL)
+            }
+
+            /// Returns true if Shift was held.
+            pub fn shift_down(&self) -> bool {
+                self.get_modifiers().contains(Modifiers::SHIFT)
+            }
+
+            /// Returns true if Alt (Option on macOS) was held.
+            pub fn alt_down(&self) -> bool {
+                self.get_modifiers().contains(Modifiers::ALT)
+            }
+
+            /// Returns true if Meta (the Windows key) was held.
+            pub fn meta_down(&self) -> bool {
+                self.get_modifiers().contains(Modifiers::META)
+            }
+
+            /// Returns true if the platform's shortcut modifier (Command on macOS,
+            /// Control elsewhere) was held.
+            pub fn cmd_down(&self) -> bool {
+                self.get_modifiers().contains(Modifiers::CMD)
+            }
+        }
+    )+};
+}
+
+impl_modifier_accessors!(
+    MouseEventData => wxd_MouseEvent_GetModifiers,
+    KeyEventData => wxd_KeyEvent_GetModifiers
+);
+
 /// Data for mouse events
 #[derive(Debug)]
 pub struct MouseEventData {
@@ -62,6 +107,48 @@ impl MouseEventData {
         self.event.get_wheel_delta()
     }
 
+    /// Gets the axis of a mouse wheel event.
+    pub fn get_wheel_axis(&self) -> WheelAxis {
+        match unsafe { ffi::wxd_MouseEvent_GetWheelAxis(self.event.0) } {
+            1 => WheelAxis::Horizontal,
+            _ => WheelAxis::Vertical,
+        }
+    }
+
+    /// Gets how many lines to scroll per wheel delta, as configured by the user.
+    pub fn wheel_lines_per_action(&self) -> i32 {
+        unsafe { ffi::wxd_MouseEvent_GetLinesPerAction(self.event.0) }
+    }
+
+    /// Returns true if the system is set to scroll a page per wheel delta
+    /// instead of [`wheel_lines_per_action`](Self::wheel_lines_per_action) lines.
+    pub fn is_page_scroll(&self) -> bool {
+        unsafe { ffi::wxd_MouseEvent_IsPageScroll(self.event.0) }
+    }
+
+    /// Gets the button whose state changed, or `None` for motion and wheel events.
+    pub fn get_button(&self) -> Option<MouseButton> {
+        MouseButton::from_raw(unsafe { ffi::wxd_MouseEvent_GetButton(self.event.0) })
+    }
+
+    /// Gets the number of clicks: 1 for a single click, 2 for a double click.
+    pub fn get_click_count(&self) -> i32 {
+        unsafe { ffi::wxd_MouseEvent_GetClickCount(self.event.0) }
+    }
+
+    /// Gets the buttons held during the event.
+    pub fn get_buttons_down(&self) -> MouseButtons {
+        MouseButtons::from_bits_truncate(unsafe {
+            ffi::wxd_MouseEvent_GetButtonState(self.event.0)
+        })
+    }
+
+    /// Returns true if `button` was held during the event.
+    pub fn is_button_down(&self, button: MouseButton) -> bool {
+        self.get_buttons_down()
+            .contains(MouseButtons::from_button(button))
+    }
+
     pub fn skip(&self, skip: bool) {
         self.event.skip(skip);
     }
@@ -86,6 +173,30 @@ impl KeyEventData {
         self.event.get_unicode_key()
     }
 
+    /// Gets the key as a [`KeyCode`]. Letters are reported in upper case in key
+    /// down and up events; use [`get_unicode_key`](Self::get_unicode_key) in char
+    /// events for the typed character.
+    pub fn get_key(&self) -> Option<KeyCode> {
+        self.event.get_key_code().map(KeyCode::from_raw)
+    }
+
+    /// Gets the platform's own key code: the virtual key code on Windows, the
+    /// GDK key value on GTK and the key code on macOS.
+    pub fn raw_key_code(&self) -> u32 {
+        unsafe { ffi::wxd_KeyEvent_GetRawKeyCode(self.event.0) }
+    }
+
+    /// Gets the platform's key flags: `lParam` on Windows, the hardware key code
+    /// on GTK and the modifier flags on macOS.
+    pub fn raw_key_flags(&self) -> u32 {
+        unsafe { ffi::wxd_KeyEvent_GetRawKeyFlags(self.event.0) }
+    }
+
+    /// Returns true for key down events repeated while the key is held.
+    pub fn is_auto_repeat(&self) -> bool {
+        unsafe { ffi::wxd_KeyEvent_IsAutoRepeat(self.event.0) }
+    }
+
     pub fn skip(&self, skip: bool) {
         self.event.skip(skip);
     }
@@ -0,0 +1,109 @@
+//! Application-wide event filters.
+//!
+//! An [`EventFilter`] sees every event before it reaches any window or handler, which
+//! makes it suitable for inactivity timers, global shortcut overlays and usage
+//! telemetry:
+//!
+//! ```rust,no_run
+//! use std::time::Instant;
+//! use wxdragon::prelude::*;
+//!
+//! let mut last_input = Instant::now();
+//! let _filter = EventFilter::add(move |event: &Event| {
+//!     if matches!(
+//!         event.get_event_type(),
+//!         Some(EventType::KEY_DOWN) | Some(EventType::LEFT_DOWN) | Some(EventType::MOTION)
+//!     ) {
+//!         last_input = Instant::now();
+//!     }
+//!     FilterResult::Skip
+//! });
+//! ```
+//!
+//! The filter runs on the GUI thread for every event, so it should return quickly.
+
+use crate::event::Event;
+use std::cell::RefCell;
+use std::ffi::c_void;
+use wxdragon_sys as ffi;
+
+/// What an event filter decides to do with an event.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FilterResult {
+    /// Let the event be processed normally.
+    #[default]
+    Skip,
+    /// Stop processing; the event is treated as not handled.
+    Ignore,
+    /// Stop processing; the event is treated as handled.
+    Processed,
+}
+
+impl FilterResult {
+    fn to_raw(self) -> i32 {
+        match self {
+            FilterResult::Skip => ffi::wxd_EventFilterResult_WXD_EVENT_FILTER_SKIP,
+            FilterResult::Ignore => ffi::wxd_EventFilterResult_WXD_EVENT_FILTER_IGNORE,
+            FilterResult::Processed => ffi::wxd_EventFilterResult_WXD_EVENT_FILTER_PROCESSED,
+        }
+    }
+}
+
+type FilterCallback = RefCell<Box<dyn FnMut(&Event) -> FilterResult>>;
+
+/// A global event filter, registered until it is dropped or [`remove`](Self::remove)d.
+///
+/// Filters added later are called before those added earlier.
+pub struct EventFilter {
+    ptr: *mut ffi::wxd_EventFilter_t,
+    callback: *mut FilterCallback,
+}
+
+impl EventFilter {
+    /// Registers `callback` to be called for every event in the application.
+    ///
+    /// Must be called on the GUI thread after wxWidgets has been initialized.
+    pub fn add<F>(callback: F) -> Self
+    where
+        F: FnMut(&Event) -> FilterResult + 'static,
+    {
+        let callback: Box<FilterCallback> = Box::new(RefCell::new(Box::new(callback)));
+        let callback = Box::into_raw(callback);
+        let ptr = unsafe {
+            ffi::wxd_EventFilter_Add(Some(event_filter_trampoline), callback as *mut c_void)
+        };
+        Self { ptr, callback }
+    }
+
+    /// Unregisters the filter.
+    pub fn remove(self) {
+        drop(self);
+    }
+}
+
+impl Drop for EventFilter {
+    fn drop(&mut self) {
+        unsafe {
+            ffi::wxd_EventFilter_Remove(self.ptr);
+            drop(Box::from_raw(self.callback));
+        }
+    }
+}
+
+unsafe extern "C" fn event_filter_trampoline(
+    user_data: *mut c_void,
+    event: *mut ffi::wxd_Event_t,
+) -> i32 {
+    if user_data.is_null() || event.is_null() {
+        return FilterResult::Skip.to_raw();
+    }
+    let callback = &*(user_data as *const FilterCallback);
+    // Events raised while the filter itself is running (e.g. from a nested loop) pass through
+    let Ok(mut callback) = callback.try_borrow_mut() else {
+        return FilterResult::Skip.to_raw();
+    };
+    let event = Event::from_ptr(event);
+    crate::panic_policy::catch("event filter", || callback(&event))
+        .unwrap_or_default()
+        .to_raw()
+}
@@ -0,0 +1,280 @@
+//! Keyboard types shared by key events, accelerators and input simulation.
+
+use std::fmt;
+use wxdragon_sys as ffi;
+
+bitflags::bitflags! {
+    /// Modifier keys held during a key or mouse event (`wxKeyModifier`).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct Modifiers: i32 {
+        /// The Alt key (Option on macOS).
+        const ALT = ffi::WXD_MOD_ALT as i32;
+        /// The Control key, or Command on macOS.
+        const CONTROL = ffi::WXD_MOD_CONTROL as i32;
+        /// The Shift key.
+        const SHIFT = ffi::WXD_MOD_SHIFT as i32;
+        /// The Meta key: Windows key on Windows and Linux, Command on macOS.
+        const META = ffi::WXD_MOD_META as i32;
+        /// The physical Control key, even on macOS. Same as `CONTROL` elsewhere.
+        const RAW_CONTROL = ffi::WXD_MOD_RAW_CONTROL as i32;
+        /// The platform's main shortcut modifier: Command on macOS, Control elsewhere.
+        const CMD = ffi::WXD_MOD_CMD as i32;
+    }
+}
+
+/// Declares the named variants of [`KeyCode`] together with their raw codes and names.
+macro_rules! key_codes {
+    ($($(#[$doc:meta])* $variant:ident = $raw:ident, $name:literal;)*) => {
+        /// A key, as reported by key events and used in accelerators (`wxKeyCode`).
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub enum KeyCode {
+            /// A key producing a printable character. Key down and up events report
+            /// letters in upper case; raw codes keep the case either way.
+            Char(char),
+            $($(#[$doc])* $variant,)*
+            /// A key without a named variant, by its raw code.
+            Other(i32),
+        }
+
+        impl KeyCode {
+            /// Converts a raw `wxKeyCode` value.
+            pub fn from_raw(code: i32) -> Self {
+                $(if code == ffi::$raw as i32 {
+                    return KeyCode::$variant;
+                })*
+                match u32::try_from(code).ok().and_then(char::from_u32) {
+                    Some(c) if !c.is_control() => KeyCode::Char(c),
+                    _ => KeyCode::Other(code),
+                }
+            }
+
+            /// Returns the raw `wxKeyCode` value.
+            pub fn to_raw(self) -> i32 {
+                match self {
+                    KeyCode::Char(c) => c as i32,
+                    $(KeyCode::$variant => ffi::$raw as i32,)*
+                    KeyCode::Other(code) => code,
+                }
+            }
+
+            /// Returns the key's name as used in shortcut strings, e.g. `"PageUp"`,
+            /// or `None` for [`Char`](KeyCode::Char) and [`Other`](KeyCode::Other).
+            pub fn name(self) -> Option<&'static str> {
+                match self {
+                    $(KeyCode::$variant => Some($name),)*
+                    _ => None,
+                }
+            }
+
+            /// Looks up a named key, ignoring case.
+            pub fn from_name(name: &str) -> Option<Self> {
+                $(if name.eq_ignore_ascii_case($name) {
+                    return Some(KeyCode::$variant);
+                })*
+                None
+            }
+        }
+    };
+}
+
+key_codes! {
+    Back = WXD_WXK_BACK, "Backspace";
+    Tab = WXD_WXK_TAB, "Tab";
+    Return = WXD_WXK_RETURN, "Enter";
+    Escape = WXD_WXK_ESCAPE, "Esc";
+    Space = WXD_WXK_SPACE, "Space";
+    Delete = WXD_WXK_DELETE, "Delete";
+    Insert = WXD_WXK_INSERT, "Insert";
+    Home = WXD_WXK_HOME, "Home";
+    End = WXD_WXK_END, "End";
+    PageUp = WXD_WXK_PAGEUP, "PageUp";
+    PageDown = WXD_WXK_PAGEDOWN, "PageDown";
+    Left = WXD_WXK_LEFT, "Left";
+    Up = WXD_WXK_UP, "Up";
+    Right = WXD_WXK_RIGHT, "Right";
+    Down = WXD_WXK_DOWN, "Down";
+    F1 = WXD_WXK_F1, "F1";
+    F2 = WXD_WXK_F2, "F2";
+    F3 = WXD_WXK_F3, "F3";
+    F4 = WXD_WXK_F4, "F4";
+    F5 = WXD_WXK_F5, "F5";
+    F6 = WXD_WXK_F6, "F6";
+    F7 = WXD_WXK_F7, "F7";
+    F8 = WXD_WXK_F8, "F8";
+    F9 = WXD_WXK_F9, "F9";
+    F10 = WXD_WXK_F10, "F10";
+    F11 = WXD_WXK_F11, "F11";
+    F12 = WXD_WXK_F12, "F12";
+    F13 = WXD_WXK_F13, "F13";
+    F14 = WXD_WXK_F14, "F14";
+    F15 = WXD_WXK_F15, "F15";
+    F16 = WXD_WXK_F16, "F16";
+    F17 = WXD_WXK_F17, "F17";
+    F18 = WXD_WXK_F18, "F18";
+    F19 = WXD_WXK_F19, "F19";
+    F20 = WXD_WXK_F20, "F20";
+    F21 = WXD_WXK_F21, "F21";
+    F22 = WXD_WXK_F22, "F22";
+    F23 = WXD_WXK_F23, "F23";
+    F24 = WXD_WXK_F24, "F24";
+    // Modifier, lock and Windows keys, reported on their own key down and up
+    Shift = WXD_WXK_SHIFT, "Shift";
+    Alt = WXD_WXK_ALT, "Alt";
+    Control = WXD_WXK_CONTROL, "Control";
+    CapsLock = WXD_WXK_CAPITAL, "CapsLock";
+    NumLock = WXD_WXK_NUMLOCK, "NumLock";
+    ScrollLock = WXD_WXK_SCROLL, "ScrollLock";
+    WindowsLeft = WXD_WXK_WINDOWS_LEFT, "WindowsLeft";
+    WindowsRight = WXD_WXK_WINDOWS_RIGHT, "WindowsRight";
+    WindowsMenu = WXD_WXK_WINDOWS_MENU, "WindowsMenu";
+    // Other special keys
+    Cancel = WXD_WXK_CANCEL, "Cancel";
+    Clear = WXD_WXK_CLEAR, "Clear";
+    Menu = WXD_WXK_MENU, "Menu";
+    Pause = WXD_WXK_PAUSE, "Pause";
+    Select = WXD_WXK_SELECT, "Select";
+    Print = WXD_WXK_PRINT, "Print";
+    Execute = WXD_WXK_EXECUTE, "Execute";
+    PrintScreen = WXD_WXK_SNAPSHOT, "PrintScreen";
+    Help = WXD_WXK_HELP, "Help";
+    // Numeric keypad. The operator keys without a prefix are sent by some keyboards only.
+    Numpad0 = WXD_WXK_NUMPAD0, "Numpad0";
+    Numpad1 = WXD_WXK_NUMPAD1, "Numpad1";
+    Numpad2 = WXD_WXK_NUMPAD2, "Numpad2";
+    Numpad3 = WXD_WXK_NUMPAD3, "Numpad3";
+    Numpad4 = WXD_WXK_NUMPAD4, "Numpad4";
+    Numpad5 = WXD_WXK_NUMPAD5, "Numpad5";
+    Numpad6 = WXD_WXK_NUMPAD6, "Numpad6";
+    Numpad7 = WXD_WXK_NUMPAD7, "Numpad7";
+    Numpad8 = WXD_WXK_NUMPAD8, "Numpad8";
+    Numpad9 = WXD_WXK_NUMPAD9, "Numpad9";
+    Multiply = WXD_WXK_MULTIPLY, "Multiply";
+    Add = WXD_WXK_ADD, "Add";
+    Separator = WXD_WXK_SEPARATOR, "Separator";
+    Subtract = WXD_WXK_SUBTRACT, "Subtract";
+    Decimal = WXD_WXK_DECIMAL, "Decimal";
+    Divide = WXD_WXK_DIVIDE, "Divide";
+    NumpadSpace = WXD_WXK_NUMPAD_SPACE, "NumpadSpace";
+    NumpadTab = WXD_WXK_NUMPAD_TAB, "NumpadTab";
+    NumpadEnter = WXD_WXK_NUMPAD_ENTER, "NumpadEnter";
+    NumpadF1 = WXD_WXK_NUMPAD_F1, "NumpadF1";
+    NumpadF2 = WXD_WXK_NUMPAD_F2, "NumpadF2";
+    NumpadF3 = WXD_WXK_NUMPAD_F3, "NumpadF3";
+    NumpadF4 = WXD_WXK_NUMPAD_F4, "NumpadF4";
+    NumpadHome = WXD_WXK_NUMPAD_HOME, "NumpadHome";
+    NumpadLeft = WXD_WXK_NUMPAD_LEFT, "NumpadLeft";
+    NumpadUp = WXD_WXK_NUMPAD_UP, "NumpadUp";
+    NumpadRight = WXD_WXK_NUMPAD_RIGHT, "NumpadRight";
+    NumpadDown = WXD_WXK_NUMPAD_DOWN, "NumpadDown";
+    NumpadPageUp = WXD_WXK_NUMPAD_PAGEUP, "NumpadPageUp";
+    NumpadPageDown = WXD_WXK_NUMPAD_PAGEDOWN, "NumpadPageDown";
+    NumpadEnd = WXD_WXK_NUMPAD_END, "NumpadEnd";
+    NumpadBegin = WXD_WXK_NUMPAD_BEGIN, "NumpadBegin";
+    NumpadInsert = WXD_WXK_NUMPAD_INSERT, "NumpadInsert";
+    NumpadDelete = WXD_WXK_NUMPAD_DELETE, "NumpadDelete";
+    NumpadEqual = WXD_WXK_NUMPAD_EQUAL, "NumpadEqual";
+    NumpadMultiply = WXD_WXK_NUMPAD_MULTIPLY, "NumpadMultiply";
+    NumpadAdd = WXD_WXK_NUMPAD_ADD, "NumpadAdd";
+    NumpadSeparator = WXD_WXK_NUMPAD_SEPARATOR, "NumpadSeparator";
+    NumpadSubtract = WXD_WXK_NUMPAD_SUBTRACT, "NumpadSubtract";
+    NumpadDecimal = WXD_WXK_NUMPAD_DECIMAL, "NumpadDecimal";
+    NumpadDivide = WXD_WXK_NUMPAD_DIVIDE, "NumpadDivide";
+    // Browser, volume, media and launcher keys
+    BrowserBack = WXD_WXK_BROWSER_BACK, "BrowserBack";
+    BrowserForward = WXD_WXK_BROWSER_FORWARD, "BrowserForward";
+    BrowserRefresh = WXD_WXK_BROWSER_REFRESH, "BrowserRefresh";
+    BrowserStop = WXD_WXK_BROWSER_STOP, "BrowserStop";
+    BrowserSearch = WXD_WXK_BROWSER_SEARCH, "BrowserSearch";
+    BrowserFavorites = WXD_WXK_BROWSER_FAVORITES, "BrowserFavorites";
+    BrowserHome = WXD_WXK_BROWSER_HOME, "BrowserHome";
+    VolumeMute = WXD_WXK_VOLUME_MUTE, "VolumeMute";
+    VolumeDown = WXD_WXK_VOLUME_DOWN, "VolumeDown";
+    VolumeUp = WXD_WXK_VOLUME_UP, "VolumeUp";
+    MediaNextTrack = WXD_WXK_MEDIA_NEXT_TRACK, "MediaNextTrack";
+    MediaPrevTrack = WXD_WXK_MEDIA_PREV_TRACK, "MediaPrevTrack";
+    MediaStop = WXD_WXK_MEDIA_STOP, "MediaStop";
+    MediaPlayPause = WXD_WXK_MEDIA_PLAY_PAUSE, "MediaPlayPause";
+    LaunchMail = WXD_WXK_LAUNCH_MAIL, "LaunchMail";
+    LaunchApp1 = WXD_WXK_LAUNCH_APP1, "LaunchApp1";
+    LaunchApp2 = WXD_WXK_LAUNCH_APP2, "LaunchApp2";
+}
+
+impl KeyCode {
+    /// Returns true for the modifier keys themselves: Shift, Alt, Control and the
+    /// Windows keys.
+    pub fn is_modifier(self) -> bool {
+        matches!(
+            self,
+            KeyCode::Shift
+                | KeyCode::Alt
+                | KeyCode::Control
+                | KeyCode::WindowsLeft
+                | KeyCode::WindowsRight
+        )
+    }
+}
+
+impl fmt::Display for KeyCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            KeyCode::Char(c) => write!(f, "{}", c.to_ascii_uppercase()),
+            KeyCode::Other(code) => write!(f, "#{code}"),
+            named => write!(f, "{}", named.name().unwrap_or_default()),
+        }
+    }
+}
+
+impl From<KeyCode> for i32 {
+    fn from(key: KeyCode) -> Self {
+        key.to_raw()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn names_round_trip() {
+        assert_eq!(KeyCode::from_name("pageup"), Some(KeyCode::PageUp));
+        assert_eq!(KeyCode::from_name("F12"), Some(KeyCode::F12));
+        assert_eq!(KeyCode::from_name("Nope"), None);
+        for key in [KeyCode::Back, KeyCode::Escape, KeyCode::Down, KeyCode::F24] {
+            assert_eq!(KeyCode::from_name(key.name().unwrap()), Some(key));
+        }
+        assert_eq!(KeyCode::Char('a').name(), None);
+    }
+
+    #[test]
+    fn modifier_keys() {
+        assert!(KeyCode::Shift.is_modifier());
+        assert!(KeyCode::Control.is_modifier());
+        assert!(!KeyCode::Char('S').is_modifier());
+        assert!(!KeyCode::F4.is_modifier());
+    }
+
+    #[test]
+    fn raw_codes_match_wx_key_codes() {
+        assert_eq!(KeyCode::Char('A').to_raw(), 'A' as i32);
+        assert_eq!(KeyCode::Return.to_raw(), 13);
+        assert_eq!(KeyCode::Escape.to_raw(), 27);
+        assert_eq!(KeyCode::Left.to_raw(), 314);
+        assert_eq!(KeyCode::F1.to_raw(), 340);
+        assert_eq!(KeyCode::Other(9999).to_raw(), 9999);
+        for key in [KeyCode::Char('Z'), KeyCode::Tab, KeyCode::End, KeyCode::F5] {
+            assert_eq!(KeyCode::from_raw(key.to_raw()), key);
+        }
+    }
+
+    #[test]
+    fn raw_codes_keep_letter_case() {
+        assert_eq!(KeyCode::from_raw('a' as i32), KeyCode::Char('a'));
+        assert_eq!(KeyCode::Char('a').to_raw(), 'a' as i32);
+        for raw in ['a', 'A', 'z', '1', '+', 'é'].map(|c| c as i32) {
+            assert_eq!(KeyCode::from_raw(raw).to_raw(), raw);
+        }
+        for key in [KeyCode::Char('q'), KeyCode::Char('Q'), KeyCode::Char('ß')] {
+            assert_eq!(KeyCode::from_raw(key.to_raw()), key);
+        }
+    }
+}
@@ -8,7 +8,7 @@ macro_rules! implement_window_event_handlers {
         impl $widget {
             // Internal binding method
             #[doc(hidden)]
-            pub(crate) fn bind_window_event<F>(&self, event_type: $crate::event::WindowEvent, mut callback: F)
+            pub(crate) fn bind_window_event<F>(&self, event_type: $crate::event::WindowEvent, mut callback: F) -> $crate::event::EventBinding
             where
                 F: FnMut($crate::event::WindowEventData) + 'static
             {
@@ -29,14 +29,14 @@ macro_rules! implement_window_event_handlers {
                 };
 
                 // Use internal bind method
-                $crate::event::WxEvtHandler::bind_internal(self, event_type_ffi, wrapper);
+                $crate::event::WxEvtHandler::bind_internal(self, event_type_ffi, wrapper)
             }
 
             // Generate public on_* methods
             $(
                 paste::paste! {
                     /// Binds a handler to a window event
-                    pub fn [<on_ $method_name>]<F>(&self, mut callback: F)
+                    pub fn [<on_ $method_name>]<F>(&self, mut callback: F) -> $crate::event::EventBinding
                     where
                         F: FnMut($crate::event::[<$event_data Event>]) + 'static
                     {
@@ -44,7 +44,7 @@ macro_rules! implement_window_event_handlers {
                             if let $crate::event::WindowEventData::$event_data(typed_event) = event {
                                 callback(typed_event);
                             }
-                        });
+                        })
                     }
                 }
             )*
@@ -61,7 +61,7 @@ macro_rules! implement_category_event_handlers {
         pub trait $trait_name: $crate::event::WxEvtHandler {
             // Internal binding method
             #[doc(hidden)]
-            fn bind_category_event<F>(&self, event: $crate::event::$event_enum, mut callback: F)
+            fn bind_category_event<F>(&self, event: $crate::event::$event_enum, mut callback: F) -> $crate::event::EventBinding
             where
                 F: FnMut($crate::event::$event_data) + 'static
             {
@@ -77,14 +77,14 @@ macro_rules! implement_category_event_handlers {
                 };
 
                 // Use internal bind method
-                $crate::event::WxEvtHandler::bind_internal(self, event_type, wrapper);
+                $crate::event::WxEvtHandler::bind_internal(self, event_type, wrapper)
             }
 
             // Public helper methods
             $(
                 paste::paste! {
                     /// Binds a handler to a category-specific event
-                    fn [<on_ $method_name>]<F>(&self, callback: F)
+                    fn [<on_ $method_name>]<F>(&self, callback: F) -> $crate::event::EventBinding
                     where
                         F: FnMut($crate::event::$event_data) + 'static
                     {
@@ -104,7 +104,7 @@ macro_rules! implement_widget_local_event_handlers {
         impl $widget {
             // Internal binding method
             #[doc(hidden)]
-            pub(crate) fn bind_widget_event<F>(&self, event: $event_enum, mut callback: F)
+            pub(crate) fn bind_widget_event<F>(&self, event: $event_enum, mut callback: F) -> $crate::event::EventBinding
             where
                 F: FnMut($event_data) + 'static
             {
@@ -120,14 +120,14 @@ macro_rules! implement_widget_local_event_handlers {
                 };
 
                 // Use internal bind method
-                $crate::event::WxEvtHandler::bind_internal(self, event_type, wrapper);
+                $crate::event::WxEvtHandler::bind_internal(self, event_type, wrapper)
             }
 
             // Public helper methods
             $(
                 paste::paste! {
                     /// Binds a handler to a widget-specific event
-                    pub fn [<on_ $method_name>]<F>(&self, callback: F)
+                    pub fn [<on_ $method_name>]<F>(&self, callback: F) -> $crate::event::EventBinding
                     where
                         F: FnMut($event_data) + 'static
                     {
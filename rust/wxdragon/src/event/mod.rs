@@ -5,11 +5,16 @@ use crate::window::Window;
 use std::boxed::Box;
 use std::ffi::c_void;
 use std::ffi::CStr;
+use std::marker::PhantomData;
 use std::os::raw::c_char;
 use wxdragon_sys as ffi;
 pub mod button_events;
+pub mod custom_event;
 pub mod event_data;
+pub mod filter;
+pub mod keys;
 pub mod macros;
+pub mod mouse;
 pub mod scroll_events;
 pub mod taskbar_events;
 pub mod text_events;
@@ -18,10 +23,23 @@ pub mod window_events;
 
 // Re-export window events for easier access
 pub use window_events::{
-    IdleEventData, KeyboardEvent, MouseButtonEvent, MouseEnterEvent, MouseLeaveEvent,
-    MouseMotionEvent, WindowEvent, WindowEventData, WindowEvents, WindowSizeEvent,
+    ContextMenuEvent, IdleEventData, KeyboardEvent, MouseButtonEvent, MouseEnterEvent,
+    MouseLeaveEvent, MouseMotionEvent, NavigationKeyEvent, WindowEvent, WindowEventData,
+    WindowEvents, WindowSizeEvent,
 };
 
+// Re-export custom events for easier access
+pub use custom_event::{CustomEvent, CustomEventData, EventSink};
+
+// Re-export global event filters
+pub use filter::{EventFilter, FilterResult};
+
+// Re-export keyboard types
+pub use keys::{KeyCode, Modifiers};
+
+// Re-export mouse types
+pub use mouse::{MouseButton, MouseButtons, WheelAxis};
+
 // Re-export button events for easier access
 pub use button_events::{ButtonEvent, ButtonEventData, ButtonEvents};
 
@@ -67,6 +85,17 @@ impl EventType {
     pub const MIDDLE_UP: EventType = EventType(ffi::WXDEventTypeCEnum_WXD_EVENT_TYPE_MIDDLE_UP);
     pub const MOTION: EventType = EventType(ffi::WXDEventTypeCEnum_WXD_EVENT_TYPE_MOTION);
     pub const MOUSEWHEEL: EventType = EventType(ffi::WXDEventTypeCEnum_WXD_EVENT_TYPE_MOUSEWHEEL);
+    pub const LEFT_DCLICK: EventType = EventType(ffi::WXDEventTypeCEnum_WXD_EVENT_TYPE_LEFT_DCLICK);
+    pub const RIGHT_DCLICK: EventType =
+        EventType(ffi::WXDEventTypeCEnum_WXD_EVENT_TYPE_RIGHT_DCLICK);
+    pub const MIDDLE_DCLICK: EventType =
+        EventType(ffi::WXDEventTypeCEnum_WXD_EVENT_TYPE_MIDDLE_DCLICK);
+    pub const AUX1_DOWN: EventType = EventType(ffi::WXDEventTypeCEnum_WXD_EVENT_TYPE_AUX1_DOWN);
+    pub const AUX1_UP: EventType = EventType(ffi::WXDEventTypeCEnum_WXD_EVENT_TYPE_AUX1_UP);
+    pub const AUX1_DCLICK: EventType = EventType(ffi::WXDEventTypeCEnum_WXD_EVENT_TYPE_AUX1_DCLICK);
+    pub const AUX2_DOWN: EventType = EventType(ffi::WXDEventTypeCEnum_WXD_EVENT_TYPE_AUX2_DOWN);
+    pub const AUX2_UP: EventType = EventType(ffi::WXDEventTypeCEnum_WXD_EVENT_TYPE_AUX2_UP);
+    pub const AUX2_DCLICK: EventType = EventType(ffi::WXDEventTypeCEnum_WXD_EVENT_TYPE_AUX2_DCLICK);
     pub const ENTER_WINDOW: EventType =
         EventType(ffi::WXDEventTypeCEnum_WXD_EVENT_TYPE_ENTER_WINDOW);
     pub const LEAVE_WINDOW: EventType =
@@ -74,6 +103,10 @@ impl EventType {
     pub const KEY_DOWN: EventType = EventType(ffi::WXDEventTypeCEnum_WXD_EVENT_TYPE_KEY_DOWN);
     pub const KEY_UP: EventType = EventType(ffi::WXDEventTypeCEnum_WXD_EVENT_TYPE_KEY_UP);
     pub const CHAR: EventType = EventType(ffi::WXDEventTypeCEnum_WXD_EVENT_TYPE_CHAR);
+    pub const NAVIGATION_KEY: EventType =
+        EventType(ffi::WXDEventTypeCEnum_WXD_EVENT_TYPE_NAVIGATION_KEY);
+    pub const CONTEXT_MENU: EventType =
+        EventType(ffi::WXDEventTypeCEnum_WXD_EVENT_TYPE_CONTEXT_MENU);
     pub const COMMAND_RADIOBUTTON_SELECTED: EventType =
         EventType(ffi::WXDEventTypeCEnum_WXD_EVENT_TYPE_COMMAND_RADIOBUTTON_SELECTED);
     pub const COMMAND_RADIOBOX_SELECTED: EventType =
@@ -519,6 +552,44 @@ impl EventType {
     pub const TASKBAR_BALLOON_CLICK: EventType =
         EventType(ffi::WXDEventTypeCEnum_WXD_EVENT_TYPE_TASKBAR_BALLOON_CLICK);
 
+    // Dialog events
+    pub const WINDOW_MODAL_DIALOG_CLOSED: EventType =
+        EventType(ffi::WXDEventTypeCEnum_WXD_EVENT_TYPE_WINDOW_MODAL_DIALOG_CLOSED);
+
+    // Child process events
+    pub const PROCESS_OUTPUT: EventType =
+        EventType(ffi::WXDEventTypeCEnum_WXD_EVENT_TYPE_PROCESS_OUTPUT);
+    pub const PROCESS_ERROR_OUTPUT: EventType =
+        EventType(ffi::WXDEventTypeCEnum_WXD_EVENT_TYPE_PROCESS_ERROR_OUTPUT);
+    pub const END_PROCESS: EventType = EventType(ffi::WXDEventTypeCEnum_WXD_EVENT_TYPE_END_PROCESS);
+
+    // File system watcher events
+    pub const FSWATCHER: EventType = EventType(ffi::WXDEventTypeCEnum_WXD_EVENT_TYPE_FSWATCHER);
+
+    /// Allocates a new, unique event type for application-defined events.
+    ///
+    /// Each call returns a different type, so allocate once and keep the value,
+    /// e.g. in a `OnceLock`. Use it with [`CustomEvent`] and
+    /// [`WxEvtHandler::on_custom_event`].
+    ///
+    /// # Panics
+    /// Panics if the range reserved for custom event types is exhausted.
+    pub fn new_custom() -> EventType {
+        let c_enum = unsafe { ffi::wxd_EventType_NewCustom() };
+        assert!(
+            c_enum != ffi::WXDEventTypeCEnum_WXD_EVENT_TYPE_NULL,
+            "Ran out of custom event types"
+        );
+        EventType(c_enum)
+    }
+
+    /// Returns `true` if this type was allocated with [`EventType::new_custom`].
+    pub fn is_custom(&self) -> bool {
+        (ffi::WXDEventTypeCEnum_WXD_EVENT_TYPE_CUSTOM_FIRST
+            ..=ffi::WXDEventTypeCEnum_WXD_EVENT_TYPE_CUSTOM_LAST)
+            .contains(&self.0)
+    }
+
     /// Get the underlying stable C enum value.
     pub(crate) fn as_c_enum(&self) -> ffi::WXDEventTypeCEnum {
         self.0
@@ -620,11 +691,10 @@ impl Event {
         }
         let event_type_c = unsafe { ffi::wxd_Event_GetEventType(self.0) };
         // If event_type_c is WXD_EVENT_TYPE_NULL or an invalid value, return None
-        if event_type_c
-            == ffi::WXDEventTypeCEnum_WXD_EVENT_TYPE_NULL
-                .try_into()
-                .unwrap()
-        {
+        let null_type: i32 = ffi::WXDEventTypeCEnum_WXD_EVENT_TYPE_NULL
+            .try_into()
+            .unwrap();
+        if event_type_c == null_type {
             None
         } else {
             // Convert i32 to the C enum type
@@ -846,14 +916,14 @@ pub trait WxEvtHandler {
 
     // Internal implementation with crate visibility
     #[doc(hidden)]
-    fn bind_internal<F>(&self, event_type: EventType, callback: F)
+    fn bind_internal<F>(&self, event_type: EventType, callback: F) -> EventBinding
     where
         F: FnMut(Event) + 'static,
     {
         let handler_ptr = unsafe { self.get_event_handler_ptr() };
         if handler_ptr.is_null() {
             /* ... error handling ... */
-            return;
+            return EventBinding::invalid();
         }
 
         // Double-box the callback to match trampoline expectations
@@ -865,26 +935,27 @@ pub trait WxEvtHandler {
         let trampoline_ptr: TrampolineFn = rust_event_handler_trampoline;
         let trampoline_c_void = trampoline_ptr as *mut c_void;
 
-        unsafe {
+        let binding_id = unsafe {
             ffi::wxd_EvtHandler_Bind(
                 handler_ptr,
                 event_type.as_c_enum(),
                 trampoline_c_void,
                 user_data,
-            );
-        }
+            )
+        };
+        EventBinding::from_raw(binding_id)
     }
 
     // Internal implementation with ID support for tools and menu items
     #[doc(hidden)]
-    fn bind_with_id_internal<F>(&self, event_type: EventType, id: i32, callback: F)
+    fn bind_with_id_internal<F>(&self, event_type: EventType, id: i32, callback: F) -> EventBinding
     where
         F: FnMut(Event) + 'static,
     {
         let handler_ptr = unsafe { self.get_event_handler_ptr() };
         if handler_ptr.is_null() {
             /* ... error handling ... */
-            return;
+            return EventBinding::invalid();
         }
 
         // Double-box the callback to match trampoline expectations
@@ -896,14 +967,139 @@ pub trait WxEvtHandler {
         let trampoline_ptr: TrampolineFn = rust_event_handler_trampoline;
         let trampoline_c_void = trampoline_ptr as *mut c_void;
 
-        unsafe {
+        let binding_id = unsafe {
             ffi::wxd_EvtHandler_BindWithId(
                 handler_ptr,
                 event_type.as_c_enum(),
                 id,
                 trampoline_c_void,
                 user_data,
-            );
+            )
+        };
+        EventBinding::from_raw(binding_id)
+    }
+
+    /// Binds a handler for a custom event type created with [`EventType::new_custom`].
+    ///
+    /// Custom events propagate to parent windows, so handlers may be bound on
+    /// the target itself or on any of its parents.
+    fn on_custom_event<T, F>(&self, event_type: EventType, mut callback: F) -> EventBinding
+    where
+        T: Send + 'static,
+        F: FnMut(CustomEventData<T>) + 'static,
+    {
+        self.bind_internal(event_type, move |event| {
+            callback(CustomEventData::new(event));
+        })
+    }
+
+    /// Queues a custom event for asynchronous delivery to this handler.
+    ///
+    /// Must be called on the main thread; use [`event_sink`](Self::event_sink)
+    /// to send events from worker threads.
+    fn queue_event<T: Send + 'static>(&self, event: CustomEvent<T>) -> bool {
+        unsafe { custom_event::queue_custom_event(self.get_event_handler_ptr(), event) }
+    }
+
+    /// Posts a copy of a custom event for asynchronous delivery to this handler.
+    ///
+    /// Must be called on the main thread; use [`event_sink`](Self::event_sink)
+    /// to send events from worker threads.
+    fn post_event<T: Clone + Send + 'static>(&self, event: &CustomEvent<T>) -> bool {
+        self.queue_event(event.clone())
+    }
+
+    /// Returns a thread-safe [`EventSink`] that sends custom events to this handler.
+    fn event_sink(&self) -> EventSink {
+        EventSink::new(self)
+    }
+}
+
+// --- EventBinding ---
+
+/// A token identifying a closure bound with one of the `on_*` methods.
+///
+/// Dropping an `EventBinding` does **not** unbind the handler by default: the
+/// closure stays alive for as long as the event handler (widget, timer owner,
+/// ...) exists, which matches the fire-and-forget style most code uses.
+/// Keep the token around when a handler needs to be removed later.
+///
+/// # Example
+/// ```rust,no_run
+/// use wxdragon::prelude::*;
+/// # fn demo(button: &Button) {
+/// // Remove the handler explicitly
+/// let binding = button.on_click(|_| println!("clicked"));
+/// binding.unbind();
+///
+/// // Or unbind automatically when the guard goes out of scope
+/// let _guard = button.on_click(|_| println!("temporary")).unbind_on_drop();
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct EventBinding {
+    id: u64,
+    auto_unbind: bool,
+    // Bindings are tied to the GUI thread, so keep the token !Send/!Sync.
+    _marker: PhantomData<*const ()>,
+}
+
+impl EventBinding {
+    /// Wraps a binding id returned by the C++ side (0 means binding failed).
+    pub(crate) fn from_raw(id: u64) -> Self {
+        EventBinding {
+            id,
+            auto_unbind: false,
+            _marker: PhantomData,
+        }
+    }
+
+    /// A binding that refers to nothing, returned when binding was not possible.
+    pub(crate) fn invalid() -> Self {
+        Self::from_raw(0)
+    }
+
+    /// Returns the raw binding id. Ids are unique for the lifetime of the process.
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    /// Returns `true` if the closure is still bound.
+    ///
+    /// This becomes `false` after [`unbind`](Self::unbind) or once the
+    /// owning event handler has been destroyed.
+    pub fn is_bound(&self) -> bool {
+        self.id != 0 && unsafe { ffi::wxd_EvtHandler_IsBound(self.id) }
+    }
+
+    /// Unbinds the handler and drops its closure.
+    ///
+    /// It is safe to call this from inside the handler itself; the closure is
+    /// then dropped once the current event has been dispatched.
+    /// Returns `false` if the handler was already unbound or its owner destroyed.
+    pub fn unbind(mut self) -> bool {
+        self.auto_unbind = false;
+        self.id != 0 && unsafe { ffi::wxd_EvtHandler_Unbind(self.id) }
+    }
+
+    /// Makes this binding unbind its handler when dropped, turning it into a scope guard.
+    pub fn unbind_on_drop(mut self) -> Self {
+        self.auto_unbind = true;
+        self
+    }
+
+    /// Returns `true` if the handler will be unbound when this token is dropped.
+    pub fn is_unbind_on_drop(&self) -> bool {
+        self.auto_unbind
+    }
+}
+
+impl Drop for EventBinding {
+    fn drop(&mut self) {
+        if self.auto_unbind && self.id != 0 {
+            unsafe {
+                ffi::wxd_EvtHandler_Unbind(self.id);
+            }
         }
     }
 }
@@ -933,13 +1129,10 @@ pub unsafe extern "C" fn rust_event_handler_trampoline(
     let closure_box = &mut *(user_data as *mut Box<dyn FnMut(Event) + 'static>);
     let event_ptr = event_ptr_cvoid as *mut ffi::wxd_Event_t;
 
-    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
-        // UPDATED: Create simple Event
+    crate::panic_policy::catch("event handler", || {
         let safe_event = Event::from_ptr(event_ptr);
         (*closure_box)(safe_event);
-    }));
-
-    if result.is_err() { /* ... error handling ... */ }
+    });
 }
 
 /// Function called by C++ to drop the Rust closure Box.
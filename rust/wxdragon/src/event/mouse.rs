@@ -0,0 +1,84 @@
+//! Mouse types reported by mouse events.
+
+use wxdragon_sys as ffi;
+
+/// A mouse button (`wxMouseButton`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MouseButton {
+    /// The left (primary) button.
+    Left,
+    /// The middle button, usually the wheel.
+    Middle,
+    /// The right (secondary) button.
+    Right,
+    /// The first extra button, usually "back".
+    Aux1,
+    /// The second extra button, usually "forward".
+    Aux2,
+}
+
+impl MouseButton {
+    /// Converts a raw `wxMouseButton` value, returning `None` for `wxMOUSE_BTN_NONE`
+    /// and `wxMOUSE_BTN_ANY`.
+    pub fn from_raw(raw: i32) -> Option<Self> {
+        match raw {
+            ffi::wxd_MouseButton_WXD_MOUSE_BTN_LEFT => Some(MouseButton::Left),
+            ffi::wxd_MouseButton_WXD_MOUSE_BTN_MIDDLE => Some(MouseButton::Middle),
+            ffi::wxd_MouseButton_WXD_MOUSE_BTN_RIGHT => Some(MouseButton::Right),
+            ffi::wxd_MouseButton_WXD_MOUSE_BTN_AUX1 => Some(MouseButton::Aux1),
+            ffi::wxd_MouseButton_WXD_MOUSE_BTN_AUX2 => Some(MouseButton::Aux2),
+            _ => None,
+        }
+    }
+
+    /// Converts to the raw `wxMouseButton` value.
+    pub(crate) fn to_raw(self) -> i32 {
+        match self {
+            MouseButton::Left => ffi::wxd_MouseButton_WXD_MOUSE_BTN_LEFT,
+            MouseButton::Middle => ffi::wxd_MouseButton_WXD_MOUSE_BTN_MIDDLE,
+            MouseButton::Right => ffi::wxd_MouseButton_WXD_MOUSE_BTN_RIGHT,
+            MouseButton::Aux1 => ffi::wxd_MouseButton_WXD_MOUSE_BTN_AUX1,
+            MouseButton::Aux2 => ffi::wxd_MouseButton_WXD_MOUSE_BTN_AUX2,
+        }
+    }
+}
+
+bitflags::bitflags! {
+    /// Mouse buttons held during a mouse event.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct MouseButtons: i32 {
+        /// The left button.
+        const LEFT = ffi::wxd_MouseButtonState_WXD_MOUSE_STATE_LEFT as i32;
+        /// The middle button.
+        const MIDDLE = ffi::wxd_MouseButtonState_WXD_MOUSE_STATE_MIDDLE as i32;
+        /// The right button.
+        const RIGHT = ffi::wxd_MouseButtonState_WXD_MOUSE_STATE_RIGHT as i32;
+        /// The first extra button.
+        const AUX1 = ffi::wxd_MouseButtonState_WXD_MOUSE_STATE_AUX1 as i32;
+        /// The second extra button.
+        const AUX2 = ffi::wxd_MouseButtonState_WXD_MOUSE_STATE_AUX2 as i32;
+    }
+}
+
+impl MouseButtons {
+    /// Returns the flag for a single button.
+    pub fn from_button(button: MouseButton) -> Self {
+        match button {
+            MouseButton::Left => MouseButtons::LEFT,
+            MouseButton::Middle => MouseButtons::MIDDLE,
+            MouseButton::Right => MouseButtons::RIGHT,
+            MouseButton::Aux1 => MouseButtons::AUX1,
+            MouseButton::Aux2 => MouseButtons::AUX2,
+        }
+    }
+}
+
+/// The axis of a mouse wheel event (`wxMouseWheelAxis`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum WheelAxis {
+    /// The usual, vertical wheel.
+    #[default]
+    Vertical,
+    /// A horizontal wheel or tilting the wheel; positive values scroll right.
+    Horizontal,
+}
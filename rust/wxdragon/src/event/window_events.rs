@@ -1,8 +1,9 @@
 //! Window-level events that are common to all widgets.
 
 use crate::event::event_data::{KeyEventData, MouseEventData};
-use crate::event::{Event, EventType};
-use crate::geometry::Size;
+use crate::event::{Event, EventType, KeyCode, Modifiers, MouseButton, MouseButtons, WheelAxis};
+use crate::geometry::{Point, Size, DEFAULT_POSITION};
+use crate::window::Window;
 use std::fmt::Debug;
 
 /// Base window events that are common to all widgets
@@ -15,6 +16,15 @@ pub enum WindowEvent {
     RightUp,
     MiddleDown,
     MiddleUp,
+    LeftDClick,
+    RightDClick,
+    MiddleDClick,
+    Aux1Down,
+    Aux1Up,
+    Aux1DClick,
+    Aux2Down,
+    Aux2Up,
+    Aux2DClick,
     Motion,
     MouseWheel,
     EnterWindow,
@@ -24,6 +34,7 @@ pub enum WindowEvent {
     KeyDown,
     KeyUp,
     Char,
+    NavigationKey,
 
     // Window events
     Size,
@@ -32,6 +43,7 @@ pub enum WindowEvent {
     Erase,     // Now implemented in C++ layer
     SetFocus,  // Now implemented in C++ layer
     KillFocus, // Now implemented in C++ layer
+    ContextMenu,
 
     // Misc events
     Idle,
@@ -47,6 +59,8 @@ pub enum WindowEventData {
     MouseEnter(MouseEnterEvent),
     MouseLeave(MouseLeaveEvent),
     Keyboard(KeyboardEvent),
+    NavigationKey(NavigationKeyEvent),
+    ContextMenu(ContextMenuEvent),
     Size(WindowSizeEvent),
     Idle(IdleEventData),
     General(Event),
@@ -84,6 +98,10 @@ impl WindowEventData {
                 return WindowEventData::MouseEnter(MouseEnterEvent::new(event));
             } else if event_type == EventType::LEAVE_WINDOW {
                 return WindowEventData::MouseLeave(MouseLeaveEvent::new(event));
+            } else if event_type == EventType::NAVIGATION_KEY {
+                return WindowEventData::NavigationKey(NavigationKeyEvent::new(event));
+            } else if event_type == EventType::CONTEXT_MENU {
+                return WindowEventData::ContextMenu(ContextMenuEvent::new(event));
             } else if event_type == EventType::IDLE {
                 return WindowEventData::Idle(IdleEventData::new(event));
             }
@@ -101,6 +119,8 @@ impl WindowEventData {
             WindowEventData::MouseEnter(event) => event.event.skip(skip),
             WindowEventData::MouseLeave(event) => event.event.skip(skip),
             WindowEventData::Keyboard(event) => event.event.skip(skip),
+            WindowEventData::NavigationKey(event) => event.event.skip(skip),
+            WindowEventData::ContextMenu(event) => event.event.skip(skip),
             WindowEventData::Size(event) => event.event.skip(skip),
             WindowEventData::Idle(event) => event.event.skip(skip),
             WindowEventData::General(event) => event.skip(skip),
@@ -108,7 +128,101 @@ impl WindowEventData {
     }
 }
 
-/// Mouse button events (left/right/middle click, up/down)
+/// Adds the accessors of [`MouseEventData`] shared by the mouse event types.
+macro_rules! impl_mouse_state_accessors {
+    ($($event:ty),+) => {$(
+        impl $event {
+            /// Gets the button whose state changed, or `None` for motion and wheel events.
+            pub fn get_button(&self) -> Option<MouseButton> {
+                self.event.get_button()
+            }
+
+            /// Gets the number of clicks: 1 for a single click, 2 for a double click.
+            pub fn get_click_count(&self) -> i32 {
+                self.event.get_click_count()
+            }
+
+            /// Gets the buttons held during the event.
+            pub fn get_buttons_down(&self) -> MouseButtons {
+                self.event.get_buttons_down()
+            }
+
+            /// Returns true if `button` was held during the event.
+            pub fn is_button_down(&self, button: MouseButton) -> bool {
+                self.event.is_button_down(button)
+            }
+
+            /// Gets the wheel rotation, in multiples of [`get_wheel_delta`](Self::get_wheel_delta).
+            pub fn get_wheel_rotation(&self) -> i32 {
+                self.event.get_wheel_rotation()
+            }
+
+            /// Gets the rotation of one wheel notch, typically 120.
+            pub fn get_wheel_delta(&self) -> i32 {
+                self.event.get_wheel_delta()
+            }
+
+            /// Gets the axis of a mouse wheel event.
+            pub fn get_wheel_axis(&self) -> WheelAxis {
+                self.event.get_wheel_axis()
+            }
+
+            /// Gets how many lines to scroll per wheel delta.
+            pub fn wheel_lines_per_action(&self) -> i32 {
+                self.event.wheel_lines_per_action()
+            }
+
+            /// Returns true if a wheel delta should scroll a whole page.
+            pub fn is_page_scroll(&self) -> bool {
+                self.event.is_page_scroll()
+            }
+        }
+    )+};
+}
+
+impl_mouse_state_accessors!(MouseButtonEvent, MouseMotionEvent);
+
+/// Adds the modifier key accessors of the wrapped event data.
+macro_rules! impl_modifier_accessors {
+    ($($event:ty),+) => {$(
+        impl $event {
+            /// Gets the modifier keys held during the event.
+            pub fn get_modifiers(&self) -> Modifiers {
+                self.event.get_modifiers()
+            }
+
+            /// Returns true if Control (Command on macOS) was held.
+            pub fn ctrl_down(&self) -> bool {
+                self.event.ctrl_down()
+            }
+
+            /// Returns true if Shift was held.
+            pub fn shift_down(&self) -> bool {
+                self.event.shift_down()
+            }
+
+            /// Returns true if Alt (Option on macOS) was held.
+            pub fn alt_down(&self) -> bool {
+                self.event.alt_down()
+            }
+
+            /// Returns true if Meta (the Windows key) was held.
+            pub fn meta_down(&self) -> bool {
+                self.event.meta_down()
+            }
+
+            /// Returns true if the platform's shortcut modifier (Command on macOS,
+            /// Control elsewhere) was held.
+            pub fn cmd_down(&self) -> bool {
+                self.event.cmd_down()
+            }
+        }
+    )+};
+}
+
+impl_modifier_accessors!(MouseButtonEvent, MouseMotionEvent, KeyboardEvent);
+
+/// Mouse button events (click, double-click, up/down) and wheel events
 #[derive(Debug)]
 pub struct MouseButtonEvent {
     pub event: MouseEventData,
@@ -200,6 +314,93 @@ impl KeyboardEvent {
     pub fn get_unicode_key(&self) -> Option<i32> {
         self.event.get_unicode_key()
     }
+
+    /// Gets the key as a [`KeyCode`].
+    pub fn get_key(&self) -> Option<KeyCode> {
+        self.event.get_key()
+    }
+
+    /// Gets the platform's own key code.
+    pub fn raw_key_code(&self) -> u32 {
+        self.event.raw_key_code()
+    }
+
+    /// Gets the platform's own key flags.
+    pub fn raw_key_flags(&self) -> u32 {
+        self.event.raw_key_flags()
+    }
+
+    /// Returns true for key down events repeated while the key is held.
+    pub fn is_auto_repeat(&self) -> bool {
+        self.event.is_auto_repeat()
+    }
+}
+
+/// Navigation key events, sent when Tab or Ctrl+Tab moves the focus
+#[derive(Debug)]
+pub struct NavigationKeyEvent {
+    pub event: Event,
+}
+
+impl NavigationKeyEvent {
+    pub fn new(event: Event) -> Self {
+        Self { event }
+    }
+
+    /// Returns true when moving to the next control, false for the previous one.
+    pub fn is_forward(&self) -> bool {
+        unsafe { wxdragon_sys::wxd_NavigationKeyEvent_GetDirection(self.event.0) }
+    }
+
+    /// Returns true when switching between pages or windows (Ctrl+Tab) rather
+    /// than controls.
+    pub fn is_window_change(&self) -> bool {
+        unsafe { wxdragon_sys::wxd_NavigationKeyEvent_IsWindowChange(self.event.0) }
+    }
+
+    /// Returns true if the event was caused by the Tab key.
+    pub fn is_from_tab(&self) -> bool {
+        unsafe { wxdragon_sys::wxd_NavigationKeyEvent_IsFromTab(self.event.0) }
+    }
+
+    /// Gets the window that had the focus, if known.
+    pub fn get_current_focus(&self) -> Option<Window> {
+        let ptr = unsafe { wxdragon_sys::wxd_NavigationKeyEvent_GetCurrentFocus(self.event.0) };
+        if ptr.is_null() {
+            None
+        } else {
+            Some(unsafe { Window::from_ptr(ptr) })
+        }
+    }
+}
+
+/// Context menu events, sent on right click, the menu key or Shift+F10
+///
+/// Unhandled events propagate to the parent window, so call `skip(true)` to
+/// let a parent show its menu instead.
+#[derive(Debug)]
+pub struct ContextMenuEvent {
+    pub event: Event,
+}
+
+impl ContextMenuEvent {
+    pub fn new(event: Event) -> Self {
+        Self { event }
+    }
+
+    /// Gets the position in screen coordinates, or `None` if the menu was
+    /// requested from the keyboard. Convert it with `screen_to_client` before
+    /// passing it to `popup_menu`.
+    pub fn get_position(&self) -> Option<Point> {
+        let pos = unsafe { wxdragon_sys::wxd_ContextMenuEvent_GetPosition(self.event.0) };
+        let pos = Point::new(pos.x, pos.y);
+        (pos != DEFAULT_POSITION).then_some(pos)
+    }
+
+    /// Returns true if the menu was requested from the keyboard rather than the mouse.
+    pub fn is_from_keyboard(&self) -> bool {
+        self.get_position().is_none()
+    }
 }
 
 /// Window size events
@@ -253,6 +454,15 @@ crate::implement_category_event_handlers!(
     RightUp => mouse_right_up, EventType::RIGHT_UP,
     MiddleDown => mouse_middle_down, EventType::MIDDLE_DOWN,
     MiddleUp => mouse_middle_up, EventType::MIDDLE_UP,
+    LeftDClick => mouse_left_dclick, EventType::LEFT_DCLICK,
+    RightDClick => mouse_right_dclick, EventType::RIGHT_DCLICK,
+    MiddleDClick => mouse_middle_dclick, EventType::MIDDLE_DCLICK,
+    Aux1Down => mouse_aux1_down, EventType::AUX1_DOWN,
+    Aux1Up => mouse_aux1_up, EventType::AUX1_UP,
+    Aux1DClick => mouse_aux1_dclick, EventType::AUX1_DCLICK,
+    Aux2Down => mouse_aux2_down, EventType::AUX2_DOWN,
+    Aux2Up => mouse_aux2_up, EventType::AUX2_UP,
+    Aux2DClick => mouse_aux2_dclick, EventType::AUX2_DCLICK,
     Motion => mouse_motion, EventType::MOTION,
     MouseWheel => mouse_wheel, EventType::MOUSEWHEEL,
     EnterWindow => mouse_enter, EventType::ENTER_WINDOW,
@@ -260,12 +470,14 @@ crate::implement_category_event_handlers!(
     KeyDown => key_down, EventType::KEY_DOWN,
     KeyUp => key_up, EventType::KEY_UP,
     Char => char, EventType::CHAR,
+    NavigationKey => navigation_key, EventType::NAVIGATION_KEY,
     Size => size, EventType::SIZE,
     Move => move_event, EventType::MOVE,
     Paint => paint, EventType::PAINT,
     Erase => erase_background, EventType::ERASE,
     SetFocus => set_focus, EventType::SET_FOCUS,
     KillFocus => kill_focus, EventType::KILL_FOCUS,
+    ContextMenu => context_menu, EventType::CONTEXT_MENU,
     Idle => idle, EventType::IDLE,
     Close => close, EventType::CLOSE_WINDOW,
     Destroy => destroy, EventType::DESTROY
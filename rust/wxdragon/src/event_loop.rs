@@ -0,0 +1,224 @@
+//! Control over the running wxWidgets event loop.
+//!
+//! [`EventLoop`] lets code running on the GUI thread exit the main loop with an exit
+//! code, yield to pending events during long synchronous work, and run a temporary
+//! nested loop until some condition holds.
+//!
+//! ```rust,no_run
+//! use wxdragon::event_loop::EventLoop;
+//! use wxdragon::prelude::*;
+//!
+//! # fn import(gauge: &Gauge, rows: &[String]) {
+//! for (i, _row) in rows.iter().enumerate() {
+//!     // ... import the row ...
+//!     gauge.set_value(i as i32);
+//!     // Keep the UI painting without letting the user start another import
+//!     EventLoop::safe_yield(None::<&Frame>);
+//! }
+//! # }
+//! ```
+//!
+//! All functions must be called from the GUI thread.
+
+use crate::window::WxWidget;
+use std::cell::{Cell, RefCell};
+use std::ffi::c_void;
+use std::panic::{catch_unwind, resume_unwind, AssertUnwindSafe};
+use std::time::Duration;
+use wxdragon_sys as ffi;
+
+/// How often [`EventLoop::run_until`] re-checks its predicate when no events arrive.
+const DEFAULT_POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+thread_local! {
+    // Set while a yield started from Rust is in progress
+    static YIELDING: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Clears the yield flag when the yield returns, even by unwinding.
+struct YieldGuard;
+
+impl YieldGuard {
+    fn enter() -> Option<Self> {
+        YIELDING.with(|yielding| {
+            if yielding.get() {
+                None
+            } else {
+                yielding.set(true);
+                Some(YieldGuard)
+            }
+        })
+    }
+}
+
+impl Drop for YieldGuard {
+    fn drop(&mut self) {
+        YIELDING.with(|yielding| yielding.set(false));
+    }
+}
+
+/// Access to the application's event loops.
+///
+/// This is a namespace for associated functions; the loops themselves are owned
+/// by wxWidgets.
+pub struct EventLoop;
+
+impl EventLoop {
+    /// Returns `true` if an event loop is currently running.
+    pub fn is_running() -> bool {
+        unsafe { ffi::wxd_EventLoop_IsRunning() }
+    }
+
+    /// Returns `true` if the innermost running loop is the application's main loop,
+    /// i.e. no modal dialog or nested loop is active.
+    pub fn is_main() -> bool {
+        unsafe { ffi::wxd_EventLoop_IsMain() }
+    }
+
+    /// Exits the main loop, making [`main`](crate::main) return with `exit_code`.
+    ///
+    /// Any nested loops still running (e.g. modal dialogs) finish first.
+    /// Returns `false` if the main loop is not running.
+    pub fn exit(exit_code: i32) -> bool {
+        unsafe { ffi::wxd_EventLoop_ExitMain(exit_code) }
+    }
+
+    /// Exits the innermost running loop with `exit_code`.
+    ///
+    /// Inside [`run_until`](Self::run_until) this ends the nested loop early and its
+    /// exit code is returned from there. Returns `false` if no loop is running.
+    pub fn exit_innermost(exit_code: i32) -> bool {
+        unsafe { ffi::wxd_EventLoop_Exit(exit_code) }
+    }
+
+    /// Returns `true` if native events are waiting to be dispatched.
+    pub fn pending() -> bool {
+        unsafe { ffi::wxd_EventLoop_Pending() }
+    }
+
+    /// Dispatches a single native event, waiting up to `timeout` for one to arrive
+    /// (`None` waits indefinitely). Returns `true` if an event was dispatched.
+    pub fn dispatch(timeout: Option<Duration>) -> bool {
+        let timeout_ms = timeout.map_or(-1, |t| t.as_millis().min(i32::MAX as u128) as i32);
+        unsafe { ffi::wxd_EventLoop_Dispatch(timeout_ms) }
+    }
+
+    /// Processes pending events, then returns.
+    ///
+    /// Use this inside long synchronous operations to keep windows repainting.
+    /// Event handlers run during the yield and may call back into your code, so
+    /// prefer [`safe_yield`](Self::safe_yield) unless the UI must stay interactive.
+    ///
+    /// Returns `false` without doing anything if a yield is already in progress.
+    pub fn yield_now() -> bool {
+        let Some(_guard) = YieldGuard::enter() else {
+            return false;
+        };
+        unsafe { ffi::wxd_EventLoop_Yield(true, false, std::ptr::null_mut()) }
+    }
+
+    /// Processes pending events while user input to all windows is disabled,
+    /// except for `enable` (e.g. a progress dialog with a cancel button).
+    ///
+    /// This keeps the UI painting without letting the user trigger handlers that
+    /// re-enter the running operation. Returns `false` without doing anything if a
+    /// yield is already in progress.
+    pub fn safe_yield<W: WxWidget + ?Sized>(enable: Option<&W>) -> bool {
+        let Some(_guard) = YieldGuard::enter() else {
+            return false;
+        };
+        let window = enable.map_or(std::ptr::null_mut(), |w| w.handle_ptr());
+        unsafe { ffi::wxd_EventLoop_Yield(true, true, window) }
+    }
+
+    /// Returns `true` while a yield is in progress.
+    pub fn is_yielding() -> bool {
+        YIELDING.with(|yielding| yielding.get()) || unsafe { ffi::wxd_EventLoop_IsYielding() }
+    }
+
+    /// Processes events queued for later delivery on all handlers, together with the
+    /// [`call_after`](crate::call_after) queue.
+    pub fn process_pending_events() {
+        unsafe { ffi::wxd_EventLoop_ProcessPendingEvents() };
+        crate::app::process_main_thread_queue();
+    }
+
+    /// Runs a nested event loop until `predicate` returns `true`.
+    ///
+    /// The predicate is checked after each batch of events and at least every 10ms.
+    /// Returns `0` once the predicate holds, or the code passed to
+    /// [`exit_innermost`](Self::exit_innermost) if the loop was ended that way.
+    ///
+    /// ```rust,no_run
+    /// use std::sync::atomic::{AtomicBool, Ordering};
+    /// use std::sync::Arc;
+    /// use wxdragon::event_loop::EventLoop;
+    ///
+    /// let done = Arc::new(AtomicBool::new(false));
+    /// let flag = done.clone();
+    /// std::thread::spawn(move || {
+    ///     // ... background work ...
+    ///     flag.store(true, Ordering::Release);
+    /// });
+    /// EventLoop::run_until(|| done.load(Ordering::Acquire));
+    /// ```
+    ///
+    /// # Panics
+    /// Resumes any panic raised by `predicate` after the nested loop has exited.
+    pub fn run_until<F>(predicate: F) -> i32
+    where
+        F: FnMut() -> bool,
+    {
+        Self::run_until_with_interval(predicate, DEFAULT_POLL_INTERVAL)
+    }
+
+    /// Like [`run_until`](Self::run_until), with a custom interval for re-checking
+    /// the predicate while no events arrive.
+    pub fn run_until_with_interval<F>(predicate: F, poll_interval: Duration) -> i32
+    where
+        F: FnMut() -> bool,
+    {
+        let mut state = PredicateState {
+            predicate,
+            panic: RefCell::new(None),
+        };
+        let interval_ms = poll_interval.as_millis().clamp(1, i32::MAX as u128) as i32;
+        let exit_code = unsafe {
+            ffi::wxd_EventLoop_RunUntil(
+                Some(predicate_trampoline::<F>),
+                &mut state as *mut PredicateState<F> as *mut c_void,
+                interval_ms,
+            )
+        };
+        if let Some(panic) = state.panic.into_inner() {
+            resume_unwind(panic);
+        }
+        exit_code
+    }
+}
+
+struct PredicateState<F> {
+    predicate: F,
+    panic: RefCell<Option<Box<dyn std::any::Any + Send + 'static>>>,
+}
+
+unsafe extern "C" fn predicate_trampoline<F>(user_data: *mut c_void) -> bool
+where
+    F: FnMut() -> bool,
+{
+    if user_data.is_null() {
+        return true;
+    }
+    let state = &mut *(user_data as *mut PredicateState<F>);
+    if state.panic.borrow().is_some() {
+        return true;
+    }
+    // A panicking predicate ends the loop; the panic is resumed once it has unwound
+    match catch_unwind(AssertUnwindSafe(|| (state.predicate)())) {
+        Ok(done) => done,
+        Err(panic) => {
+            *state.panic.borrow_mut() = Some(panic);
+            true
+        }
+    }
+}
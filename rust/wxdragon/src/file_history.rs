@@ -0,0 +1,209 @@
+//! Recently used files, shown in menus (`wxFileHistory`).
+//!
+//! A [`FileHistory`] keeps a most-recently-used list of paths and mirrors it into
+//! every menu it is attached to. Choosing one of those items sends a menu event with
+//! an id from [`id_base`](FileHistory::id_base) upwards, which
+//! [`on_file_selected`](FileHistory::on_file_selected) turns into the chosen path:
+//!
+//! ```rust,no_run
+//! use wxdragon::prelude::*;
+//!
+//! const ID_OPEN: Id = ID_HIGHEST + 1;
+//!
+//! # fn setup(frame: &Frame) {
+//! let history = FileHistory::new(9);
+//! history.load("RecentFiles");
+//!
+//! let recent = Menu::builder().build();
+//! history.use_menu(&recent);
+//! let file_menu = Menu::builder()
+//!     .append_item(ID_OPEN, "&Open...\tCtrl+O", "")
+//!     .append_submenu(recent, "Open &Recent", "")
+//!     .build();
+//! frame.set_menu_bar(MenuBar::builder().append(file_menu, "&File").build());
+//!
+//! history.on_file_selected(frame, |file| {
+//!     println!("open {}", file.path);
+//! });
+//! # }
+//! ```
+//!
+//! Call [`add_file`](FileHistory::add_file) whenever a document is opened or saved,
+//! and [`save`](FileHistory::save) before the application exits.
+
+use crate::event::{EventBinding, EventType, WxEvtHandler};
+use crate::id::{Id, ID_FILE1};
+use crate::menus::Menu;
+use std::cell::RefCell;
+use std::ffi::{CStr, CString};
+use std::rc::Rc;
+use wxdragon_sys as ffi;
+
+/// A file chosen from a history menu.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecentFile {
+    /// Position in the history, 0 being the most recent.
+    pub index: usize,
+    /// The file's path.
+    pub path: String,
+}
+
+struct Inner {
+    ptr: *mut ffi::wxd_FileHistory_t,
+}
+
+impl Drop for Inner {
+    fn drop(&mut self) {
+        unsafe { ffi::wxd_FileHistory_Destroy(self.ptr) };
+    }
+}
+
+/// A most-recently-used file list (`wxFileHistory`).
+///
+/// Cloning is cheap and yields a handle to the same history.
+#[derive(Clone)]
+pub struct FileHistory {
+    inner: Rc<Inner>,
+}
+
+impl FileHistory {
+    /// Creates a history keeping up to `max_files` entries, whose menu items use
+    /// the ids from [`ID_FILE1`] upwards.
+    pub fn new(max_files: usize) -> Self {
+        Self::with_id_base(max_files, ID_FILE1)
+    }
+
+    /// Creates a history keeping up to `max_files` entries, whose menu items use
+    /// the ids `id_base..id_base + max_files`.
+    pub fn with_id_base(max_files: usize, id_base: Id) -> Self {
+        let max_files = max_files.clamp(1, i32::MAX as usize) as i32;
+        let ptr = unsafe { ffi::wxd_FileHistory_Create(max_files, id_base) };
+        Self {
+            inner: Rc::new(Inner { ptr }),
+        }
+    }
+
+    /// Adds `path` as the most recent file, moving it to the top if it is already
+    /// in the history. The oldest entry is dropped when the history is full.
+    pub fn add_file(&self, path: &str) {
+        let path_c = CString::new(path).unwrap_or_default();
+        unsafe { ffi::wxd_FileHistory_AddFile(self.inner.ptr, path_c.as_ptr()) };
+    }
+
+    /// Removes the entry at `index`, e.g. a file that no longer exists.
+    pub fn remove_file(&self, index: usize) {
+        if let Ok(index) = i32::try_from(index) {
+            unsafe { ffi::wxd_FileHistory_RemoveFile(self.inner.ptr, index) };
+        }
+    }
+
+    /// Removes every entry.
+    pub fn clear(&self) {
+        unsafe { ffi::wxd_FileHistory_Clear(self.inner.ptr) };
+    }
+
+    /// Returns the number of entries.
+    pub fn len(&self) -> usize {
+        unsafe { ffi::wxd_FileHistory_GetCount(self.inner.ptr) }.max(0) as usize
+    }
+
+    /// Returns `true` if the history has no entries.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the maximum number of entries.
+    pub fn max_files(&self) -> usize {
+        unsafe { ffi::wxd_FileHistory_GetMaxFiles(self.inner.ptr) }.max(0) as usize
+    }
+
+    /// Returns the id of the first entry's menu items.
+    pub fn id_base(&self) -> Id {
+        unsafe { ffi::wxd_FileHistory_GetBaseId(self.inner.ptr) }
+    }
+
+    /// Returns the menu item id used for the entry at `index`.
+    pub fn id_for_index(&self, index: usize) -> Id {
+        self.id_base() + index as Id
+    }
+
+    /// Returns the index of the entry whose menu items use `id`, if any.
+    pub fn index_for_id(&self, id: Id) -> Option<usize> {
+        let index = usize::try_from(id.checked_sub(self.id_base())?).ok()?;
+        (index < self.len()).then_some(index)
+    }
+
+    /// Returns the path at `index`, 0 being the most recent.
+    pub fn get_file(&self, index: usize) -> Option<String> {
+        let index = i32::try_from(index).ok()?;
+        let ptr = unsafe { ffi::wxd_FileHistory_GetFile(self.inner.ptr, index) };
+        if ptr.is_null() {
+            return None;
+        }
+        let path = unsafe { CStr::from_ptr(ptr) }
+            .to_string_lossy()
+            .into_owned();
+        unsafe { ffi::wxd_free_string(ptr) };
+        Some(path)
+    }
+
+    /// Returns all paths, most recent first.
+    pub fn files(&self) -> Vec<String> {
+        (0..self.len()).filter_map(|i| self.get_file(i)).collect()
+    }
+
+    /// Shows the history in `menu`, after any items it already has, and keeps it
+    /// up to date from now on. Call before the menu is handed to a menu bar or
+    /// parent menu. A destroyed menu is forgotten automatically.
+    pub fn use_menu(&self, menu: &Menu) {
+        unsafe { ffi::wxd_FileHistory_UseMenu(self.inner.ptr, menu.as_ptr()) };
+    }
+
+    /// Stops updating `menu`. Its current items are left in place.
+    pub fn remove_menu(&self, menu: &Menu) {
+        unsafe { ffi::wxd_FileHistory_RemoveMenu(self.inner.ptr, menu.as_ptr()) };
+    }
+
+    /// Calls `handler` with the chosen file when a history item is selected in a
+    /// menu of `window` (usually the frame owning the menu bar).
+    ///
+    /// One binding is made per history slot; they are returned in slot order.
+    pub fn on_file_selected<W, F>(&self, window: &W, handler: F) -> Vec<EventBinding>
+    where
+        W: WxEvtHandler,
+        F: FnMut(RecentFile) + 'static,
+    {
+        let handler = Rc::new(RefCell::new(handler));
+        (0..self.max_files())
+            .map(|index| {
+                let history = self.clone();
+                let handler = handler.clone();
+                window.bind_with_id_internal(EventType::MENU, self.id_for_index(index), move |_| {
+                    let Some(path) = history.get_file(index) else {
+                        return;
+                    };
+                    if let Ok(mut handler) = handler.try_borrow_mut() {
+                        handler(RecentFile { index, path });
+                    }
+                })
+            })
+            .collect()
+    }
+
+    /// Replaces the entries with those saved under `group` in the application's
+    /// [global config](crate::config::Config::global), which is created if none was
+    /// set. Returns `false` only if it could not be created.
+    ///
+    /// Call this before attaching menus with [`use_menu`](Self::use_menu).
+    pub fn load(&self, group: &str) -> bool {
+        let group_c = CString::new(group).unwrap_or_default();
+        unsafe { ffi::wxd_FileHistory_Load(self.inner.ptr, group_c.as_ptr()) }
+    }
+
+    /// Saves the entries under `group` in the application's configuration and
+    /// flushes it. Returns `false` if that failed.
+    pub fn save(&self, group: &str) -> bool {
+        let group_c = CString::new(group).unwrap_or_default();
+        unsafe { ffi::wxd_FileHistory_Save(self.inner.ptr, group_c.as_ptr()) }
+    }
+}
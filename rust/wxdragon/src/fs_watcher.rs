@@ -0,0 +1,281 @@
+//! Watching files and directories for changes (`wxFileSystemWatcher`).
+//!
+//! A [`FileSystemWatcher`] reports changes below the paths added to it as
+//! [`FsEvent`]s, delivered on the main thread by the event loop (inotify on
+//! Linux, `ReadDirectoryChangesW` on Windows, kqueue and FSEvents on macOS):
+//!
+//! ```rust,no_run
+//! use wxdragon::prelude::*;
+//!
+//! # fn setup() {
+//! let watcher = FileSystemWatcher::new();
+//! watcher.add_tree("assets", FsChangeKinds::ALL, Some("*.png"));
+//! watcher.on_change(|event| match event {
+//!     FsEvent::Created(path) | FsEvent::Modified(path) => println!("reload {}", path.display()),
+//!     FsEvent::Renamed { from, to } => println!("{} -> {}", from.display(), to.display()),
+//!     FsEvent::Warning(FsWarning::Overflow, _) => println!("missed changes, rescan everything"),
+//!     _ => {}
+//! });
+//! // Keep `watcher` alive for as long as the paths should be watched
+//! # }
+//! ```
+//!
+//! The watcher needs a running event loop. Paths added before it starts, e.g.
+//! in `on_init`, are watched from its first iteration on; until then adding
+//! them always succeeds.
+
+use crate::event::{Event, EventBinding, EventType, WxEvtHandler};
+use crate::utils::WxdArrayString;
+use std::ffi::{CStr, CString};
+use std::os::raw::c_char;
+use std::path::{Path, PathBuf};
+use wxdragon_sys as ffi;
+
+bitflags::bitflags! {
+    /// Kinds of changes to report for a watched path (`wxFSWFlags`).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct FsChangeKinds: i32 {
+        /// A file or directory was created.
+        const CREATE = ffi::wxd_FswEventType_WXD_FSW_EVENT_CREATE as i32;
+        /// A file or directory was deleted.
+        const DELETE = ffi::wxd_FswEventType_WXD_FSW_EVENT_DELETE as i32;
+        /// A file or directory was renamed.
+        const RENAME = ffi::wxd_FswEventType_WXD_FSW_EVENT_RENAME as i32;
+        /// A file was written to.
+        const MODIFY = ffi::wxd_FswEventType_WXD_FSW_EVENT_MODIFY as i32;
+        /// A file was read. Not reported on Windows.
+        const ACCESS = ffi::wxd_FswEventType_WXD_FSW_EVENT_ACCESS as i32;
+        /// Permissions, ownership or timestamps changed.
+        const ATTRIB = ffi::wxd_FswEventType_WXD_FSW_EVENT_ATTRIB as i32;
+        /// The file system containing the path was unmounted.
+        const UNMOUNT = ffi::wxd_FswEventType_WXD_FSW_EVENT_UNMOUNT as i32;
+        /// Warnings, such as lost events.
+        const WARNING = ffi::wxd_FswEventType_WXD_FSW_EVENT_WARNING as i32;
+        /// Errors of the watcher itself.
+        const ERROR = ffi::wxd_FswEventType_WXD_FSW_EVENT_ERROR as i32;
+        /// Everything.
+        const ALL = ffi::wxd_FswEventType_WXD_FSW_EVENT_ALL as i32;
+    }
+}
+
+/// Kind of [`FsEvent::Warning`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FsWarning {
+    /// Too many changes happened at once and some were dropped; anything watched
+    /// may have changed.
+    Overflow,
+    /// Any other warning.
+    General,
+}
+
+/// A change reported by a [`FileSystemWatcher`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FsEvent {
+    /// A file or directory was created.
+    Created(PathBuf),
+    /// A file or directory was deleted.
+    Deleted(PathBuf),
+    /// A file or directory was renamed or moved within the watched paths.
+    Renamed {
+        /// The old path.
+        from: PathBuf,
+        /// The new path.
+        to: PathBuf,
+    },
+    /// A file was written to.
+    Modified(PathBuf),
+    /// A file was read.
+    Accessed(PathBuf),
+    /// Permissions, ownership or timestamps of a file changed.
+    AttributesChanged(PathBuf),
+    /// The file system containing the path was unmounted.
+    Unmounted(PathBuf),
+    /// Something went wrong, with a description.
+    Warning(FsWarning, String),
+    /// The watcher failed, with a description.
+    Error(String),
+}
+
+fn take_path(ptr: *mut c_char) -> PathBuf {
+    if ptr.is_null() {
+        return PathBuf::new();
+    }
+    let value = unsafe { CStr::from_ptr(ptr) }
+        .to_string_lossy()
+        .into_owned();
+    unsafe { ffi::wxd_free_string(ptr) };
+    PathBuf::from(value)
+}
+
+impl FsEvent {
+    fn from_event(event: Event) -> Option<Self> {
+        let ptr = event.0;
+        let kind = unsafe { ffi::wxd_FileSystemWatcherEvent_GetChangeType(ptr) };
+        let path = || take_path(unsafe { ffi::wxd_FileSystemWatcherEvent_GetPath(ptr) });
+        let description = || {
+            take_path(unsafe { ffi::wxd_FileSystemWatcherEvent_GetErrorDescription(ptr) })
+                .to_string_lossy()
+                .into_owned()
+        };
+        let kind = FsChangeKinds::from_bits_truncate(kind);
+        let event = if kind.contains(FsChangeKinds::CREATE) {
+            FsEvent::Created(path())
+        } else if kind.contains(FsChangeKinds::DELETE) {
+            FsEvent::Deleted(path())
+        } else if kind.contains(FsChangeKinds::RENAME) {
+            FsEvent::Renamed {
+                from: path(),
+                to: take_path(unsafe { ffi::wxd_FileSystemWatcherEvent_GetNewPath(ptr) }),
+            }
+        } else if kind.contains(FsChangeKinds::MODIFY) {
+            FsEvent::Modified(path())
+        } else if kind.contains(FsChangeKinds::ACCESS) {
+            FsEvent::Accessed(path())
+        } else if kind.contains(FsChangeKinds::ATTRIB) {
+            FsEvent::AttributesChanged(path())
+        } else if kind.contains(FsChangeKinds::UNMOUNT) {
+            FsEvent::Unmounted(path())
+        } else if kind.contains(FsChangeKinds::WARNING) {
+            let warning = match unsafe { ffi::wxd_FileSystemWatcherEvent_GetWarningType(ptr) } {
+                ffi::wxd_FswWarningType_WXD_FSW_WARNING_OVERFLOW => FsWarning::Overflow,
+                _ => FsWarning::General,
+            };
+            FsEvent::Warning(warning, description())
+        } else if kind.contains(FsChangeKinds::ERROR) {
+            FsEvent::Error(description())
+        } else {
+            return None;
+        };
+        Some(event)
+    }
+}
+
+fn path_cstring(path: &Path) -> Option<CString> {
+    path.to_str().and_then(|path| CString::new(path).ok())
+}
+
+/// Watches files and directories; see the [module docs](self).
+///
+/// Watching stops when the watcher is dropped.
+pub struct FileSystemWatcher {
+    ptr: *mut ffi::wxd_FileSystemWatcher_t,
+}
+
+impl FileSystemWatcher {
+    /// Creates a watcher without any watched paths.
+    pub fn new() -> Self {
+        Self {
+            ptr: unsafe { ffi::wxd_FileSystemWatcher_Create() },
+        }
+    }
+
+    /// Watches a file, or the entries of a directory without its subdirectories.
+    /// For a directory, `filespec` (e.g. `"*.png"`) limits the reported entries.
+    ///
+    /// Returns `false` if the path cannot be watched, e.g. because it does not exist.
+    pub fn add(
+        &self,
+        path: impl AsRef<Path>,
+        kinds: FsChangeKinds,
+        filespec: Option<&str>,
+    ) -> bool {
+        self.add_impl(path.as_ref(), kinds, filespec, false)
+    }
+
+    /// Watches a directory and everything below it. `filespec` limits the
+    /// reported files, not the directories descended into.
+    pub fn add_tree(
+        &self,
+        path: impl AsRef<Path>,
+        kinds: FsChangeKinds,
+        filespec: Option<&str>,
+    ) -> bool {
+        self.add_impl(path.as_ref(), kinds, filespec, true)
+    }
+
+    fn add_impl(
+        &self,
+        path: &Path,
+        kinds: FsChangeKinds,
+        filespec: Option<&str>,
+        tree: bool,
+    ) -> bool {
+        let Some(path_c) = path_cstring(path) else {
+            return false;
+        };
+        let filespec_c = CString::new(filespec.unwrap_or_default()).unwrap_or_default();
+        unsafe {
+            if tree {
+                ffi::wxd_FileSystemWatcher_AddTree(
+                    self.ptr,
+                    path_c.as_ptr(),
+                    kinds.bits(),
+                    filespec_c.as_ptr(),
+                )
+            } else {
+                ffi::wxd_FileSystemWatcher_Add(
+                    self.ptr,
+                    path_c.as_ptr(),
+                    kinds.bits(),
+                    filespec_c.as_ptr(),
+                )
+            }
+        }
+    }
+
+    /// Stops watching a path added with [`add`](Self::add).
+    pub fn remove(&self, path: impl AsRef<Path>) -> bool {
+        path_cstring(path.as_ref()).is_some_and(|path_c| unsafe {
+            ffi::wxd_FileSystemWatcher_Remove(self.ptr, path_c.as_ptr())
+        })
+    }
+
+    /// Stops watching a tree added with [`add_tree`](Self::add_tree).
+    pub fn remove_tree(&self, path: impl AsRef<Path>) -> bool {
+        path_cstring(path.as_ref()).is_some_and(|path_c| unsafe {
+            ffi::wxd_FileSystemWatcher_RemoveTree(self.ptr, path_c.as_ptr())
+        })
+    }
+
+    /// Stops watching all paths.
+    pub fn remove_all(&self) -> bool {
+        unsafe { ffi::wxd_FileSystemWatcher_RemoveAll(self.ptr) }
+    }
+
+    /// Returns the watched paths, including every directory of watched trees.
+    pub fn watched_paths(&self) -> Vec<PathBuf> {
+        let paths = WxdArrayString::new();
+        unsafe { ffi::wxd_FileSystemWatcher_GetWatchedPaths(self.ptr, paths.as_ptr()) };
+        paths.get_strings().into_iter().map(PathBuf::from).collect()
+    }
+
+    /// Calls `callback` with every reported change.
+    pub fn on_change<F>(&self, mut callback: F) -> EventBinding
+    where
+        F: FnMut(FsEvent) + 'static,
+    {
+        self.bind_internal(EventType::FSWATCHER, move |event| {
+            if let Some(change) = FsEvent::from_event(event) {
+                callback(change);
+            }
+        })
+    }
+}
+
+impl Default for FileSystemWatcher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl WxEvtHandler for FileSystemWatcher {
+    unsafe fn get_event_handler_ptr(&self) -> *mut ffi::wxd_EvtHandler_t {
+        ffi::wxd_FileSystemWatcher_GetEvtHandler(self.ptr)
+    }
+}
+
+impl Drop for FileSystemWatcher {
+    fn drop(&mut self) {
+        unsafe { ffi::wxd_FileSystemWatcher_Destroy(self.ptr) };
+    }
+}
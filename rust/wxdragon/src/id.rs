@@ -18,13 +18,8 @@ pub const ID_EXIT: Id = ffi::WXD_ID_EXIT as Id;
 /// Standard ID for About commands.
 pub const ID_ABOUT: Id = ffi::WXD_ID_ABOUT as Id;
 
-// ID_NONE is not typically exported/used directly this way;
-// comparisons usually happen against ID_ANY or specific IDs.
-// pub const ID_NONE: Id = ffi::WXD_ID_NONE; // WXD_ID_NONE wasn't generated
-
-// No explicit ID_NONE needed for now, maybe later if required for API consistency.
-// Comparisons usually happen against ID_ANY or specific IDs.
-// pub const ID_NONE: Id = ffi::WXD_ID_NONE; // WXD_ID_NONE wasn't generated
+/// "No id", e.g. returned when a popup menu is dismissed without a selection.
+pub const ID_NONE: Id = ffi::WXD_ID_NONE as Id;
 
 // --- Standard Dialog IDs (Manually Defined) ---
 // Values based on typical wxWidgets assignments, verify if necessary.
@@ -33,10 +28,17 @@ pub const ID_CANCEL: Id = ffi::WXD_ID_CANCEL as Id; // Typically wxID_CANCEL
 pub const ID_YES: Id = ffi::WXD_ID_YES as Id; // Typically wxID_YES
 pub const ID_NO: Id = ffi::WXD_ID_NO as Id; // Typically wxID_NO
 
-// Other standard IDs that might be useful (can be added if needed)
-// pub const ID_HELP: Id = ...;
-// pub const ID_CLOSE: Id = ...;
-// pub const ID_ABOUT: Id = ...;
-// pub const ID_EXIT: Id = ...;
+pub const ID_APPLY: Id = ffi::WXD_ID_APPLY as Id; // Typically wxID_APPLY
+pub const ID_CLOSE: Id = ffi::WXD_ID_CLOSE as Id; // Typically wxID_CLOSE
+pub const ID_HELP: Id = ffi::WXD_ID_HELP as Id; // Typically wxID_HELP
+
+/// Standard ID for Undo commands.
+pub const ID_UNDO: Id = ffi::WXD_ID_UNDO as Id;
+
+/// Standard ID for Redo commands.
+pub const ID_REDO: Id = ffi::WXD_ID_REDO as Id;
+
+/// First of the IDs used by [`FileHistory`](crate::file_history::FileHistory) menu items.
+pub const ID_FILE1: Id = ffi::WXD_ID_FILE1 as Id;
 
 // ... Any other constants ...
@@ -3,6 +3,7 @@ pub use wxdragon_sys as ffi;
 
 #[macro_use]
 pub mod macros;
+pub mod accelerator;
 pub mod app;
 pub mod appearance;
 pub mod appprogress;
@@ -11,6 +12,8 @@ pub mod bitmap;
 pub mod bitmap_bundle;
 pub mod clipboard;
 pub mod color;
+pub mod command_processor;
+pub mod config;
 pub mod cursor;
 pub mod data_object;
 pub mod datetime;
@@ -18,16 +21,30 @@ pub mod dc;
 pub mod dialogs;
 pub mod dnd;
 pub mod event;
+pub mod event_loop;
+pub mod file_history;
 pub mod font;
 pub mod font_data;
+pub mod fs_watcher;
 pub mod geometry;
 pub mod id;
+pub mod locale;
+pub mod logging;
 pub mod menus;
+pub mod panic_policy;
+pub mod persistence;
 pub mod prelude;
+pub mod process;
 pub mod scrollable;
+#[cfg(feature = "single-instance")]
+pub mod single_instance;
 pub mod sizers;
+pub mod standard_paths;
+pub mod task;
+pub mod testing;
 pub mod timer;
 pub mod types;
+pub mod ui_channel;
 pub mod utils;
 pub mod widgets;
 pub mod window;
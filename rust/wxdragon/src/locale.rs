@@ -0,0 +1,375 @@
+//! Localization: UI language, message catalogs and locale-aware formatting.
+//!
+//! Translations come from gettext `.mo` catalogs, found on disk below a lookup
+//! path or embedded in the executable with
+//! [`add_catalog_data`](Translations::add_catalog_data). Set the language, then
+//! add the catalogs:
+//!
+//! ```rust,no_run
+//! use wxdragon::prelude::*;
+//!
+//! Locale::use_default();
+//! Translations::set_language(""); // the user's language
+//! Translations::add_lookup_path("locale"); // locale/fr/myapp.mo, ...
+//! Translations::add_catalog("myapp");
+//! Translations::add_std_catalog(); // stock labels and dialog buttons
+//!
+//! let count = 3;
+//! let title = tr!("Open File");
+//! let status = tr_n!("{} file selected", "{} files selected", count);
+//! let greeting = tr!("Hello, {}!", "Ada");
+//! ```
+//!
+//! [`tr!`](crate::tr) and [`tr_n!`](crate::tr_n) substitute `{}` placeholders in
+//! order, or `{0}`, `{1}`... by position so translators can reorder them; `tr_n!`
+//! passes its count as the first argument. To extract the strings, run
+//! `xgettext --keyword=tr --keyword=tr_n:1,2 --language=C`.
+
+use crate::datetime::DateTime;
+use crate::utils::WxdArrayString;
+use std::ffi::{CStr, CString};
+use std::fmt::{self, Write};
+use std::os::raw::c_char;
+use wxdragon_sys as ffi;
+
+/// Direction in which a window lays out its children and text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LayoutDirection {
+    /// Inherited from the parent window or the locale.
+    #[default]
+    Default,
+    /// Left to right, as in English.
+    LeftToRight,
+    /// Right to left, as in Arabic or Hebrew.
+    RightToLeft,
+}
+
+impl LayoutDirection {
+    pub(crate) fn to_raw(self) -> ffi::wxd_LayoutDirection {
+        match self {
+            LayoutDirection::Default => ffi::wxd_LayoutDirection_WXD_LAYOUT_DEFAULT,
+            LayoutDirection::LeftToRight => ffi::wxd_LayoutDirection_WXD_LAYOUT_LEFT_TO_RIGHT,
+            LayoutDirection::RightToLeft => ffi::wxd_LayoutDirection_WXD_LAYOUT_RIGHT_TO_LEFT,
+        }
+    }
+
+    pub(crate) fn from_raw(raw: ffi::wxd_LayoutDirection) -> Self {
+        match raw {
+            ffi::wxd_LayoutDirection_WXD_LAYOUT_LEFT_TO_RIGHT => LayoutDirection::LeftToRight,
+            ffi::wxd_LayoutDirection_WXD_LAYOUT_RIGHT_TO_LEFT => LayoutDirection::RightToLeft,
+            _ => LayoutDirection::Default,
+        }
+    }
+}
+
+/// Which locale format [`DateTime::format_locale`] uses.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DateFormat {
+    /// The short date, e.g. `12/31/2025` or `31.12.2025`.
+    Short,
+    /// The long date, e.g. `Wednesday, December 31, 2025`.
+    Long,
+    /// The time of day.
+    Time,
+    /// Date and time.
+    DateTime,
+}
+
+impl DateFormat {
+    fn to_raw(self) -> ffi::wxd_LocaleDateFormat {
+        match self {
+            DateFormat::Short => ffi::wxd_LocaleDateFormat_WXD_LOCALE_DATE_SHORT,
+            DateFormat::Long => ffi::wxd_LocaleDateFormat_WXD_LOCALE_DATE_LONG,
+            DateFormat::Time => ffi::wxd_LocaleDateFormat_WXD_LOCALE_TIME,
+            DateFormat::DateTime => ffi::wxd_LocaleDateFormat_WXD_LOCALE_DATE_TIME,
+        }
+    }
+}
+
+fn take_string(ptr: *mut c_char) -> String {
+    if ptr.is_null() {
+        return String::new();
+    }
+    let value = unsafe { CStr::from_ptr(ptr) }
+        .to_string_lossy()
+        .into_owned();
+    unsafe { ffi::wxd_free_string(ptr) };
+    value
+}
+
+fn c_string(text: &str) -> CString {
+    CString::new(text).unwrap_or_default()
+}
+
+/// The UI locale (`wxUILocale`), deciding how numbers and dates are formatted and
+/// which layout direction windows use.
+///
+/// Until [`use_default`](Self::use_default) is called the "C" locale is in effect.
+pub struct Locale;
+
+impl Locale {
+    /// Switches to the user's preferred locale. Call once, early in the
+    /// application's startup. Returns `false` if the locale is not supported.
+    pub fn use_default() -> bool {
+        unsafe { ffi::wxd_UILocale_UseDefault() }
+    }
+
+    /// The name of the current locale, e.g. `en_US`.
+    pub fn name() -> String {
+        take_string(unsafe { ffi::wxd_UILocale_GetName() })
+    }
+
+    /// The user's preferred language, e.g. `fr_FR`.
+    pub fn system_language() -> String {
+        take_string(unsafe { ffi::wxd_UILocale_GetSystemLanguage() })
+    }
+
+    /// The layout direction of the current locale's script.
+    pub fn layout_direction() -> LayoutDirection {
+        LayoutDirection::from_raw(unsafe { ffi::wxd_UILocale_GetLayoutDirection() })
+    }
+
+    /// The decimal separator, e.g. `.` or `,`.
+    pub fn decimal_separator() -> String {
+        take_string(unsafe { ffi::wxd_UILocale_GetDecimalSeparator() })
+    }
+
+    /// The thousands separator; may be empty.
+    pub fn thousands_separator() -> String {
+        take_string(unsafe { ffi::wxd_UILocale_GetThousandsSeparator() })
+    }
+
+    /// Formats `value` with `decimals` digits after the separator, or as many as
+    /// needed when `None`, grouping thousands if `group_thousands` is set.
+    pub fn format_number(value: f64, decimals: Option<usize>, group_thousands: bool) -> String {
+        let precision = decimals.map_or(-1, |d| d.min(i32::MAX as usize) as i32);
+        take_string(unsafe { ffi::wxd_UILocale_FormatNumber(value, precision, group_thousands) })
+    }
+
+    /// Formats an integer, grouping thousands if `group_thousands` is set.
+    pub fn format_integer(value: i64, group_thousands: bool) -> String {
+        take_string(unsafe { ffi::wxd_UILocale_FormatInteger(value, group_thousands) })
+    }
+
+    /// Parses a number written in the current locale's format, with or without
+    /// thousands separators.
+    pub fn parse_number(text: &str) -> Option<f64> {
+        let text_c = c_string(text);
+        let mut value = 0.0;
+        unsafe { ffi::wxd_UILocale_ParseNumber(text_c.as_ptr(), &mut value) }.then_some(value)
+    }
+}
+
+impl DateTime {
+    /// Formats the date and/or time as is customary in the current locale.
+    /// Returns an empty string for an invalid date.
+    pub fn format_locale(&self, format: DateFormat) -> String {
+        take_string(unsafe { ffi::wxd_UILocale_FormatDateTime(self.as_ptr(), format.to_raw()) })
+    }
+}
+
+/// Message catalogs and translation lookups (`wxTranslations`).
+pub struct Translations;
+
+impl Translations {
+    /// Sets the language messages are translated to, e.g. `"de"` or `"pt_BR"`; an
+    /// empty string selects the user's language. Catalogs already added keep their
+    /// language, so call this first.
+    pub fn set_language(language: &str) {
+        let language_c = c_string(language);
+        unsafe { ffi::wxd_Translations_SetLanguage(language_c.as_ptr()) };
+    }
+
+    /// Adds a directory searched for `<lang>/<domain>.mo` and
+    /// `<lang>/LC_MESSAGES/<domain>.mo`.
+    pub fn add_lookup_path(prefix: &str) {
+        let prefix_c = c_string(prefix);
+        unsafe { ffi::wxd_Translations_AddCatalogLookupPath(prefix_c.as_ptr()) };
+    }
+
+    /// Makes the `.mo` catalog `data` available as the `language` translation of
+    /// `domain`, e.g. from `include_bytes!`. It is used by
+    /// [`add_catalog`](Self::add_catalog) in preference to files. Returns `false` if
+    /// `data` is not a valid catalog.
+    pub fn add_catalog_data(domain: &str, language: &str, data: &[u8]) -> bool {
+        let domain_c = c_string(domain);
+        let language_c = c_string(language);
+        unsafe {
+            ffi::wxd_Translations_AddCatalogData(
+                domain_c.as_ptr(),
+                language_c.as_ptr(),
+                data.as_ptr(),
+                data.len(),
+            )
+        }
+    }
+
+    /// Loads the best catalog of `domain` for the current language. Returns `false`
+    /// if none was found (and the language is not the one the messages are written in).
+    pub fn add_catalog(domain: &str) -> bool {
+        let domain_c = c_string(domain);
+        unsafe { ffi::wxd_Translations_AddCatalog(domain_c.as_ptr()) }
+    }
+
+    /// Loads wxWidgets' own catalog, which translates stock labels, standard dialogs
+    /// and their buttons.
+    pub fn add_std_catalog() -> bool {
+        unsafe { ffi::wxd_Translations_AddStdCatalog() }
+    }
+
+    /// Returns `true` if a catalog of `domain` is loaded.
+    pub fn is_loaded(domain: &str) -> bool {
+        let domain_c = c_string(domain);
+        unsafe { ffi::wxd_Translations_IsLoaded(domain_c.as_ptr()) }
+    }
+
+    /// The languages `domain` has catalogs for, on disk or added from memory.
+    pub fn available_languages(domain: &str) -> Vec<String> {
+        let domain_c = c_string(domain);
+        let languages = WxdArrayString::new();
+        unsafe {
+            ffi::wxd_Translations_GetAvailableLanguages(domain_c.as_ptr(), languages.as_ptr())
+        };
+        languages.get_strings()
+    }
+
+    /// Translates `text` using the loaded catalogs, or returns it unchanged.
+    pub fn translate(text: &str) -> String {
+        Self::translate_in("", text)
+    }
+
+    /// Translates `text` using only the catalog of `domain`.
+    pub fn translate_in(domain: &str, text: &str) -> String {
+        let text_c = c_string(text);
+        let domain_c = c_string(domain);
+        take_string(unsafe { ffi::wxd_Translations_Translate(text_c.as_ptr(), domain_c.as_ptr()) })
+    }
+
+    /// Translates the form of a message matching the count `n`, following the
+    /// plural rules of the language.
+    pub fn translate_plural(singular: &str, plural: &str, n: u64) -> String {
+        let singular_c = c_string(singular);
+        let plural_c = c_string(plural);
+        let domain_c = c_string("");
+        let n = n.min(u32::MAX as u64) as u32;
+        take_string(unsafe {
+            ffi::wxd_Translations_TranslatePlural(
+                singular_c.as_ptr(),
+                plural_c.as_ptr(),
+                n,
+                domain_c.as_ptr(),
+            )
+        })
+    }
+}
+
+/// Replaces `{}` (in order) and `{N}` (by position) with `args`; `{{` and `}}`
+/// stand for braces. Placeholders without an argument are left as they are.
+#[doc(hidden)]
+pub fn format_translated(template: &str, args: &[&dyn fmt::Display]) -> String {
+    let mut out = String::with_capacity(template.len());
+    let mut next = 0;
+    let mut rest = template;
+    while let Some(pos) = rest.find(['{', '}']) {
+        out.push_str(&rest[..pos]);
+        rest = &rest[pos..];
+        if rest.starts_with("{{") || rest.starts_with("}}") {
+            out.push_str(&rest[..1]);
+            rest = &rest[2..];
+            continue;
+        }
+        let placeholder = rest
+            .strip_prefix('{')
+            .and_then(|r| r.find('}').map(|end| &r[..end]));
+        let index = match placeholder {
+            Some("") => {
+                next += 1;
+                Some(next - 1)
+            }
+            Some(digits) => digits.parse::<usize>().ok(),
+            None => None,
+        };
+        match (placeholder, index.and_then(|i| args.get(i))) {
+            (Some(inner), Some(arg)) => {
+                let _ = write!(out, "{arg}");
+                rest = &rest[inner.len() + 2..];
+            }
+            _ => {
+                out.push_str(&rest[..1]);
+                rest = &rest[1..];
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Translates a message with the loaded catalogs, substituting any arguments.
+///
+/// ```rust,no_run
+/// # use wxdragon::tr;
+/// let name = "report.txt";
+/// let title = tr!("Save Changes");
+/// let question = tr!("Save changes to {}?", name);
+/// ```
+#[macro_export]
+macro_rules! tr {
+    ($text:literal) => {
+        $crate::locale::Translations::translate($text)
+    };
+    ($text:literal, $($arg:expr),+ $(,)?) => {
+        $crate::locale::format_translated(
+            &$crate::locale::Translations::translate($text),
+            &[$(&$arg as &dyn ::std::fmt::Display),+],
+        )
+    };
+}
+
+/// Translates the singular or plural form of a message for a count, which is
+/// substituted as the first argument.
+///
+/// ```rust,no_run
+/// # use wxdragon::tr_n;
+/// let count = 2;
+/// let folder = "Inbox";
+/// let text = tr_n!("{} message in {}", "{} messages in {}", count, folder);
+/// ```
+#[macro_export]
+macro_rules! tr_n {
+    ($singular:literal, $plural:literal, $n:expr $(, $arg:expr)* $(,)?) => {{
+        let n = $n;
+        $crate::locale::format_translated(
+            &$crate::locale::Translations::translate_plural($singular, $plural, n as u64),
+            &[&n as &dyn ::std::fmt::Display $(, &$arg as &dyn ::std::fmt::Display)*],
+        )
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use super::format_translated;
+
+    #[test]
+    fn braces_are_escaped_by_doubling() {
+        assert_eq!(format_translated("{{}} {{0}}", &[&1]), "{} {0}");
+        assert_eq!(format_translated("{{{}}}", &[&"x"]), "{x}");
+        assert_eq!(format_translated("a } b {", &[]), "a } b {");
+    }
+
+    #[test]
+    fn positional_arguments_can_be_reordered() {
+        assert_eq!(
+            format_translated("{1} before {0}", &[&"second", &"first"]),
+            "first before second"
+        );
+        assert_eq!(format_translated("{0}{0}{1}", &[&'a', &'b']), "aab");
+        assert_eq!(format_translated("{} and {}", &[&1, &2]), "1 and 2");
+    }
+
+    #[test]
+    fn placeholders_without_an_argument_are_kept() {
+        assert_eq!(format_translated("{2} {}", &[&"a"]), "{2} a");
+        assert_eq!(format_translated("{} {} {}", &[&1, &2]), "1 2 {}");
+        assert_eq!(format_translated("{name} {x1}", &[&0]), "{name} {x1}");
+    }
+}
@@ -0,0 +1,347 @@
+//! wxWidgets logging (`wxLog`) and its bridge to the `log` crate.
+//!
+//! wxWidgets reports problems through its active log target, which in a GUI
+//! application shows errors and warnings in message boxes. [`set_log_target`]
+//! replaces that target, for example to forward everything to the `log` crate:
+//!
+//! ```rust,no_run
+//! use wxdragon::logging::{set_log_target, LogTarget};
+//!
+//! set_log_target(LogTarget::LogCrate);
+//! ```
+//!
+//! In the other direction, [`WxLogger`] is a `log::Log` implementation sending
+//! records to the active wx target, e.g. a text control showing the log:
+//!
+//! ```rust,no_run
+//! use wxdragon::logging::{set_log_target, LogTarget, WxLogger};
+//! # fn setup(log_view: &wxdragon::widgets::TextCtrl) {
+//!
+//! set_log_target(LogTarget::TextCtrl(log_view));
+//! WxLogger::new().with_level(log::LevelFilter::Debug).init().unwrap();
+//! log::info!("ready");
+//! # }
+//! ```
+//!
+//! A message is never passed back in the direction it came from, so nothing can
+//! loop; using [`LogTarget::LogCrate`] together with [`WxLogger`] therefore drops
+//! the messages of both sides, so pick one direction. To silence wx messages for a while, hold a
+//! [`LogNull`].
+
+use crate::widgets::TextCtrl;
+use crate::window::WxWidget;
+use std::cell::Cell;
+use std::ffi::{c_void, CStr, CString};
+use std::os::raw::c_char;
+use wxdragon_sys as ffi;
+
+/// Severity of a wx log message.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LogLevel {
+    /// A fatal error; wx aborts the program after logging it.
+    FatalError,
+    /// An error.
+    Error,
+    /// A warning.
+    Warning,
+    /// A normal message.
+    Message,
+    /// A message for the status bar.
+    Status,
+    /// An informational message, only shown by wx in verbose mode.
+    Info,
+    /// A debugging message.
+    Debug,
+    /// A tracing message.
+    Trace,
+    /// A progress message.
+    Progress,
+}
+
+impl LogLevel {
+    fn to_raw(self) -> ffi::wxd_LogLevel {
+        match self {
+            LogLevel::FatalError => ffi::wxd_LogLevel_WXD_LOG_FATAL_ERROR,
+            LogLevel::Error => ffi::wxd_LogLevel_WXD_LOG_ERROR,
+            LogLevel::Warning => ffi::wxd_LogLevel_WXD_LOG_WARNING,
+            LogLevel::Message => ffi::wxd_LogLevel_WXD_LOG_MESSAGE,
+            LogLevel::Status => ffi::wxd_LogLevel_WXD_LOG_STATUS,
+            LogLevel::Info => ffi::wxd_LogLevel_WXD_LOG_INFO,
+            LogLevel::Debug => ffi::wxd_LogLevel_WXD_LOG_DEBUG,
+            LogLevel::Trace => ffi::wxd_LogLevel_WXD_LOG_TRACE,
+            LogLevel::Progress => ffi::wxd_LogLevel_WXD_LOG_PROGRESS,
+        }
+    }
+
+    fn from_raw(raw: ffi::wxd_LogLevel) -> Self {
+        match raw {
+            ffi::wxd_LogLevel_WXD_LOG_FATAL_ERROR => LogLevel::FatalError,
+            ffi::wxd_LogLevel_WXD_LOG_ERROR => LogLevel::Error,
+            ffi::wxd_LogLevel_WXD_LOG_WARNING => LogLevel::Warning,
+            ffi::wxd_LogLevel_WXD_LOG_MESSAGE => LogLevel::Message,
+            ffi::wxd_LogLevel_WXD_LOG_STATUS => LogLevel::Status,
+            ffi::wxd_LogLevel_WXD_LOG_INFO => LogLevel::Info,
+            ffi::wxd_LogLevel_WXD_LOG_DEBUG => LogLevel::Debug,
+            ffi::wxd_LogLevel_WXD_LOG_PROGRESS => LogLevel::Progress,
+            _ => LogLevel::Trace,
+        }
+    }
+
+    /// The `log` crate level wx messages of this level are forwarded with.
+    pub fn to_log_level(self) -> log::Level {
+        match self {
+            LogLevel::FatalError | LogLevel::Error => log::Level::Error,
+            LogLevel::Warning => log::Level::Warn,
+            LogLevel::Message | LogLevel::Status | LogLevel::Info | LogLevel::Progress => {
+                log::Level::Info
+            }
+            LogLevel::Debug => log::Level::Debug,
+            LogLevel::Trace => log::Level::Trace,
+        }
+    }
+
+    /// The wx level `log` crate records of `level` are logged with.
+    pub fn from_log_level(level: log::Level) -> Self {
+        match level {
+            log::Level::Error => LogLevel::Error,
+            log::Level::Warn => LogLevel::Warning,
+            log::Level::Info => LogLevel::Message,
+            log::Level::Debug => LogLevel::Debug,
+            log::Level::Trace => LogLevel::Trace,
+        }
+    }
+}
+
+/// Where wx log messages go. See [`set_log_target`].
+#[derive(Clone, Copy)]
+pub enum LogTarget<'a> {
+    /// wx's default: message boxes for errors and warnings in GUI applications,
+    /// standard error otherwise.
+    Default,
+    /// Standard error.
+    Stderr,
+    /// Records of the `log` crate, with the target `wx` or the wx component name.
+    LogCrate,
+    /// Appended to a text control. Messages are dropped once it is destroyed.
+    TextCtrl(&'a TextCtrl),
+    /// A separate frame listing the messages.
+    Window {
+        /// The frame's parent, if any.
+        parent: Option<&'a dyn WxWidget>,
+        /// The frame's title.
+        title: &'a str,
+        /// Whether to show the frame right away; see [`show_log_window`].
+        show: bool,
+    },
+}
+
+thread_local! {
+    // Set while a message crosses between wx and the log crate
+    static IN_BRIDGE: Cell<bool> = const { Cell::new(false) };
+}
+
+fn bridged<R>(f: impl FnOnce() -> R) -> Option<R> {
+    if IN_BRIDGE.with(|flag| flag.replace(true)) {
+        return None;
+    }
+    let result = f();
+    IN_BRIDGE.with(|flag| flag.set(false));
+    Some(result)
+}
+
+unsafe fn opt_str<'a>(ptr: *const c_char) -> Option<&'a str> {
+    if ptr.is_null() {
+        None
+    } else {
+        CStr::from_ptr(ptr).to_str().ok().filter(|s| !s.is_empty())
+    }
+}
+
+unsafe extern "C" fn log_crate_trampoline(
+    _user_data: *mut c_void,
+    level: ffi::wxd_LogLevel,
+    message: *const c_char,
+    component: *const c_char,
+    file: *const c_char,
+    line: i32,
+) {
+    if message.is_null() {
+        return;
+    }
+    let level = LogLevel::from_raw(level).to_log_level();
+    let target = opt_str(component).unwrap_or("wx");
+    if !log::log_enabled!(target: target, level) {
+        return;
+    }
+    let message = CStr::from_ptr(message).to_string_lossy();
+    let file = opt_str(file);
+    bridged(|| {
+        crate::panic_policy::catch("wx log forwarding", || {
+            log::logger().log(
+                &log::Record::builder()
+                    .level(level)
+                    .target(target)
+                    .file(file)
+                    .line(u32::try_from(line).ok().filter(|&l| l > 0))
+                    .args(format_args!("{message}"))
+                    .build(),
+            )
+        })
+    });
+}
+
+/// Replaces wx's active log target. The previous target is deleted, closing a
+/// previous log window.
+///
+/// Returns `false` if the target could not be installed.
+pub fn set_log_target(target: LogTarget<'_>) -> bool {
+    match target {
+        LogTarget::Default => unsafe { ffi::wxd_Log_SetDefaultTarget() },
+        LogTarget::Stderr => unsafe { ffi::wxd_Log_SetStderrTarget() },
+        LogTarget::LogCrate => unsafe {
+            ffi::wxd_Log_SetCallbackTarget(Some(log_crate_trampoline), std::ptr::null_mut())
+        },
+        LogTarget::TextCtrl(text) => {
+            return unsafe { ffi::wxd_Log_SetTextCtrlTarget(text.handle_ptr()) };
+        }
+        LogTarget::Window {
+            parent,
+            title,
+            show,
+        } => {
+            let parent = parent.map_or(std::ptr::null_mut(), |p| p.handle_ptr());
+            let title_c = CString::new(title).unwrap_or_default();
+            unsafe { ffi::wxd_Log_SetWindowTarget(parent, title_c.as_ptr(), show) };
+        }
+    }
+    true
+}
+
+/// Shows or hides the frame of a [`LogTarget::Window`] target. Returns `false` if
+/// the active target is not a log window.
+pub fn show_log_window(show: bool) -> bool {
+    unsafe { ffi::wxd_Log_ShowWindow(show) }
+}
+
+/// Logs `message` through the active wx log target.
+pub fn log_message(level: LogLevel, message: &str) {
+    let message_c = CString::new(message).unwrap_or_default();
+    unsafe { ffi::wxd_Log_Message(level.to_raw(), message_c.as_ptr()) };
+}
+
+/// Shows messages still buffered by the active target, e.g. errors collected
+/// for a single message box.
+pub fn flush() {
+    unsafe { ffi::wxd_Log_Flush() };
+}
+
+/// Discards wx messages less severe than `level`.
+pub fn set_log_level(level: LogLevel) {
+    unsafe { ffi::wxd_Log_SetLogLevel(level.to_raw()) };
+}
+
+/// Makes wx's own targets show [`LogLevel::Info`] messages.
+pub fn set_verbose(verbose: bool) {
+    unsafe { ffi::wxd_Log_SetVerbose(verbose) };
+}
+
+/// Suppresses wx log messages on this thread while alive (`wxLogNull`), e.g. the
+/// error box for a file that is allowed to be missing.
+///
+/// ```rust,no_run
+/// use wxdragon::logging::LogNull;
+///
+/// let _quiet = LogNull::new();
+/// // wx errors raised here are dropped
+/// ```
+#[must_use = "logging is only suppressed while the LogNull is alive"]
+pub struct LogNull {
+    ptr: *mut ffi::wxd_LogNull_t,
+}
+
+impl LogNull {
+    /// Suppresses logging until the returned value is dropped.
+    pub fn new() -> Self {
+        Self {
+            ptr: unsafe { ffi::wxd_LogNull_Create() },
+        }
+    }
+}
+
+impl Default for LogNull {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for LogNull {
+    fn drop(&mut self) {
+        unsafe { ffi::wxd_LogNull_Destroy(self.ptr) };
+    }
+}
+
+/// A `log::Log` implementation sending records to the active wx log target.
+///
+/// Records logged from other threads are shown once the main thread processes
+/// them. `Info` records become [`LogLevel::Message`], so the default GUI target
+/// shows them in a message box; set another target with [`set_log_target`].
+pub struct WxLogger {
+    level: log::LevelFilter,
+    with_target: bool,
+}
+
+impl WxLogger {
+    /// Creates a logger passing records up to `Info`.
+    pub fn new() -> Self {
+        Self {
+            level: log::LevelFilter::Info,
+            with_target: false,
+        }
+    }
+
+    /// Sets the most verbose level passed on.
+    pub fn with_level(mut self, level: log::LevelFilter) -> Self {
+        self.level = level;
+        self
+    }
+
+    /// Prefixes each message with the record's target, e.g. `my_app::net: ...`.
+    pub fn with_target(mut self, with_target: bool) -> Self {
+        self.with_target = with_target;
+        self
+    }
+
+    /// Installs this logger as the `log` crate's logger and sets its maximum level.
+    pub fn init(self) -> Result<(), log::SetLoggerError> {
+        let level = self.level;
+        log::set_logger(Box::leak(Box::new(self)))?;
+        log::set_max_level(level);
+        Ok(())
+    }
+}
+
+impl Default for WxLogger {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl log::Log for WxLogger {
+    fn enabled(&self, metadata: &log::Metadata) -> bool {
+        metadata.level() <= self.level
+    }
+
+    fn log(&self, record: &log::Record) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+        let message = if self.with_target {
+            format!("{}: {}", record.target(), record.args())
+        } else {
+            record.args().to_string()
+        };
+        bridged(|| log_message(LogLevel::from_log_level(record.level()), &message));
+    }
+
+    fn flush(&self) {}
+}
@@ -23,6 +23,13 @@ impl Menu {
         MenuBuilder::new()
     }
 
+    /// Destroys a menu that was never added to a menubar or another menu, such as
+    /// one built for [`popup_menu`](crate::window::WxWidget::popup_menu).
+    /// Does nothing for menus owned by a menubar or parent menu.
+    pub fn destroy(self) {
+        unsafe { ffi::wxd_Menu_Destroy(self.ptr) };
+    }
+
     /// Appends a menu item.
     /// Returns a wrapper for the created item (for potential modification), but ownership remains with the menu.
     pub fn append(
@@ -40,6 +47,21 @@ impl Menu {
         self.append_separator_raw();
     }
 
+    /// Appends `submenu` as a submenu item labelled `text`.
+    /// The submenu is owned by this menu afterwards.
+    pub fn append_submenu(&self, submenu: Menu, text: &str, help_string: &str) -> Option<MenuItem> {
+        let text_c = CString::new(text).unwrap_or_default();
+        let help_c = CString::new(help_string).unwrap_or_default();
+        let item_ptr = unsafe {
+            ffi::wxd_Menu_AppendSubMenu(self.ptr, submenu.ptr, text_c.as_ptr(), help_c.as_ptr())
+        };
+        if item_ptr.is_null() {
+            None
+        } else {
+            Some(unsafe { MenuItem::from_ptr(item_ptr) })
+        }
+    }
+
     /// Gets a menu item by its XRC name.
     /// Returns a MenuItem wrapper that can be used for event binding.
     #[cfg(feature = "xrc")]
@@ -121,7 +143,11 @@ enum MenuAction {
         kind: ItemKind,
     },
     AppendSeparator,
-    // TODO: Add AppendSubMenu if needed
+    SubMenu {
+        submenu: Menu,
+        text: String,
+        help: String,
+    },
 }
 
 /// Builder for [`Menu`].
@@ -176,6 +202,16 @@ impl MenuBuilder {
         self
     }
 
+    /// Adds a submenu to be appended to the menu.
+    pub fn append_submenu(mut self, submenu: Menu, text: &str, help: &str) -> Self {
+        self.actions.push(MenuAction::SubMenu {
+            submenu,
+            text: text.to_string(),
+            help: help.to_string(),
+        });
+        self
+    }
+
     /// Builds the `Menu`.
     ///
     /// # Panics
@@ -205,6 +241,13 @@ impl MenuBuilder {
                 MenuAction::AppendSeparator => {
                     menu.append_separator_raw();
                 }
+                MenuAction::SubMenu {
+                    submenu,
+                    text,
+                    help,
+                } => {
+                    let _ = menu.append_submenu(submenu, &text, &help);
+                }
             }
         }
         menu
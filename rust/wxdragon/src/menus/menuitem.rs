@@ -1,7 +1,7 @@
 // wxdragon/src/menus/menuitem.rs
 //! wxMenuItem wrapper and related types
 
-use crate::event::{Event, EventType, WxEvtHandler};
+use crate::event::{Event, EventBinding, EventType, WxEvtHandler};
 use crate::window::{Window, WxWidget};
 use std::ffi::{CStr, CString};
 use wxdragon_sys as ffi;
@@ -87,13 +87,13 @@ impl MenuItem {
 
     /// Binds a click event handler to this menu item.
     /// This binds a menu event on the parent window for this item's ID.
-    pub fn on_click<F>(&self, handler: F)
+    pub fn on_click<F>(&self, handler: F) -> EventBinding
     where
         F: FnMut(Event) + 'static,
     {
         // Use ID-specific binding for MENU events
         self.parent_window
-            .bind_with_id_internal(EventType::MENU, self.item_id, handler);
+            .bind_with_id_internal(EventType::MENU, self.item_id, handler)
     }
 
     /// Special XRC loading method for menu items.
@@ -0,0 +1,201 @@
+//! Application-wide handling of panics raised inside callbacks.
+//!
+//! Panics must not unwind into wxWidgets, so every callback invoked from C++ (event
+//! handlers, timers, `call_after` closures, dataview models and renderers, drag and
+//! drop targets, lifecycle hooks) runs behind a guard. What happens after a panic is
+//! caught is decided by the [`PanicPolicy`] set with [`set_panic_policy`]:
+//!
+//! ```rust,no_run
+//! use wxdragon::prelude::*;
+//!
+//! // Release builds: tell the user, then shut down cleanly instead of running on
+//! // in a possibly inconsistent state.
+//! set_panic_policy(PanicPolicy::ExitMainLoop(101));
+//! ```
+//!
+//! Panics in futures spawned with [`spawn_local`](crate::spawn_local) are delivered
+//! to the task's [`JoinHandle`](crate::task::JoinHandle) instead. Only when the handle
+//! has been dropped, so nobody can receive the panic, is it handled by the policy.
+
+use std::any::Any;
+use std::backtrace::Backtrace;
+use std::cell::{Cell, RefCell};
+use std::ffi::CString;
+use std::fmt;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::sync::{Arc, Once, RwLock};
+use wxdragon_sys as ffi;
+
+/// Handler invoked by [`PanicPolicy::Custom`].
+pub type PanicHandler = Arc<dyn Fn(&PanicReport) + Send + Sync + 'static>;
+
+/// What to do after a panic has been caught in a callback.
+#[derive(Clone, Default)]
+pub enum PanicPolicy {
+    /// Print the panic to stderr and keep running. This is the default.
+    #[default]
+    LogAndContinue,
+    /// Print the panic and show an error dialog with the message and backtrace,
+    /// then keep running.
+    ShowDialog,
+    /// Print the panic and exit the main loop with the given code, so
+    /// [`AppBuilder::run`](crate::app::AppBuilder::run) returns it.
+    ExitMainLoop(i32),
+    /// Print the panic and abort the process immediately.
+    Abort,
+    /// Call a custom handler. The application keeps running afterwards unless
+    /// the handler exits the loop or the process.
+    Custom(PanicHandler),
+}
+
+impl fmt::Debug for PanicPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PanicPolicy::LogAndContinue => write!(f, "LogAndContinue"),
+            PanicPolicy::ShowDialog => write!(f, "ShowDialog"),
+            PanicPolicy::ExitMainLoop(code) => write!(f, "ExitMainLoop({code})"),
+            PanicPolicy::Abort => write!(f, "Abort"),
+            PanicPolicy::Custom(_) => write!(f, "Custom(..)"),
+        }
+    }
+}
+
+/// Details of a caught panic.
+#[derive(Debug, Clone)]
+pub struct PanicReport {
+    /// The kind of callback that panicked, e.g. `"event handler"`.
+    pub context: &'static str,
+    /// The panic message.
+    pub message: String,
+    /// The backtrace captured when the panic was raised, if available.
+    pub backtrace: Option<String>,
+}
+
+impl fmt::Display for PanicReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "panic in {}: {}", self.context, self.message)
+    }
+}
+
+static POLICY: RwLock<Option<PanicPolicy>> = RwLock::new(None);
+static INSTALL_HOOK: Once = Once::new();
+
+thread_local! {
+    // Number of active guards on this thread; the hook only captures backtraces inside one
+    static GUARD_DEPTH: Cell<usize> = const { Cell::new(0) };
+    static LAST_BACKTRACE: RefCell<Option<String>> = const { RefCell::new(None) };
+    static SHOWING_DIALOG: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Sets how panics caught in callbacks are handled, for all threads.
+pub fn set_panic_policy(policy: PanicPolicy) {
+    *POLICY.write().unwrap_or_else(|e| e.into_inner()) = Some(policy);
+}
+
+/// Returns the current panic policy.
+pub fn panic_policy() -> PanicPolicy {
+    POLICY
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .clone()
+        .unwrap_or_default()
+}
+
+/// Chains a panic hook that records a backtrace for panics raised inside [`catch`].
+fn install_hook() {
+    INSTALL_HOOK.call_once(|| {
+        let previous = std::panic::take_hook();
+        std::panic::set_hook(Box::new(move |info| {
+            if GUARD_DEPTH.with(|depth| depth.get()) > 0 {
+                let backtrace = Backtrace::force_capture().to_string();
+                LAST_BACKTRACE.with(|last| *last.borrow_mut() = Some(backtrace));
+            }
+            previous(info);
+        }));
+    });
+}
+
+/// Runs `f`, catching any panic and handling it according to the panic policy.
+///
+/// Returns `None` if `f` panicked. `context` names the kind of callback for the report.
+pub(crate) fn catch<R>(context: &'static str, f: impl FnOnce() -> R) -> Option<R> {
+    install_hook();
+    GUARD_DEPTH.with(|depth| depth.set(depth.get() + 1));
+    let result = catch_unwind(AssertUnwindSafe(f));
+    GUARD_DEPTH.with(|depth| depth.set(depth.get() - 1));
+
+    match result {
+        Ok(value) => Some(value),
+        Err(payload) => {
+            let backtrace = LAST_BACKTRACE.with(|last| last.borrow_mut().take());
+            handle(PanicReport {
+                context,
+                message: panic_message(payload.as_ref()),
+                backtrace,
+            });
+            None
+        }
+    }
+}
+
+/// Handles a panic that was caught elsewhere and has nobody left to receive it, such
+/// as one raised in a detached task. May be called from any thread; the policy is
+/// applied on the main thread.
+pub(crate) fn report(context: &'static str, payload: &(dyn Any + Send)) {
+    let report = PanicReport {
+        context,
+        message: panic_message(payload),
+        backtrace: None,
+    };
+    crate::app::call_after(Box::new(move || handle(report)));
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "Box<dyn Any>".to_string()
+    }
+}
+
+fn handle(report: PanicReport) {
+    let policy = panic_policy();
+    if !matches!(policy, PanicPolicy::Custom(_)) {
+        eprintln!("wxDragon: {report}");
+    }
+
+    match policy {
+        PanicPolicy::LogAndContinue => {}
+        PanicPolicy::ShowDialog => show_dialog(&report),
+        PanicPolicy::ExitMainLoop(code) => {
+            if !crate::event_loop::EventLoop::exit(code) {
+                // Not inside the main loop (e.g. during initialization): nothing to exit
+                eprintln!("wxDragon: main loop is not running, continuing");
+            }
+        }
+        PanicPolicy::Abort => std::process::abort(),
+        PanicPolicy::Custom(handler) => {
+            // A panicking handler must not unwind into C++ either
+            if catch_unwind(AssertUnwindSafe(|| handler(&report))).is_err() {
+                eprintln!("wxDragon: panic handler panicked while handling {report}");
+            }
+        }
+    }
+}
+
+fn show_dialog(report: &PanicReport) {
+    // A panic raised while the dialog's own loop is running is only logged
+    if SHOWING_DIALOG.with(|showing| showing.replace(true)) {
+        return;
+    }
+    let message = CString::new(format!(
+        "An unexpected error occurred in {}:\n\n{}",
+        report.context, report.message
+    ))
+    .unwrap_or_default();
+    let details = CString::new(report.backtrace.clone().unwrap_or_default()).unwrap_or_default();
+    unsafe { ffi::wxd_App_ShowErrorDialog(message.as_ptr(), details.as_ptr()) };
+    SHOWING_DIALOG.with(|showing| showing.set(false));
+}
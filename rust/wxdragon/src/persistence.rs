@@ -0,0 +1,123 @@
+//! Remembering window state between runs (`wxPersistenceManager`).
+//!
+//! [`persist`] names a window, restores the state saved for that name in an
+//! earlier session and saves it again when the window is destroyed:
+//!
+//! ```rust,no_run
+//! use wxdragon::prelude::*;
+//!
+//! # fn setup(frame: &Frame, splitter: &SplitterWindow, notebook: &Notebook) {
+//! // After the children are created and the splitter is split
+//! persist(splitter, "MainSplitter");
+//! persist(notebook, "MainNotebook");
+//! persist(frame, "MainFrame");
+//! frame.show(true);
+//! # }
+//! ```
+//!
+//! What is remembered depends on the widget:
+//!
+//! | Widget | State |
+//! |--------|-------|
+//! | [`Frame`], [`Dialog`] | position, size, maximized and iconized state |
+//! | [`Notebook`], [`SimpleBook`], `AuiNotebook` | selected page |
+//! | [`Treebook`] | selected page and expanded branches |
+//! | [`SplitterWindow`] | sash position |
+//! | [`ListCtrl`] | column widths, and column order on Windows |
+//! | [`DataViewCtrl`] and its variants | column widths, visibility and sort order, and column order on Windows |
+//! | `AuiManager` | the layout, as saved by `save_perspective` |
+//!
+//! The state is stored in the global [`Config`](crate::config::Config) under
+//! `Persistent_Options/<kind>/<name>`; one is created from the application name if
+//! none was set. Names only need to be unique among widgets of the same kind.
+
+use crate::dialogs::Dialog;
+use crate::widgets::{
+    DataViewCtrl, DataViewListCtrl, DataViewTreeCtrl, Frame, ListCtrl, Notebook, SimpleBook,
+    SplitterWindow, Treebook,
+};
+use crate::window::WxWidget;
+use std::ffi::{c_void, CStr, CString};
+use wxdragon_sys as ffi;
+
+/// An object whose state can be saved and restored with [`persist`].
+pub trait Persistent {
+    #[doc(hidden)]
+    fn register_persistent(&self, name: &CStr) -> bool;
+
+    #[doc(hidden)]
+    fn persistent_key(&self) -> *mut c_void;
+}
+
+/// Restores the state saved under `name` for `object` and saves it again when
+/// `object` is destroyed. Returns `true` if saved state was found and applied.
+///
+/// Call it once the object is set up (columns added, splitter split, AUI panes
+/// added) and, for frames and dialogs, before they are shown. Persisting an object
+/// a second time has no effect and returns `false`.
+pub fn persist<P: Persistent + ?Sized>(object: &P, name: &str) -> bool {
+    let name_c = CString::new(name).unwrap_or_default();
+    object.register_persistent(&name_c)
+}
+
+/// Saves the state of a persisted object now instead of waiting for it to be
+/// destroyed.
+pub fn save<P: Persistent + ?Sized>(object: &P) {
+    unsafe { ffi::wxd_Persistence_Save(object.persistent_key()) };
+}
+
+/// Stops tracking a persisted object; its state is not saved when it is destroyed.
+pub fn forget<P: Persistent + ?Sized>(object: &P) {
+    unsafe { ffi::wxd_Persistence_Unregister(object.persistent_key()) };
+}
+
+/// Ignores saved state for the rest of the session, e.g. for a "reset layout"
+/// command line option. State is still saved on exit.
+pub fn disable_restoring() {
+    unsafe { ffi::wxd_Persistence_DisableRestoring() };
+}
+
+/// Stops saving state for the rest of the session.
+pub fn disable_saving() {
+    unsafe { ffi::wxd_Persistence_DisableSaving() };
+}
+
+fn register_window(
+    window: *mut ffi::wxd_Window_t,
+    kind: ffi::wxd_PersistentKind,
+    name: &CStr,
+) -> bool {
+    unsafe { ffi::wxd_Persistence_RegisterAndRestore(window, kind, name.as_ptr()) }
+}
+
+macro_rules! impl_persistent_window {
+    ($kind:ident => $($ty:ty),+ $(,)?) => {
+        $(
+            impl Persistent for $ty {
+                fn register_persistent(&self, name: &CStr) -> bool {
+                    register_window(self.handle_ptr(), ffi::$kind, name)
+                }
+
+                fn persistent_key(&self) -> *mut c_void {
+                    self.handle_ptr() as *mut c_void
+                }
+            }
+        )+
+    };
+}
+
+impl_persistent_window!(wxd_PersistentKind_WXD_PERSIST_TOPLEVEL => Frame, Dialog);
+impl_persistent_window!(wxd_PersistentKind_WXD_PERSIST_BOOK => Notebook, SimpleBook);
+impl_persistent_window!(wxd_PersistentKind_WXD_PERSIST_TREEBOOK => Treebook);
+impl_persistent_window!(wxd_PersistentKind_WXD_PERSIST_SPLITTER => SplitterWindow);
+impl_persistent_window!(wxd_PersistentKind_WXD_PERSIST_LISTCTRL => ListCtrl);
+impl_persistent_window!(
+    wxd_PersistentKind_WXD_PERSIST_DATAVIEW => DataViewCtrl,
+    DataViewListCtrl,
+    DataViewTreeCtrl,
+);
+
+#[cfg(feature = "aui")]
+impl_persistent_window!(
+    wxd_PersistentKind_WXD_PERSIST_BOOK => crate::widgets::aui_notebook::AuiNotebook
+);
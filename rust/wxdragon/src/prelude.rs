@@ -1,30 +1,60 @@
 // --- Core Types & Traits ---
-pub use crate::app::{call_after, get_app, main, set_appearance, set_top_window};
+pub use crate::accelerator::{Accelerator, AcceleratorParseError};
+pub use crate::app::{
+    call_after, get_app, main, set_appearance, set_main_thread_time_budget, set_top_window,
+    AppBuilder, AppError,
+};
 pub use crate::appearance::{
     get_app as get_app_for_appearance, get_system_appearance, is_system_dark_mode, AppAppearance,
     Appearance, AppearanceResult, SystemAppearance,
 };
 pub use crate::clipboard::{Clipboard, ClipboardLocker};
 pub use crate::color::{colours, Colour};
+pub use crate::command_processor::{Command, CommandProcessor};
+#[cfg(feature = "serde")]
+pub use crate::config::ConfigError;
+pub use crate::config::{Config, FromConfig, ToConfig};
 pub use crate::cursor::{
     begin_busy_cursor, end_busy_cursor, is_busy, set_cursor, BitmapType, BusyCursor, Cursor,
     StockCursor,
 };
 pub use crate::datetime::DateTime;
-pub use crate::event::{Event, EventType, IdleEvent, IdleMode, WindowEventData, WxEvtHandler};
+pub use crate::event::{
+    CustomEvent, CustomEventData, Event, EventBinding, EventFilter, EventSink, EventType,
+    FilterResult, IdleEvent, IdleMode, KeyCode, Modifiers, MouseButton, MouseButtons, WheelAxis,
+    WindowEventData, WxEvtHandler,
+};
 // ADDED: Event category traits
 pub use crate::event::{ButtonEvents, ScrollEvents, TextEvents, TreeEvents, WindowEvents};
 // ADDED: Event Data Structs
 pub use crate::event::event_data::{CommandEventData, KeyEventData, MouseEventData};
 pub use crate::event::IdleEventData;
+pub use crate::event_loop::EventLoop;
+pub use crate::file_history::{FileHistory, RecentFile};
+pub use crate::fs_watcher::{FileSystemWatcher, FsChangeKinds, FsEvent, FsWarning};
 pub use crate::geometry::{Point, Rect, Size, DEFAULT_POSITION, DEFAULT_SIZE};
-pub use crate::id::{Id, ID_ANY, ID_CANCEL, ID_HIGHEST, ID_NO, ID_OK, ID_YES};
+pub use crate::id::{
+    Id, ID_ANY, ID_APPLY, ID_CANCEL, ID_CLOSE, ID_FILE1, ID_HELP, ID_HIGHEST, ID_NO, ID_NONE,
+    ID_OK, ID_REDO, ID_UNDO, ID_YES,
+};
+pub use crate::locale::{DateFormat, LayoutDirection, Locale, Translations};
+pub use crate::logging::{set_log_target, LogNull, LogTarget, WxLogger};
+pub use crate::panic_policy::{set_panic_policy, PanicPolicy, PanicReport};
+pub use crate::persistence::{persist, Persistent};
+pub use crate::process::{Process, ProcessBuilder, Signal};
+#[cfg(feature = "single-instance")]
+pub use crate::single_instance::InstanceArgs;
 pub use crate::sizers::WxSizer;
+pub use crate::standard_paths::StandardPaths;
+pub use crate::task::{spawn, spawn_local, JoinHandle};
+pub use crate::timer::{call_every, call_later, Debouncer, Throttler, TimerHandle};
 pub use crate::types::Style;
+pub use crate::ui_channel::{ui_channel, SendError, TrySendError, UiChannelBuilder};
 pub use crate::utils::WxdArrayString;
 pub use crate::window::{
     BackgroundStyle, ExtraWindowStyle, Window, WindowStyle, WxWidget, WxWidgetDowncast,
 };
+pub use crate::{tr, tr_n};
 
 // --- Sizers ---
 pub use crate::sizers::box_sizer::{BoxSizer, BoxSizerBuilder};
@@ -227,7 +257,7 @@ pub use crate::dialogs::single_choice_dialog::{SingleChoiceDialog, SingleChoiceD
 pub use crate::dialogs::text_entry_dialog::{
     TextEntryDialog, TextEntryDialogBuilder, TextEntryDialogStyle,
 };
-pub use crate::dialogs::{Dialog, DialogBuilder, DialogStyle}; // Base Dialog struct and builder
+pub use crate::dialogs::{Dialog, DialogBuilder, DialogResult, DialogStyle, WindowModalFuture}; // Base Dialog struct and builder
 
 // --- Fonts ---
 pub use crate::font::{Font, FontBuilder, FontFamily, FontStyle, FontWeight}; // Added FontBuilder
@@ -0,0 +1,365 @@
+//! Child processes started asynchronously (`wxProcess`/`wxExecute`).
+//!
+//! A [`Process`] runs alongside the event loop with its standard streams
+//! redirected. Each line the child writes arrives as an event on the UI thread,
+//! so handlers can append it straight to a control, and a last event reports
+//! the exit code:
+//!
+//! ```rust,no_run
+//! use wxdragon::prelude::*;
+//!
+//! # fn build(log_view: &TextCtrl) -> Result<(), wxdragon::process::ProcessError> {
+//! let build = Process::builder("cargo")
+//!     .args(["build", "--release"])
+//!     .current_dir("/path/to/project")
+//!     .env("CARGO_TERM_COLOR", "never")
+//!     .spawn()?;
+//!
+//! let view = log_view.clone();
+//! build.on_output(move |event| view.append_text(&format!("{}\n", event.line())));
+//! let view = log_view.clone();
+//! build.on_error_output(move |event| view.append_text(&format!("{}\n", event.line())));
+//! build.on_terminated(|event| println!("cargo exited with {}", event.exit_code()));
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Dropping the [`Process`] does not stop the child: it keeps running and its
+//! handlers keep being called until it exits. Use [`Process::kill`] to stop it.
+
+use crate::event::{Event, EventBinding, EventType, WxEvtHandler};
+use std::ffi::{CString, OsStr, OsString};
+use std::fmt;
+use std::os::raw::c_char;
+use std::path::{Path, PathBuf};
+use wxdragon_sys as ffi;
+
+/// Errors returned when starting a child process.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ProcessError {
+    /// The program, an argument, a variable or the directory is not valid UTF-8
+    /// or contains a NUL byte.
+    InvalidArgument(String),
+    /// The program could not be started.
+    SpawnFailed,
+}
+
+impl fmt::Display for ProcessError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ProcessError::InvalidArgument(arg) => write!(f, "invalid process argument: {arg:?}"),
+            ProcessError::SpawnFailed => write!(f, "failed to start the process"),
+        }
+    }
+}
+
+impl std::error::Error for ProcessError {}
+
+/// Signals [`Process::kill`] can send.
+///
+/// On Windows, [`Signal::Kill`] and [`Signal::Term`] terminate the process and
+/// [`Signal::None`] checks that it exists; the others are not supported.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Signal {
+    /// No signal; only checks that the process exists.
+    None,
+    /// `SIGHUP`.
+    Hup,
+    /// `SIGINT`, as sent by Ctrl+C in a terminal.
+    Int,
+    /// `SIGQUIT`.
+    Quit,
+    /// `SIGKILL`, which cannot be caught.
+    Kill,
+    /// `SIGTERM`, asking the process to exit.
+    Term,
+}
+
+impl Signal {
+    fn to_raw(self) -> ffi::wxd_Signal {
+        match self {
+            Signal::None => ffi::wxd_Signal_WXD_SIGNAL_NONE,
+            Signal::Hup => ffi::wxd_Signal_WXD_SIGNAL_HUP,
+            Signal::Int => ffi::wxd_Signal_WXD_SIGNAL_INT,
+            Signal::Quit => ffi::wxd_Signal_WXD_SIGNAL_QUIT,
+            Signal::Kill => ffi::wxd_Signal_WXD_SIGNAL_KILL,
+            Signal::Term => ffi::wxd_Signal_WXD_SIGNAL_TERM,
+        }
+    }
+}
+
+/// Why [`Process::kill`] failed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum KillError {
+    /// The signal is not supported on this platform.
+    BadSignal,
+    /// The process belongs to another user.
+    AccessDenied,
+    /// The process has already exited.
+    NoProcess,
+    /// Another error.
+    Other,
+}
+
+impl fmt::Display for KillError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            KillError::BadSignal => write!(f, "unsupported signal"),
+            KillError::AccessDenied => write!(f, "permission denied"),
+            KillError::NoProcess => write!(f, "no such process"),
+            KillError::Other => write!(f, "failed to signal the process"),
+        }
+    }
+}
+
+impl std::error::Error for KillError {}
+
+fn to_cstring(value: &OsStr) -> Result<CString, ProcessError> {
+    value
+        .to_str()
+        .and_then(|s| CString::new(s).ok())
+        .ok_or_else(|| ProcessError::InvalidArgument(value.to_string_lossy().into_owned()))
+}
+
+/// Builder for [`Process`], modelled on `std::process::Command`.
+#[derive(Debug, Clone)]
+pub struct ProcessBuilder {
+    program: OsString,
+    args: Vec<OsString>,
+    env: Vec<(OsString, Option<OsString>)>,
+    env_clear: bool,
+    cwd: Option<PathBuf>,
+}
+
+impl ProcessBuilder {
+    fn new(program: &OsStr) -> Self {
+        Self {
+            program: program.to_owned(),
+            args: Vec::new(),
+            env: Vec::new(),
+            env_clear: false,
+            cwd: None,
+        }
+    }
+
+    /// Adds an argument. It is passed as is, without any shell interpretation.
+    pub fn arg(mut self, arg: impl AsRef<OsStr>) -> Self {
+        self.args.push(arg.as_ref().to_owned());
+        self
+    }
+
+    /// Adds several arguments.
+    pub fn args<I, S>(mut self, args: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<OsStr>,
+    {
+        self.args
+            .extend(args.into_iter().map(|arg| arg.as_ref().to_owned()));
+        self
+    }
+
+    /// Sets an environment variable of the child.
+    pub fn env(mut self, key: impl AsRef<OsStr>, value: impl AsRef<OsStr>) -> Self {
+        self.env
+            .push((key.as_ref().to_owned(), Some(value.as_ref().to_owned())));
+        self
+    }
+
+    /// Removes an environment variable from the child's environment.
+    pub fn env_remove(mut self, key: impl AsRef<OsStr>) -> Self {
+        self.env.push((key.as_ref().to_owned(), None));
+        self
+    }
+
+    /// Starts the child with only the variables set by [`env`](Self::env) instead
+    /// of inheriting this process' environment. If none are set, wx still passes
+    /// the current environment.
+    pub fn env_clear(mut self) -> Self {
+        self.env_clear = true;
+        self.env.clear();
+        self
+    }
+
+    /// Sets the child's working directory.
+    pub fn current_dir(mut self, dir: impl AsRef<Path>) -> Self {
+        self.cwd = Some(dir.as_ref().to_owned());
+        self
+    }
+
+    /// Starts the child. Its output is read from the next event loop iteration
+    /// on, so handlers bound right after this call miss nothing.
+    pub fn spawn(self) -> Result<Process, ProcessError> {
+        let argv = std::iter::once(&self.program)
+            .chain(&self.args)
+            .map(|arg| to_cstring(arg))
+            .collect::<Result<Vec<_>, _>>()?;
+        let argv_ptrs: Vec<*const c_char> = argv.iter().map(|arg| arg.as_ptr()).collect();
+
+        let cwd = self
+            .cwd
+            .as_deref()
+            .map(|dir| to_cstring(dir.as_os_str()))
+            .transpose()?;
+
+        let mut keys = Vec::with_capacity(self.env.len());
+        let mut values = Vec::with_capacity(self.env.len());
+        for (key, value) in &self.env {
+            keys.push(to_cstring(key)?);
+            values.push(value.as_deref().map(to_cstring).transpose()?);
+        }
+        let key_ptrs: Vec<*const c_char> = keys.iter().map(|key| key.as_ptr()).collect();
+        let value_ptrs: Vec<*const c_char> = values
+            .iter()
+            .map(|value| value.as_ref().map_or(std::ptr::null(), |v| v.as_ptr()))
+            .collect();
+
+        let ptr = unsafe {
+            ffi::wxd_Process_Start(
+                argv_ptrs.as_ptr(),
+                argv_ptrs.len() as i32,
+                cwd.as_ref().map_or(std::ptr::null(), |dir| dir.as_ptr()),
+                key_ptrs.as_ptr(),
+                value_ptrs.as_ptr(),
+                key_ptrs.len() as i32,
+                self.env_clear,
+            )
+        };
+        if ptr.is_null() {
+            Err(ProcessError::SpawnFailed)
+        } else {
+            Ok(Process { ptr })
+        }
+    }
+}
+
+/// A line written by the child, see [`Process::on_output`].
+#[derive(Debug)]
+pub struct ProcessOutputEvent {
+    event: Event,
+}
+
+impl ProcessOutputEvent {
+    /// The line, without its line terminator. Output that is not UTF-8 is
+    /// decoded with the system encoding.
+    pub fn line(&self) -> String {
+        self.event.get_string().unwrap_or_default()
+    }
+}
+
+/// The child exited, see [`Process::on_terminated`].
+#[derive(Debug)]
+pub struct ProcessEndEvent {
+    event: Event,
+}
+
+impl ProcessEndEvent {
+    /// The exit code. A process killed by a signal reports `-1` on Unix.
+    pub fn exit_code(&self) -> i32 {
+        unsafe { ffi::wxd_ProcessEvent_GetExitCode(self.event.0) }
+    }
+
+    /// The id of the process that exited.
+    pub fn pid(&self) -> i32 {
+        unsafe { ffi::wxd_ProcessEvent_GetPid(self.event.0) }
+    }
+}
+
+/// A running (or finished) child process; see the [module docs](self).
+pub struct Process {
+    ptr: *mut ffi::wxd_Process_t,
+}
+
+impl Process {
+    /// Starts building a process running `program`, looked up in `PATH` if it
+    /// is not a path.
+    pub fn builder(program: impl AsRef<OsStr>) -> ProcessBuilder {
+        ProcessBuilder::new(program.as_ref())
+    }
+
+    /// The child's process id.
+    pub fn pid(&self) -> u32 {
+        unsafe { ffi::wxd_Process_GetPid(self.ptr) as u32 }
+    }
+
+    /// Returns `true` until the termination event has been sent.
+    pub fn is_running(&self) -> bool {
+        unsafe { ffi::wxd_Process_IsRunning(self.ptr) }
+    }
+
+    /// Writes `data` to the child's standard input. This blocks while the pipe
+    /// is full, so a child that stops reading can freeze the UI.
+    ///
+    /// Returns `false` if the child has exited or its input is closed.
+    pub fn write_stdin(&self, data: &[u8]) -> bool {
+        unsafe { ffi::wxd_Process_WriteStdin(self.ptr, data.as_ptr(), data.len()) }
+    }
+
+    /// Closes the child's standard input, e.g. to signal the end of its input.
+    pub fn close_stdin(&self) {
+        unsafe { ffi::wxd_Process_CloseStdin(self.ptr) };
+    }
+
+    /// Sends `signal` to the child.
+    pub fn kill(&self, signal: Signal) -> Result<(), KillError> {
+        self.send_signal(signal, false)
+    }
+
+    /// Sends `signal` to the child and the processes it started.
+    pub fn kill_tree(&self, signal: Signal) -> Result<(), KillError> {
+        self.send_signal(signal, true)
+    }
+
+    fn send_signal(&self, signal: Signal, with_children: bool) -> Result<(), KillError> {
+        match unsafe { ffi::wxd_Process_Kill(self.ptr, signal.to_raw(), with_children) } {
+            ffi::wxd_KillError_WXD_KILL_OK => Ok(()),
+            ffi::wxd_KillError_WXD_KILL_BAD_SIGNAL => Err(KillError::BadSignal),
+            ffi::wxd_KillError_WXD_KILL_ACCESS_DENIED => Err(KillError::AccessDenied),
+            ffi::wxd_KillError_WXD_KILL_NO_PROCESS => Err(KillError::NoProcess),
+            _ => Err(KillError::Other),
+        }
+    }
+
+    /// Calls `callback` with every line the child writes to standard output.
+    pub fn on_output<F>(&self, mut callback: F) -> EventBinding
+    where
+        F: FnMut(ProcessOutputEvent) + 'static,
+    {
+        self.bind_internal(EventType::PROCESS_OUTPUT, move |event| {
+            callback(ProcessOutputEvent { event })
+        })
+    }
+
+    /// Calls `callback` with every line the child writes to standard error.
+    pub fn on_error_output<F>(&self, mut callback: F) -> EventBinding
+    where
+        F: FnMut(ProcessOutputEvent) + 'static,
+    {
+        self.bind_internal(EventType::PROCESS_ERROR_OUTPUT, move |event| {
+            callback(ProcessOutputEvent { event })
+        })
+    }
+
+    /// Calls `callback` once the child has exited, after all of its output.
+    pub fn on_terminated<F>(&self, mut callback: F) -> EventBinding
+    where
+        F: FnMut(ProcessEndEvent) + 'static,
+    {
+        self.bind_internal(EventType::END_PROCESS, move |event| {
+            callback(ProcessEndEvent { event })
+        })
+    }
+}
+
+impl WxEvtHandler for Process {
+    unsafe fn get_event_handler_ptr(&self) -> *mut ffi::wxd_EvtHandler_t {
+        ffi::wxd_Process_GetEvtHandler(self.ptr)
+    }
+}
+
+impl Drop for Process {
+    fn drop(&mut self) {
+        // The C++ object lives on until the child exits, to deliver its events
+        unsafe { ffi::wxd_Process_Release(self.ptr) };
+    }
+}
@@ -0,0 +1,408 @@
+//! Single-instance enforcement with argument forwarding.
+//!
+//! The usual way to use this is [`AppBuilder::single_instance`](crate::app::AppBuilder::single_instance):
+//! when a second copy of the application is launched, it forwards its command line to
+//! the running instance and exits before creating any windows. The running instance
+//! receives the arguments on the main thread:
+//!
+//! ```rust,no_run
+//! use wxdragon::prelude::*;
+//!
+//! let _ = AppBuilder::new()
+//!     .with_app_name("my-editor")
+//!     .single_instance("my-editor", |request: InstanceArgs| {
+//!         for path in request.resolved_paths() {
+//!             println!("Open {}", path.display());
+//!         }
+//!     })
+//!     .run(|_| {
+//!         let frame = Frame::builder().with_title("Editor").build();
+//!         frame.show(true);
+//!     });
+//! ```
+//!
+//! [`SingleInstanceChecker`] and [`InstanceServer`] are also available separately for
+//! applications that manage startup themselves. Both must be created after wxWidgets
+//! has been initialized, on the main thread.
+//!
+//! On Unix the IPC socket lives in `$XDG_RUNTIME_DIR`, or else in a private
+//! `wxdragon-<uid>` directory below the temporary directory, so other users can
+//! neither read nor inject forwarded command lines.
+
+use std::cell::RefCell;
+use std::ffi::{c_char, c_void, CString};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use wxdragon_sys as ffi;
+
+/// IPC topic used for forwarded command lines.
+const TOPIC: &str = "wxdragon-instance-args";
+
+/// How often a second instance tries to reach a running instance that is busy.
+const FORWARD_ATTEMPTS: u32 = 5;
+const FORWARD_RETRY_DELAY: Duration = Duration::from_millis(200);
+
+// --- SingleInstanceChecker ---
+
+/// Detects whether another instance of the application is already running.
+///
+/// The lock is held until the checker is dropped.
+pub struct SingleInstanceChecker {
+    ptr: *mut ffi::wxd_SingleInstanceChecker_t,
+}
+
+impl SingleInstanceChecker {
+    /// Creates a checker for `name`, which should be unique to the application.
+    /// The current user id is appended, so different users do not see each other.
+    ///
+    /// Returns `None` if the lock could not be created.
+    pub fn new(name: &str) -> Option<Self> {
+        Self::create(name, None)
+    }
+
+    /// Like [`new`](Self::new), placing the lock file in `dir` (only used on Unix).
+    pub fn with_path(name: &str, dir: &Path) -> Option<Self> {
+        Self::create(name, Some(dir))
+    }
+
+    fn create(name: &str, dir: Option<&Path>) -> Option<Self> {
+        let c_name = CString::new(name).ok()?;
+        let c_dir = match dir {
+            Some(dir) => Some(CString::new(dir.to_string_lossy().as_ref()).ok()?),
+            None => None,
+        };
+        let ptr = unsafe {
+            ffi::wxd_SingleInstanceChecker_Create(
+                c_name.as_ptr(),
+                c_dir.as_ref().map_or(std::ptr::null(), |d| d.as_ptr()),
+            )
+        };
+        if ptr.is_null() {
+            None
+        } else {
+            Some(Self { ptr })
+        }
+    }
+
+    /// Returns `true` if another instance holds the lock.
+    pub fn is_another_running(&self) -> bool {
+        unsafe { ffi::wxd_SingleInstanceChecker_IsAnotherRunning(self.ptr) }
+    }
+}
+
+impl Drop for SingleInstanceChecker {
+    fn drop(&mut self) {
+        unsafe { ffi::wxd_SingleInstanceChecker_Destroy(self.ptr) };
+    }
+}
+
+// --- InstanceArgs ---
+
+/// The command line forwarded by a second instance.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InstanceArgs {
+    /// Working directory of the second instance, for resolving relative paths.
+    pub cwd: PathBuf,
+    /// Arguments of the second instance, without the program name.
+    pub args: Vec<String>,
+}
+
+impl InstanceArgs {
+    /// Describes the current process, as it would be forwarded.
+    pub fn current() -> Self {
+        Self {
+            cwd: std::env::current_dir().unwrap_or_default(),
+            args: std::env::args_os()
+                .skip(1)
+                .map(|arg| arg.to_string_lossy().into_owned())
+                .collect(),
+        }
+    }
+
+    /// Returns the arguments that do not start with `-`, resolved against [`cwd`](Self::cwd).
+    pub fn resolved_paths(&self) -> Vec<PathBuf> {
+        self.args
+            .iter()
+            .filter(|arg| !arg.starts_with('-'))
+            .map(|arg| self.cwd.join(arg))
+            .collect()
+    }
+
+    // Wire format: NUL-separated UTF-8 fields, the working directory first.
+    fn encode(&self) -> Vec<u8> {
+        let mut data = self.cwd.to_string_lossy().into_owned().into_bytes();
+        for arg in &self.args {
+            data.push(0);
+            data.extend_from_slice(arg.as_bytes());
+        }
+        data
+    }
+
+    fn decode(data: &[u8]) -> Self {
+        let mut fields = data
+            .split(|&b| b == 0)
+            .map(|field| String::from_utf8_lossy(field).into_owned());
+        let cwd = PathBuf::from(fields.next().unwrap_or_default());
+        Self {
+            cwd,
+            args: fields.collect(),
+        }
+    }
+}
+
+// --- InstanceServer ---
+
+type ArgsCallback = RefCell<Box<dyn FnMut(InstanceArgs)>>;
+
+/// Receives command lines forwarded by [`forward_to_running_instance`].
+///
+/// The server stops listening when dropped.
+pub struct InstanceServer {
+    ptr: *mut ffi::wxd_IpcServer_t,
+    callback: *mut ArgsCallback,
+    #[cfg(unix)]
+    socket_path: PathBuf,
+}
+
+impl InstanceServer {
+    /// Starts listening for instances of `name`. `callback` runs on the main thread
+    /// for every forwarded command line.
+    ///
+    /// `checker` must be the lock for the same `name`. Returns `None` if another
+    /// instance holds it, since that instance is the one serving, or if the local IPC
+    /// endpoint could not be created.
+    pub fn new<F>(checker: &SingleInstanceChecker, name: &str, callback: F) -> Option<Self>
+    where
+        F: FnMut(InstanceArgs) + 'static,
+    {
+        if checker.is_another_running() {
+            return None;
+        }
+        let service = service_name(name)?;
+        // We own the instance lock, so any socket left at this path is stale
+        #[cfg(unix)]
+        let _ = std::fs::remove_file(&service);
+
+        let c_service = CString::new(service.to_string_lossy().as_ref()).ok()?;
+        let c_topic = CString::new(TOPIC).ok()?;
+        let callback: Box<ArgsCallback> = Box::new(RefCell::new(Box::new(callback)));
+        let callback = Box::into_raw(callback);
+
+        let ptr = unsafe {
+            ffi::wxd_IpcServer_Create(
+                c_service.as_ptr(),
+                c_topic.as_ptr(),
+                Some(instance_args_trampoline),
+                callback as *mut c_void,
+            )
+        };
+        if ptr.is_null() {
+            drop(unsafe { Box::from_raw(callback) });
+            return None;
+        }
+
+        Some(Self {
+            ptr,
+            callback,
+            #[cfg(unix)]
+            socket_path: service,
+        })
+    }
+}
+
+impl Drop for InstanceServer {
+    fn drop(&mut self) {
+        unsafe {
+            ffi::wxd_IpcServer_Destroy(self.ptr);
+            drop(Box::from_raw(self.callback));
+        }
+        #[cfg(unix)]
+        let _ = std::fs::remove_file(&self.socket_path);
+    }
+}
+
+unsafe extern "C" fn instance_args_trampoline(
+    user_data: *mut c_void,
+    data: *const c_char,
+    len: i32,
+) {
+    if user_data.is_null() {
+        return;
+    }
+    let bytes = if data.is_null() || len <= 0 {
+        &[][..]
+    } else {
+        std::slice::from_raw_parts(data as *const u8, len as usize)
+    };
+    let request = InstanceArgs::decode(bytes);
+    let callback = &*(user_data as *const ArgsCallback);
+
+    // A panic must not unwind into the socket handling code
+    crate::panic_policy::catch("single-instance callback", || {
+        if let Ok(mut callback) = callback.try_borrow_mut() {
+            callback(request);
+        }
+    });
+}
+
+/// Sends `args` to the running instance of `name`.
+///
+/// Returns `false` if no instance answered, e.g. because it is busy or not serving.
+pub fn forward_to_running_instance(name: &str, args: &InstanceArgs) -> bool {
+    let Some(service) = service_name(name) else {
+        return false;
+    };
+    let (Ok(c_service), Ok(c_topic)) = (
+        CString::new(service.to_string_lossy().as_ref()),
+        CString::new(TOPIC),
+    ) else {
+        return false;
+    };
+    let data = args.encode();
+    unsafe {
+        ffi::wxd_IpcClient_Execute(
+            c_service.as_ptr(),
+            c_topic.as_ptr(),
+            data.as_ptr() as *const c_char,
+            data.len() as i32,
+        )
+    }
+}
+
+/// Returns the IPC service for `name`: a socket path in a private per-user directory
+/// on Unix, a DDE service name on Windows. `None` if no safe directory is available.
+fn service_name(name: &str) -> Option<PathBuf> {
+    let sanitized: String = name
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '-' || c == '.' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    #[cfg(unix)]
+    {
+        Some(private_runtime_dir()?.join(format!("{sanitized}.ipc")))
+    }
+    #[cfg(not(unix))]
+    {
+        let user = std::env::var("USERNAME").unwrap_or_default();
+        Some(PathBuf::from(format!("{sanitized}-{user}.ipc")))
+    }
+}
+
+/// Returns a directory only the current user can access: `$XDG_RUNTIME_DIR`, or a
+/// `wxdragon-<uid>` directory in the temporary directory, created with mode 0700.
+#[cfg(unix)]
+fn private_runtime_dir() -> Option<PathBuf> {
+    use std::os::unix::fs::{DirBuilderExt, MetadataExt};
+
+    let uid = unsafe { ffi::wxd_SingleInstance_GetUserUid() };
+    // Refuses symlinks and directories other users own or can enter
+    let is_private = |dir: &Path| {
+        std::fs::symlink_metadata(dir)
+            .is_ok_and(|meta| meta.is_dir() && meta.uid() == uid && meta.mode() & 0o077 == 0)
+    };
+
+    if let Some(dir) = std::env::var_os("XDG_RUNTIME_DIR").map(PathBuf::from) {
+        if dir.is_absolute() && is_private(&dir) {
+            return Some(dir);
+        }
+    }
+    let dir = std::env::temp_dir().join(format!("wxdragon-{uid}"));
+    if let Err(err) = std::fs::DirBuilder::new().mode(0o700).create(&dir) {
+        if err.kind() != std::io::ErrorKind::AlreadyExists {
+            return None;
+        }
+    }
+    is_private(&dir).then_some(dir)
+}
+
+// --- App integration ---
+
+/// A primary instance's lock and server, kept alive until the application exits.
+pub(crate) struct PrimaryInstance {
+    // Dropped in declaration order: stop serving before releasing the lock
+    _server: Option<InstanceServer>,
+    _checker: SingleInstanceChecker,
+}
+
+/// Outcome of the single-instance check performed during app initialization.
+pub(crate) enum InstanceRole {
+    /// This is the first instance and should continue starting up.
+    Primary(Option<PrimaryInstance>),
+    /// The arguments were handed to the running instance; this one should exit.
+    Forwarded,
+    /// Another instance is running but did not accept the arguments yet; this one
+    /// should retry with [`retry_forward`] and exit without serving, as the running
+    /// instance still owns the IPC endpoint.
+    Busy(InstanceArgs),
+}
+
+thread_local! {
+    static PRIMARY: RefCell<Option<PrimaryInstance>> = const { RefCell::new(None) };
+}
+
+/// Keeps the primary instance's lock and server alive until [`release_primary`].
+pub(crate) fn set_primary(instance: Option<PrimaryInstance>) {
+    PRIMARY.with(|primary| *primary.borrow_mut() = instance);
+}
+
+/// Stops serving and releases the lock. Called when the application exits.
+pub(crate) fn release_primary() {
+    let instance = PRIMARY.with(|primary| primary.borrow_mut().take());
+    drop(instance);
+}
+
+/// Discards the primary instance without destroying its wx objects, for when
+/// wxWidgets has already shut down.
+pub(crate) fn forget_primary() {
+    let instance = PRIMARY.with(|primary| primary.borrow_mut().take());
+    std::mem::forget(instance);
+}
+
+/// Runs the single-instance check. Must be called on the main thread after wxWidgets
+/// has been initialized.
+pub(crate) fn claim<F>(name: &str, callback: F) -> InstanceRole
+where
+    F: FnMut(InstanceArgs) + 'static,
+{
+    let Some(checker) = SingleInstanceChecker::new(name) else {
+        return InstanceRole::Primary(None);
+    };
+    if checker.is_another_running() {
+        // The lock holder is alive (wx removes stale locks), but may be busy for a moment
+        let args = InstanceArgs::current();
+        if forward_to_running_instance(name, &args) {
+            return InstanceRole::Forwarded;
+        }
+        return InstanceRole::Busy(args);
+    }
+    InstanceRole::Primary(Some(PrimaryInstance {
+        _server: InstanceServer::new(&checker, name, callback),
+        _checker: checker,
+    }))
+}
+
+/// Retries forwarding `args` from the event loop, so a busy running instance does not
+/// freeze this one, then calls `done` with whether they were accepted.
+pub(crate) fn retry_forward<F>(name: String, args: InstanceArgs, done: F)
+where
+    F: FnOnce(bool) + 'static,
+{
+    schedule_forward(name, args, FORWARD_ATTEMPTS - 1, Box::new(done));
+}
+
+fn schedule_forward(name: String, args: InstanceArgs, remaining: u32, done: Box<dyn FnOnce(bool)>) {
+    crate::timer::call_later(FORWARD_RETRY_DELAY, move || {
+        if forward_to_running_instance(&name, &args) {
+            done(true);
+        } else if remaining > 1 {
+            schedule_forward(name, args, remaining - 1, done);
+        } else {
+            done(false);
+        }
+    });
+}
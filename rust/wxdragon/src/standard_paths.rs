@@ -0,0 +1,136 @@
+//! Standard locations for configuration, data and documents (`wxStandardPaths`).
+//!
+//! The application and vendor names set on the app decide the per-application
+//! directories, e.g. `~/.config/MyApp` or `%APPDATA%\MyApp`. Nothing is created on
+//! disk; create a directory before writing into it.
+
+use std::ffi::{CStr, CString};
+use wxdragon_sys as ffi;
+
+/// Access to the platform's standard directories.
+pub struct StandardPaths;
+
+fn standard_path(which: ffi::wxd_StandardPath) -> String {
+    take_string(unsafe { ffi::wxd_StandardPaths_Get(which) })
+}
+
+fn take_string(ptr: *mut std::os::raw::c_char) -> String {
+    if ptr.is_null() {
+        return String::new();
+    }
+    let value = unsafe { CStr::from_ptr(ptr) }
+        .to_string_lossy()
+        .into_owned();
+    unsafe { ffi::wxd_free_string(ptr) };
+    value
+}
+
+impl StandardPaths {
+    /// The directory for the user's configuration files, e.g. `~/.config` or
+    /// `%APPDATA%`. Shared by all applications.
+    pub fn user_config_dir() -> String {
+        standard_path(ffi::wxd_StandardPath_WXD_STDPATH_USER_CONFIG_DIR)
+    }
+
+    /// The directory for this application's per-user data files.
+    pub fn user_data_dir() -> String {
+        standard_path(ffi::wxd_StandardPath_WXD_STDPATH_USER_DATA_DIR)
+    }
+
+    /// Like [`user_data_dir`](Self::user_data_dir), for data that should not roam
+    /// between machines (Windows); the same elsewhere.
+    pub fn user_local_data_dir() -> String {
+        standard_path(ffi::wxd_StandardPath_WXD_STDPATH_USER_LOCAL_DATA_DIR)
+    }
+
+    /// The system-wide configuration directory, e.g. `/etc`.
+    pub fn config_dir() -> String {
+        standard_path(ffi::wxd_StandardPath_WXD_STDPATH_CONFIG_DIR)
+    }
+
+    /// The directory with the application's read-only data files.
+    pub fn data_dir() -> String {
+        standard_path(ffi::wxd_StandardPath_WXD_STDPATH_DATA_DIR)
+    }
+
+    /// The directory with the application's host-specific data files.
+    pub fn local_data_dir() -> String {
+        standard_path(ffi::wxd_StandardPath_WXD_STDPATH_LOCAL_DATA_DIR)
+    }
+
+    /// The directory with the application's resources (the bundle's `Resources`
+    /// on macOS, the data directory elsewhere).
+    pub fn resources_dir() -> String {
+        standard_path(ffi::wxd_StandardPath_WXD_STDPATH_RESOURCES_DIR)
+    }
+
+    /// The directory with the application's plugins.
+    pub fn plugins_dir() -> String {
+        standard_path(ffi::wxd_StandardPath_WXD_STDPATH_PLUGINS_DIR)
+    }
+
+    /// The directory for documents created by this application.
+    pub fn app_documents_dir() -> String {
+        standard_path(ffi::wxd_StandardPath_WXD_STDPATH_APP_DOCUMENTS_DIR)
+    }
+
+    /// The user's documents directory.
+    pub fn documents_dir() -> String {
+        standard_path(ffi::wxd_StandardPath_WXD_STDPATH_DOCUMENTS_DIR)
+    }
+
+    /// The user's desktop directory.
+    pub fn desktop_dir() -> String {
+        standard_path(ffi::wxd_StandardPath_WXD_STDPATH_DESKTOP_DIR)
+    }
+
+    /// The user's downloads directory.
+    pub fn downloads_dir() -> String {
+        standard_path(ffi::wxd_StandardPath_WXD_STDPATH_DOWNLOADS_DIR)
+    }
+
+    /// The user's music directory.
+    pub fn music_dir() -> String {
+        standard_path(ffi::wxd_StandardPath_WXD_STDPATH_MUSIC_DIR)
+    }
+
+    /// The user's pictures directory.
+    pub fn pictures_dir() -> String {
+        standard_path(ffi::wxd_StandardPath_WXD_STDPATH_PICTURES_DIR)
+    }
+
+    /// The user's videos directory.
+    pub fn videos_dir() -> String {
+        standard_path(ffi::wxd_StandardPath_WXD_STDPATH_VIDEOS_DIR)
+    }
+
+    /// The user's cache directory, e.g. `~/.cache`.
+    pub fn cache_dir() -> String {
+        standard_path(ffi::wxd_StandardPath_WXD_STDPATH_CACHE_DIR)
+    }
+
+    /// The directory for temporary files.
+    pub fn temp_dir() -> String {
+        standard_path(ffi::wxd_StandardPath_WXD_STDPATH_TEMP_DIR)
+    }
+
+    /// The full path of the running executable.
+    pub fn executable_path() -> String {
+        standard_path(ffi::wxd_StandardPath_WXD_STDPATH_EXECUTABLE_PATH)
+    }
+
+    /// The full path of the per-user configuration file named after `basename`, as
+    /// used by file-based configs, e.g. `~/.myapp` or `%APPDATA%\myapp.ini`. Pass it
+    /// to [`Config::from_file`](crate::config::Config::from_file).
+    pub fn user_config_file(basename: &str) -> String {
+        let basename_c = CString::new(basename).unwrap_or_default();
+        take_string(unsafe { ffi::wxd_StandardPaths_GetLocalConfigFile(basename_c.as_ptr()) })
+    }
+
+    /// Uses the XDG base directories (`~/.config/app`, `~/.local/share/app`) instead
+    /// of dot files in the home directory on Linux. No effect on other platforms.
+    /// Call before any path is queried or a config is created.
+    pub fn use_xdg_layout(xdg: bool) {
+        unsafe { ffi::wxd_StandardPaths_UseXdgLayout(xdg) };
+    }
+}
@@ -0,0 +1,362 @@
+//! Async task support integrated with the wxWidgets main loop.
+//!
+//! [`spawn_local`] drives futures (which need not be `Send`) on the GUI thread.
+//! Tasks are polled from the [`call_after`](crate::call_after) queue whenever their
+//! waker fires, so an idle application does not busy-poll.
+//!
+//! [`spawn`] hands `Send` futures to a small background thread pool. The returned
+//! [`JoinHandle`] can be awaited from a local task, which makes it easy to write
+//! linear code that hops between the UI thread and background work:
+//!
+//! ```rust,no_run
+//! use wxdragon::prelude::*;
+//!
+//! # fn demo(label: StaticText, path: std::path::PathBuf) {
+//! wxdragon::spawn_local(async move {
+//!     label.set_label("Loading...");
+//!     let data = wxdragon::spawn(async move { std::fs::read_to_string(path) }).await;
+//!     match data {
+//!         Ok(text) => label.set_label(&format!("{} bytes", text.len())),
+//!         Err(e) => label.set_label(&format!("Error: {e}")),
+//!     }
+//! });
+//! # }
+//! ```
+//!
+//! The background pool has no I/O reactor. Futures that depend on a specific
+//! runtime (e.g. tokio) should be spawned on that runtime instead; its join
+//! handle can still be awaited from [`spawn_local`] tasks.
+
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::future::Future;
+use std::panic::{catch_unwind, resume_unwind, AssertUnwindSafe};
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{channel, Receiver, Sender};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::task::{Context, Poll, Wake, Waker};
+use std::thread::{self, ThreadId};
+
+type PanicPayload = Box<dyn std::any::Any + Send + 'static>;
+
+// --- JoinHandle ---
+
+struct JoinState<T> {
+    result: Option<thread::Result<T>>,
+    finished: bool,
+    waker: Option<Waker>,
+    // The JoinHandle was dropped; a panic has nobody to resume it
+    detached: bool,
+}
+
+/// A handle to a spawned task that resolves to the task's output when awaited.
+///
+/// Dropping the handle detaches the task; it keeps running to completion.
+/// If the task panicked, awaiting the handle resumes the panic in the awaiting task.
+/// A panic in a detached task is handled by the [panic policy](crate::panic_policy).
+pub struct JoinHandle<T> {
+    state: Arc<Mutex<JoinState<T>>>,
+}
+
+impl<T> JoinHandle<T> {
+    fn new() -> (Self, Arc<Mutex<JoinState<T>>>) {
+        let state = Arc::new(Mutex::new(JoinState {
+            result: None,
+            finished: false,
+            waker: None,
+            detached: false,
+        }));
+        (
+            Self {
+                state: state.clone(),
+            },
+            state,
+        )
+    }
+
+    /// Returns `true` if the task has completed (or panicked).
+    pub fn is_finished(&self) -> bool {
+        self.state.lock().unwrap().finished
+    }
+}
+
+impl<T> Drop for JoinHandle<T> {
+    fn drop(&mut self) {
+        let panic = {
+            let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+            state.detached = true;
+            match state.result.take() {
+                Some(Err(panic)) => Some(panic),
+                _ => None,
+            }
+        };
+        // The task panicked but the handle was never awaited
+        if let Some(panic) = panic {
+            crate::panic_policy::report("detached task", panic.as_ref());
+        }
+    }
+}
+
+impl<T> std::fmt::Debug for JoinHandle<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("JoinHandle")
+            .field("finished", &self.is_finished())
+            .finish()
+    }
+}
+
+impl<T> Future for JoinHandle<T> {
+    type Output = T;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<T> {
+        let mut state = self.state.lock().unwrap();
+        match state.result.take() {
+            Some(Ok(value)) => Poll::Ready(value),
+            Some(Err(panic)) => {
+                drop(state);
+                resume_unwind(panic)
+            }
+            None => {
+                assert!(!state.finished, "JoinHandle polled after completion");
+                state.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+fn complete<T>(state: &Mutex<JoinState<T>>, result: thread::Result<T>) {
+    let waker = {
+        let mut state = state.lock().unwrap();
+        if let (true, Err(panic)) = (state.detached, &result) {
+            state.finished = true;
+            drop(state);
+            crate::panic_policy::report("detached task", panic.as_ref());
+            return;
+        }
+        state.result = Some(result);
+        state.finished = true;
+        state.waker.take()
+    };
+    if let Some(waker) = waker {
+        waker.wake();
+    }
+}
+
+// --- Local (GUI thread) executor ---
+
+type LocalFuture = Pin<Box<dyn Future<Output = ()> + 'static>>;
+
+static GUI_THREAD: OnceLock<ThreadId> = OnceLock::new();
+
+thread_local! {
+    static LOCAL_TASKS: RefCell<HashMap<u64, LocalFuture>> = RefCell::new(HashMap::new());
+    static NEXT_LOCAL_TASK_ID: Cell<u64> = const { Cell::new(1) };
+}
+
+/// Records the current thread as the GUI thread. Called when the app starts.
+///
+/// # Panics
+/// Panics if [`spawn_local`] was called on another thread before the app started.
+pub(crate) fn set_gui_thread() {
+    let gui_thread = *GUI_THREAD.get_or_init(|| thread::current().id());
+    assert_eq!(
+        gui_thread,
+        thread::current().id(),
+        "spawn_local was called on a thread other than the one running the app"
+    );
+}
+
+struct LocalWaker {
+    task_id: u64,
+    scheduled: AtomicBool,
+}
+
+impl Wake for LocalWaker {
+    fn wake(self: Arc<Self>) {
+        self.wake_by_ref();
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        // Coalesce repeated wakes until the task has been polled again
+        if !self.scheduled.swap(true, Ordering::AcqRel) {
+            let waker = self.clone();
+            crate::app::call_after(Box::new(move || poll_local_task(waker)));
+        }
+    }
+}
+
+fn poll_local_task(waker: Arc<LocalWaker>) {
+    waker.scheduled.store(false, Ordering::Release);
+
+    // Take the future out of the map so it can spawn or wake tasks while being polled
+    let Some(mut future) = LOCAL_TASKS.with(|tasks| tasks.borrow_mut().remove(&waker.task_id))
+    else {
+        return;
+    };
+
+    let std_waker = Waker::from(waker.clone());
+    let mut cx = Context::from_waker(&std_waker);
+    match catch_unwind(AssertUnwindSafe(|| future.as_mut().poll(&mut cx))) {
+        Ok(Poll::Pending) => {
+            LOCAL_TASKS.with(|tasks| {
+                tasks.borrow_mut().insert(waker.task_id, future);
+            });
+        }
+        // Panics from the spawned future itself are captured for its JoinHandle;
+        // this only keeps an executor failure from unwinding into C++.
+        Ok(Poll::Ready(())) | Err(_) => {}
+    }
+}
+
+/// Spawns a future on the GUI thread.
+///
+/// The future does not need to be `Send`, so it may freely capture widgets.
+/// It is first polled on the next event loop iteration, and afterwards whenever
+/// its waker is woken (from any thread).
+///
+/// Futures spawned before the app starts are polled once its event loop runs.
+/// The thread spawning them then becomes the GUI thread, so the app must be run
+/// on that same thread.
+///
+/// # Panics
+/// Panics if called from a thread other than the GUI thread.
+pub fn spawn_local<F>(future: F) -> JoinHandle<F::Output>
+where
+    F: Future + 'static,
+    F::Output: 'static,
+{
+    let gui_thread = *GUI_THREAD.get_or_init(|| thread::current().id());
+    assert_eq!(
+        gui_thread,
+        thread::current().id(),
+        "spawn_local must be called from the GUI thread"
+    );
+
+    let (handle, state) = JoinHandle::new();
+    let task: LocalFuture = Box::pin(async move {
+        let mut future = std::pin::pin!(future);
+        let result = std::future::poll_fn(|cx| {
+            match catch_unwind(AssertUnwindSafe(|| future.as_mut().poll(cx))) {
+                Ok(Poll::Ready(value)) => Poll::Ready(Ok(value)),
+                Ok(Poll::Pending) => Poll::Pending,
+                Err(panic) => Poll::Ready(Err::<F::Output, PanicPayload>(panic)),
+            }
+        })
+        .await;
+        complete(&state, result);
+    });
+
+    let task_id = NEXT_LOCAL_TASK_ID.with(|next| {
+        let id = next.get();
+        next.set(id + 1);
+        id
+    });
+    LOCAL_TASKS.with(|tasks| {
+        tasks.borrow_mut().insert(task_id, task);
+    });
+
+    let waker = Arc::new(LocalWaker {
+        task_id,
+        scheduled: AtomicBool::new(false),
+    });
+    waker.wake_by_ref();
+
+    handle
+}
+
+// --- Background executor ---
+
+type BoxedSendFuture = Pin<Box<dyn Future<Output = ()> + Send + 'static>>;
+
+struct BackgroundTask {
+    future: Mutex<Option<BoxedSendFuture>>,
+    queue: Sender<Arc<BackgroundTask>>,
+}
+
+impl Wake for BackgroundTask {
+    fn wake(self: Arc<Self>) {
+        let queue = self.queue.clone();
+        let _ = queue.send(self);
+    }
+}
+
+impl BackgroundTask {
+    fn run(self: Arc<Self>) {
+        let mut slot = self.future.lock().unwrap();
+        let Some(mut future) = slot.take() else {
+            return;
+        };
+        let waker = Waker::from(self.clone());
+        let mut cx = Context::from_waker(&waker);
+        if future.as_mut().poll(&mut cx).is_pending() {
+            *slot = Some(future);
+        }
+    }
+}
+
+/// Returns the sending side of the background pool's run queue, starting the pool on first use.
+fn background_queue() -> &'static Mutex<Sender<Arc<BackgroundTask>>> {
+    static QUEUE: OnceLock<Mutex<Sender<Arc<BackgroundTask>>>> = OnceLock::new();
+    QUEUE.get_or_init(|| {
+        let (sender, receiver) = channel::<Arc<BackgroundTask>>();
+        let receiver = Arc::new(Mutex::new(receiver));
+        let workers = thread::available_parallelism()
+            .map(|n| n.get().clamp(2, 4))
+            .unwrap_or(2);
+        for index in 0..workers {
+            let receiver = receiver.clone();
+            thread::Builder::new()
+                .name(format!("wxdragon-worker-{index}"))
+                .spawn(move || worker_loop(receiver))
+                .expect("Failed to start wxdragon background worker");
+        }
+        Mutex::new(sender)
+    })
+}
+
+fn worker_loop(receiver: Arc<Mutex<Receiver<Arc<BackgroundTask>>>>) {
+    loop {
+        let task = match receiver.lock().unwrap().recv() {
+            Ok(task) => task,
+            Err(_) => return,
+        };
+        task.run();
+    }
+}
+
+/// Spawns a `Send` future on the background thread pool.
+///
+/// Use this for blocking or CPU-heavy work that must not stall the GUI, and
+/// await the returned [`JoinHandle`] from a [`spawn_local`] task to get the result
+/// back on the GUI thread. Blocking calls inside the future occupy a pool thread,
+/// so keep long-running blocking work in its own `std::thread` if it never yields.
+pub fn spawn<F>(future: F) -> JoinHandle<F::Output>
+where
+    F: Future + Send + 'static,
+    F::Output: Send + 'static,
+{
+    let (handle, state) = JoinHandle::new();
+    let future: BoxedSendFuture = Box::pin(async move {
+        let mut future = std::pin::pin!(future);
+        let result = std::future::poll_fn(|cx| {
+            match catch_unwind(AssertUnwindSafe(|| future.as_mut().poll(cx))) {
+                Ok(Poll::Ready(value)) => Poll::Ready(Ok(value)),
+                Ok(Poll::Pending) => Poll::Pending,
+                Err(panic) => Poll::Ready(Err::<F::Output, PanicPayload>(panic)),
+            }
+        })
+        .await;
+        complete(&state, result);
+    });
+
+    let queue = background_queue().lock().unwrap().clone();
+    let task = Arc::new(BackgroundTask {
+        future: Mutex::new(Some(future)),
+        queue: queue.clone(),
+    });
+    let _ = queue.send(task);
+
+    handle
+}
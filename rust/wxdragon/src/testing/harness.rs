@@ -0,0 +1,238 @@
+//! Running `#[test]` functions against a shared wxWidgets application.
+//!
+//! wxWidgets can only be initialized once per process, so the harness starts a
+//! single application on a dedicated GUI thread the first time [`run`] is called and
+//! sends every test there. Tests are serialized; after each one the harness pumps
+//! pending events and destroys all top-level windows, so the next test starts clean.
+//!
+//! ```rust,no_run
+//! use wxdragon::prelude::*;
+//!
+//! wxdragon::gui_test! {
+//!     fn frame_has_title() {
+//!         let frame = Frame::builder().with_title("Hello").build();
+//!         frame.show(true);
+//!         wxdragon::testing::pump_events();
+//!         assert_eq!(frame.get_title(), "Hello");
+//!     }
+//! }
+//! ```
+//!
+//! On macOS the GUI must run on the process's main thread, which the standard test
+//! harness keeps for itself. There, put GUI tests in a test target with
+//! `harness = false` and run them with [`main`].
+
+use crate::app::{call_after, AppBuilder};
+use crate::event_loop::EventLoop;
+use std::any::Any;
+use std::panic::{catch_unwind, resume_unwind, AssertUnwindSafe};
+use std::sync::mpsc;
+use std::sync::{Mutex, OnceLock};
+use std::time::{Duration, Instant};
+use wxdragon_sys as ffi;
+
+/// Upper bound on the rounds of event processing done by [`pump_events`].
+const MAX_PUMP_ROUNDS: usize = 100;
+
+// Serializes tests: cargo runs them on several threads at once
+static TEST_LOCK: Mutex<()> = Mutex::new(());
+static GUI_THREAD: OnceLock<Result<(), String>> = OnceLock::new();
+
+/// Starts the shared application on its own thread, once.
+fn ensure_started() {
+    let started = GUI_THREAD.get_or_init(|| {
+        if cfg!(target_os = "macos") {
+            return Err("the GUI must run on the main thread on macOS; \
+                        use wxdragon::testing::main with `harness = false`"
+                .to_string());
+        }
+        let (ready_tx, ready_rx) = mpsc::channel::<()>();
+        let spawned = std::thread::Builder::new()
+            .name("wxdragon-gui-tests".to_string())
+            .spawn(move || {
+                let result = AppBuilder::new()
+                    .with_args(["wxdragon-tests"])
+                    .run(move |_| {
+                        // Tests create and destroy windows freely; keep the loop alive
+                        unsafe { ffi::wxd_App_SetExitOnFrameDelete(false) };
+                        let _ = ready_tx.send(());
+                    });
+                if let Err(err) = result {
+                    eprintln!("wxDragon test application failed: {err}");
+                }
+            });
+        if let Err(err) = spawned {
+            return Err(format!("failed to start the GUI thread: {err}"));
+        }
+        ready_rx
+            .recv()
+            .map_err(|_| "the test application failed to initialize".to_string())
+    });
+    if let Err(message) = started {
+        panic!("{message}");
+    }
+}
+
+/// Runs `test` on the GUI thread of the shared test application and returns its
+/// result, then pumps events and destroys all top-level windows.
+///
+/// A panic in `test` (e.g. a failed assertion) is re-raised on the calling thread,
+/// so the calling `#[test]` fails with the original message.
+pub fn run<F, R>(test: F) -> R
+where
+    F: FnOnce() -> R + Send + 'static,
+    R: Send + 'static,
+{
+    ensure_started();
+    let _guard = TEST_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+
+    let (result_tx, result_rx) = mpsc::channel::<Result<R, Box<dyn Any + Send>>>();
+    call_after(Box::new(move || {
+        let result = run_test(test);
+        let _ = result_tx.send(result);
+    }));
+
+    match result_rx.recv() {
+        Ok(Ok(value)) => value,
+        Ok(Err(panic)) => resume_unwind(panic),
+        Err(_) => panic!("the GUI test thread has stopped"),
+    }
+}
+
+/// Runs one test on the GUI thread, catching its panic, and cleans up after it.
+fn run_test<F, R>(test: F) -> Result<R, Box<dyn Any + Send>>
+where
+    F: FnOnce() -> R,
+{
+    let result = catch_unwind(AssertUnwindSafe(test));
+    // Cleanup runs even after a failure so one test cannot break the next
+    let cleanup = catch_unwind(|| {
+        pump_events();
+        destroy_top_level_windows();
+        pump_events();
+    });
+    match (result, cleanup) {
+        (Err(panic), _) | (Ok(_), Err(panic)) => Err(panic),
+        (Ok(value), Ok(())) => Ok(value),
+    }
+}
+
+/// Processes pending events until there are none left.
+///
+/// Call this from a test after an action whose effects arrive as events, e.g.
+/// showing a window or simulating input.
+pub fn pump_events() {
+    for _ in 0..MAX_PUMP_ROUNDS {
+        EventLoop::yield_now();
+        let queued = crate::app::process_main_thread_queue();
+        if !queued && !EventLoop::pending() {
+            break;
+        }
+    }
+}
+
+/// Pumps events until `condition` holds or `timeout` has passed. Returns whether
+/// the condition was met.
+///
+/// Useful for effects that take a while to arrive, such as native input under a
+/// virtual display.
+pub fn wait_until<F>(timeout: Duration, mut condition: F) -> bool
+where
+    F: FnMut() -> bool,
+{
+    let deadline = Instant::now() + timeout;
+    loop {
+        pump_events();
+        if condition() {
+            return true;
+        }
+        if Instant::now() >= deadline {
+            return false;
+        }
+        EventLoop::dispatch(Some(Duration::from_millis(10)));
+    }
+}
+
+/// Destroys every top-level window. Returns how many were destroyed.
+pub fn destroy_top_level_windows() -> usize {
+    unsafe { ffi::wxd_App_DestroyTopLevelWindows() }.max(0) as usize
+}
+
+/// Declares `#[test]` functions whose bodies run on the GUI thread via [`run`].
+#[macro_export]
+macro_rules! gui_test {
+    ($($(#[$meta:meta])* fn $name:ident() $body:block)*) => {
+        $(
+            $(#[$meta])*
+            #[test]
+            fn $name() {
+                $crate::testing::run(|| $body)
+            }
+        )*
+    };
+}
+
+/// A named test for [`main`].
+pub type TestCase = (&'static str, fn());
+
+/// Runs `tests` on the main thread and exits the process with a non-zero code if
+/// any failed. For test targets declared with `harness = false`, as required on
+/// macOS:
+///
+/// ```rust,no_run
+/// fn frame_shows() {
+///     // ...
+/// }
+///
+/// fn main() {
+///     wxdragon::testing::main(&[("frame_shows", frame_shows)]);
+/// }
+/// ```
+///
+/// Each test is isolated like with [`run`]. A command-line argument that is not a
+/// flag filters the tests by name, as with the standard harness.
+pub fn main(tests: &'static [TestCase]) -> ! {
+    let filter = std::env::args().skip(1).find(|arg| !arg.starts_with('-'));
+    let selected: Vec<TestCase> = tests
+        .iter()
+        .copied()
+        .filter(|(name, _)| match &filter {
+            Some(filter) => name.contains(filter.as_str()),
+            None => true,
+        })
+        .collect();
+
+    let result = AppBuilder::new()
+        .with_args(["wxdragon-tests"])
+        .run(move |_| {
+            unsafe { ffi::wxd_App_SetExitOnFrameDelete(false) };
+            call_after(Box::new(move || {
+                println!("\nrunning {} tests", selected.len());
+                let mut failed = Vec::new();
+                for (name, test) in &selected {
+                    match run_test(test) {
+                        Ok(()) => println!("test {name} ... ok"),
+                        Err(_) => {
+                            println!("test {name} ... FAILED");
+                            failed.push(*name);
+                        }
+                    }
+                }
+                let status = if failed.is_empty() { "ok" } else { "FAILED" };
+                println!(
+                    "\ntest result: {status}. {} passed; {} failed\n",
+                    selected.len() - failed.len(),
+                    failed.len()
+                );
+                EventLoop::exit(if failed.is_empty() { 0 } else { 101 });
+            }));
+        });
+
+    match result {
+        Ok(code) => std::process::exit(code),
+        Err(err) => {
+            eprintln!("wxDragon test application failed: {err}");
+            std::process::exit(101)
+        }
+    }
+}
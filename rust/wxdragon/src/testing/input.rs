@@ -0,0 +1,202 @@
+//! Synthetic mouse and keyboard input via `wxUIActionSimulator`.
+//!
+//! The simulator injects events at the operating-system level, as if the user had
+//! produced them, so the target window must be shown and on top. Events are
+//! delivered by the event loop: yield after an action (e.g. with
+//! [`EventLoop::yield_now`](crate::event_loop::EventLoop::yield_now)) before
+//! checking its effect.
+//!
+//! ```rust,no_run
+//! use std::cell::Cell;
+//! use std::rc::Rc;
+//! use wxdragon::event_loop::EventLoop;
+//! use wxdragon::prelude::*;
+//! use wxdragon::testing::InputSimulator;
+//!
+//! # fn check(button: Button) {
+//! let clicked = Rc::new(Cell::new(false));
+//! let flag = clicked.clone();
+//! button.on_click(move |_| flag.set(true));
+//!
+//! let sim = InputSimulator::new().expect("input simulation not available");
+//! sim.click_on(&button, MouseButton::Left);
+//! EventLoop::yield_now();
+//! assert!(clicked.get());
+//! # }
+//! ```
+
+use crate::event::{Modifiers, MouseButton};
+use crate::geometry::Point;
+use crate::window::WxWidget;
+use std::ffi::CString;
+use wxdragon_sys as ffi;
+
+/// Generates native mouse and keyboard input.
+///
+/// Positions given together with a widget are in that widget's client coordinates.
+/// Every method returns `false` if the action could not be performed.
+pub struct InputSimulator {
+    ptr: *mut ffi::wxd_UIActionSimulator_t,
+}
+
+impl InputSimulator {
+    /// Creates a simulator. Must be called on the GUI thread after wxWidgets has been
+    /// initialized.
+    ///
+    /// Returns `None` if wxWidgets was built without input simulation support.
+    pub fn new() -> Option<Self> {
+        let ptr = unsafe { ffi::wxd_UIActionSimulator_Create() };
+        if ptr.is_null() {
+            None
+        } else {
+            Some(Self { ptr })
+        }
+    }
+
+    // --- Mouse ---
+
+    /// Moves the mouse pointer to a screen position.
+    pub fn mouse_move_screen(&self, pos: Point) -> bool {
+        unsafe { ffi::wxd_UIActionSimulator_MouseMove(self.ptr, pos.x, pos.y) }
+    }
+
+    /// Moves the mouse pointer to `pos` within `widget`.
+    pub fn mouse_move<W: WxWidget + ?Sized>(&self, widget: &W, pos: Point) -> bool {
+        self.mouse_move_screen(widget.client_to_screen(pos))
+    }
+
+    /// Moves the mouse pointer to the centre of `widget`.
+    pub fn mouse_move_to_center<W: WxWidget + ?Sized>(&self, widget: &W) -> bool {
+        self.mouse_move(widget, center_of(widget))
+    }
+
+    /// Presses `button` at the current pointer position.
+    pub fn mouse_down(&self, button: MouseButton) -> bool {
+        unsafe { ffi::wxd_UIActionSimulator_MouseDown(self.ptr, button.to_raw()) }
+    }
+
+    /// Releases `button` at the current pointer position.
+    pub fn mouse_up(&self, button: MouseButton) -> bool {
+        unsafe { ffi::wxd_UIActionSimulator_MouseUp(self.ptr, button.to_raw()) }
+    }
+
+    /// Clicks `button` at the current pointer position.
+    pub fn click(&self, button: MouseButton) -> bool {
+        unsafe { ffi::wxd_UIActionSimulator_MouseClick(self.ptr, button.to_raw()) }
+    }
+
+    /// Double-clicks `button` at the current pointer position.
+    pub fn double_click(&self, button: MouseButton) -> bool {
+        unsafe { ffi::wxd_UIActionSimulator_MouseDblClick(self.ptr, button.to_raw()) }
+    }
+
+    /// Moves the pointer to the centre of `widget` and clicks `button`.
+    pub fn click_on<W: WxWidget + ?Sized>(&self, widget: &W, button: MouseButton) -> bool {
+        self.mouse_move_to_center(widget) && self.click(button)
+    }
+
+    /// Presses `button` at `from` and releases it at `to`, both within `widget`.
+    pub fn drag<W: WxWidget + ?Sized>(
+        &self,
+        widget: &W,
+        from: Point,
+        to: Point,
+        button: MouseButton,
+    ) -> bool {
+        let from = widget.client_to_screen(from);
+        let to = widget.client_to_screen(to);
+        unsafe {
+            ffi::wxd_UIActionSimulator_MouseDragDrop(
+                self.ptr,
+                from.x,
+                from.y,
+                to.x,
+                to.y,
+                button.to_raw(),
+            )
+        }
+    }
+
+    /// Sends a vertical mouse wheel event to `widget` at `pos`. `notches` is positive
+    /// for scrolling up.
+    ///
+    /// Unlike the other actions this is not native input: the event goes straight to
+    /// the widget's handlers, so native controls do not scroll in response.
+    pub fn mouse_wheel<W: WxWidget + ?Sized>(&self, widget: &W, pos: Point, notches: i32) -> bool {
+        wheel(widget, pos, notches, false)
+    }
+
+    /// Like [`mouse_wheel`](Self::mouse_wheel), for horizontal scrolling. `notches` is
+    /// positive for scrolling right.
+    pub fn mouse_wheel_horizontal<W: WxWidget + ?Sized>(
+        &self,
+        widget: &W,
+        pos: Point,
+        notches: i32,
+    ) -> bool {
+        wheel(widget, pos, notches, true)
+    }
+
+    // --- Keyboard ---
+
+    /// Presses a key, given as a [`KeyCode`](crate::event::KeyCode) or a raw
+    /// `wxKeyCode` value as returned by
+    /// [`Event::get_key_code`](crate::event::Event::get_key_code).
+    pub fn key_down(&self, key: impl Into<i32>, modifiers: Modifiers) -> bool {
+        unsafe { ffi::wxd_UIActionSimulator_KeyDown(self.ptr, key.into(), modifiers.bits()) }
+    }
+
+    /// Releases a key.
+    pub fn key_up(&self, key: impl Into<i32>, modifiers: Modifiers) -> bool {
+        unsafe { ffi::wxd_UIActionSimulator_KeyUp(self.ptr, key.into(), modifiers.bits()) }
+    }
+
+    /// Presses and releases a key, with `modifiers` held down.
+    pub fn key_press(&self, key: impl Into<i32>, modifiers: Modifiers) -> bool {
+        unsafe { ffi::wxd_UIActionSimulator_Char(self.ptr, key.into(), modifiers.bits()) }
+    }
+
+    /// Types `text` into the focused window.
+    pub fn text(&self, text: &str) -> bool {
+        let Ok(c_text) = CString::new(text) else {
+            return false;
+        };
+        unsafe { ffi::wxd_UIActionSimulator_Text(self.ptr, c_text.as_ptr()) }
+    }
+
+    /// Selects the entry labelled `item` in a choice, combo box, list box or similar
+    /// control, the way a user would with the mouse or keyboard.
+    pub fn select<W: WxWidget + ?Sized>(&self, control: &W, item: &str) -> bool {
+        let Ok(c_item) = CString::new(item) else {
+            return false;
+        };
+        unsafe {
+            ffi::wxd_UIActionSimulator_Select(self.ptr, control.handle_ptr(), c_item.as_ptr())
+        }
+    }
+}
+
+impl Drop for InputSimulator {
+    fn drop(&mut self) {
+        unsafe { ffi::wxd_UIActionSimulator_Destroy(self.ptr) };
+    }
+}
+
+fn center_of<W: WxWidget + ?Sized>(widget: &W) -> Point {
+    let size = widget.get_client_size();
+    Point::new(size.width / 2, size.height / 2)
+}
+
+fn wheel<W: WxWidget + ?Sized>(widget: &W, pos: Point, notches: i32, horizontal: bool) -> bool {
+    // One notch is reported as a rotation of 120
+    let rotation = notches.saturating_mul(120);
+    unsafe {
+        ffi::wxd_UIActionSimulator_MouseWheel(
+            widget.handle_ptr(),
+            pos.x,
+            pos.y,
+            rotation,
+            horizontal,
+        )
+    }
+}
@@ -0,0 +1,13 @@
+//! Support for automated GUI tests.
+//!
+//! The [`harness`] runs ordinary `#[test]` functions (conveniently declared with
+//! [`gui_test!`](crate::gui_test)) on the GUI thread of a shared application.
+//! [`InputSimulator`] generates native mouse and keyboard input, so tests can drive
+//! real widgets and assert on the events their handlers receive.
+
+pub mod harness;
+pub mod input;
+
+pub use crate::event::MouseButton;
+pub use harness::{destroy_top_level_windows, main, pump_events, run, wait_until, TestCase};
+pub use input::InputSimulator;
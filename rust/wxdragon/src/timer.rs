@@ -2,9 +2,17 @@
 //!
 //! This module provides a safe wrapper around wxWidgets' wxTimer class.
 //! Timers are used to generate events at regular intervals.
+//!
+//! For scheduling work without a window to own the timer, use [`call_later`] and
+//! [`call_every`]. [`Debouncer`] and [`Throttler`] limit how often a callback runs
+//! when it is triggered in quick succession, e.g. by text changes.
 
-use crate::event::{Event, EventType, WxEvtHandler};
+use crate::event::{Event, EventBinding, EventType, WxEvtHandler};
+use std::cell::{Cell, RefCell};
+use std::ffi::c_void;
 use std::marker::PhantomData;
+use std::rc::{Rc, Weak};
+use std::time::Duration;
 use wxdragon_sys as ffi;
 
 /// Represents a timer that triggers events at specified intervals.
@@ -62,18 +70,20 @@ impl<T: WxEvtHandler> Timer<T> {
     /// Bind an event handler for timer events.
     ///
     /// This method registers the callback to be called when the timer fires.
-    pub fn on_tick<F>(&self, callback: F)
+    pub fn on_tick<F>(&self, callback: F) -> EventBinding
     where
         F: FnMut(Event) + 'static,
     {
         // We need to make sure the owner exists when using its pointer
-        if !self.owner_ptr.is_null() {
-            // Create a WxEvtHandler wrapper from the bare pointer
-            let handler = TimerOwnerWrapper(self.owner_ptr);
-
-            // Use bind_internal from the WxEvtHandler trait via the wrapper
-            handler.bind_internal(EventType::TIMER, callback);
+        if self.owner_ptr.is_null() {
+            return EventBinding::invalid();
         }
+
+        // Create a WxEvtHandler wrapper from the bare pointer
+        let handler = TimerOwnerWrapper(self.owner_ptr);
+
+        // Use bind_internal from the WxEvtHandler trait via the wrapper
+        handler.bind_internal(EventType::TIMER, callback)
     }
 
     /// Start the timer.
@@ -145,3 +155,316 @@ impl WxEvtHandler for TimerOwnerWrapper {
         self.0
     }
 }
+
+// --- Owner-less callback timers ---
+
+/// Converts a duration to a timer interval, rounding zero up to the shortest interval.
+fn interval_ms(duration: Duration) -> i32 {
+    duration.as_millis().clamp(1, i32::MAX as u128) as i32
+}
+
+/// A wxTimer calling a Rust closure. The C++ timer holds a strong reference to
+/// this state until [`release`](Self::release).
+struct CallbackTimer {
+    ptr: Cell<*mut ffi::wxd_Timer_t>,
+    callback: RefCell<Option<Box<dyn FnMut()>>>,
+}
+
+impl CallbackTimer {
+    fn new<F>(make_callback: F) -> Rc<Self>
+    where
+        F: FnOnce(Weak<Self>) -> Box<dyn FnMut()>,
+    {
+        let timer = Rc::new_cyclic(|weak| Self {
+            ptr: Cell::new(std::ptr::null_mut()),
+            callback: RefCell::new(Some(make_callback(weak.clone()))),
+        });
+        let user_data = Rc::into_raw(timer.clone()) as *mut c_void;
+        let ptr = unsafe {
+            ffi::wxd_Timer_CreateWithCallback(Some(callback_timer_trampoline), user_data)
+        };
+        if ptr.is_null() {
+            drop(unsafe { Rc::from_raw(user_data as *const Self) });
+        }
+        timer.ptr.set(ptr);
+        timer
+    }
+
+    fn start(&self, interval: Duration, one_shot: bool) -> bool {
+        let ptr = self.ptr.get();
+        !ptr.is_null() && unsafe { ffi::wxd_Timer_Start(ptr, interval_ms(interval), one_shot) }
+    }
+
+    fn stop(&self) {
+        let ptr = self.ptr.get();
+        if !ptr.is_null() {
+            unsafe { ffi::wxd_Timer_Stop(ptr) };
+        }
+    }
+
+    fn is_running(&self) -> bool {
+        let ptr = self.ptr.get();
+        !ptr.is_null() && unsafe { ffi::wxd_Timer_IsRunning(ptr) }
+    }
+
+    fn is_released(&self) -> bool {
+        self.ptr.get().is_null()
+    }
+
+    /// Destroys the C++ timer and drops the closure, breaking any reference cycle
+    /// through it. Safe to call from within the closure.
+    fn release(self: &Rc<Self>) {
+        let ptr = self.ptr.replace(std::ptr::null_mut());
+        if ptr.is_null() {
+            return;
+        }
+        unsafe {
+            ffi::wxd_Timer_Destroy(ptr);
+            // The caller still holds a reference, so this never frees the state
+            Rc::decrement_strong_count(Rc::as_ptr(self));
+        }
+        // While the closure is running, the trampoline drops it once it returns
+        let callback = match self.callback.try_borrow_mut() {
+            Ok(mut callback) => callback.take(),
+            Err(_) => None,
+        };
+        drop(callback);
+    }
+}
+
+unsafe extern "C" fn callback_timer_trampoline(user_data: *mut c_void) {
+    if user_data.is_null() {
+        return;
+    }
+    // Hold our own reference so the state outlives a release from inside the closure
+    let raw = user_data as *const CallbackTimer;
+    Rc::increment_strong_count(raw);
+    let timer = Rc::from_raw(raw);
+
+    if let Ok(mut callback) = timer.callback.try_borrow_mut() {
+        if let Some(callback) = callback.as_mut() {
+            crate::panic_policy::catch("timer callback", callback);
+        }
+    }
+    if timer.is_released() {
+        let callback = timer.callback.borrow_mut().take();
+        drop(callback);
+    }
+}
+
+/// Handle to a timer started with [`call_later`] or [`call_every`].
+///
+/// Dropping the handle does not cancel the timer; call [`cancel`](Self::cancel).
+#[derive(Clone)]
+pub struct TimerHandle {
+    timer: Rc<CallbackTimer>,
+}
+
+impl TimerHandle {
+    /// Stops the timer. The callback will not run again and is dropped.
+    pub fn cancel(&self) {
+        self.timer.release();
+    }
+
+    /// Returns `true` until the timer has been cancelled or, for [`call_later`],
+    /// has fired.
+    pub fn is_active(&self) -> bool {
+        !self.timer.is_released()
+    }
+}
+
+/// Runs `callback` once on the main thread after `delay`.
+///
+/// Must be called on the main thread; from other threads use
+/// [`call_after`](crate::call_after).
+///
+/// ```rust,no_run
+/// use std::time::Duration;
+/// use wxdragon::prelude::*;
+///
+/// # fn hide_later(status: StaticText) {
+/// call_later(Duration::from_secs(3), move || status.set_label(""));
+/// # }
+/// ```
+pub fn call_later<F>(delay: Duration, callback: F) -> TimerHandle
+where
+    F: FnOnce() + 'static,
+{
+    let mut callback = Some(callback);
+    let timer = CallbackTimer::new(|weak| {
+        Box::new(move || {
+            if let Some(timer) = weak.upgrade() {
+                timer.release();
+            }
+            if let Some(callback) = callback.take() {
+                callback();
+            }
+        })
+    });
+    timer.start(delay, true);
+    TimerHandle { timer }
+}
+
+/// Runs `callback` on the main thread every `interval` until the returned handle
+/// is cancelled.
+///
+/// Must be called on the main thread.
+pub fn call_every<F>(interval: Duration, callback: F) -> TimerHandle
+where
+    F: FnMut() + 'static,
+{
+    let timer = CallbackTimer::new(|_| Box::new(callback));
+    timer.start(interval, false);
+    TimerHandle { timer }
+}
+
+/// Delays a callback until triggering has stopped for a quiet period.
+///
+/// Each [`call`](Self::call) restarts the delay; the callback runs once with the last
+/// value passed. Pending calls are discarded when the debouncer is dropped.
+///
+/// ```rust,no_run
+/// use std::time::Duration;
+/// use wxdragon::prelude::*;
+///
+/// # fn wire(search: TextCtrl) {
+/// let debouncer = Debouncer::new(Duration::from_millis(300), |query: String| {
+///     println!("Searching for {query}");
+/// });
+/// let input = search.clone();
+/// search.on_text_updated(move |_| debouncer.call(input.get_value()));
+/// # }
+/// ```
+pub struct Debouncer<T: 'static> {
+    timer: Rc<CallbackTimer>,
+    pending: Rc<RefCell<Option<T>>>,
+    delay: Duration,
+}
+
+impl<T: 'static> Debouncer<T> {
+    /// Creates a debouncer that runs `callback` once `delay` has passed without
+    /// another call.
+    pub fn new<F>(delay: Duration, mut callback: F) -> Self
+    where
+        F: FnMut(T) + 'static,
+    {
+        let pending = Rc::new(RefCell::new(None));
+        let pending_value = pending.clone();
+        let timer = CallbackTimer::new(|_| {
+            Box::new(move || {
+                let value = pending_value.borrow_mut().take();
+                if let Some(value) = value {
+                    callback(value);
+                }
+            })
+        });
+        Self {
+            timer,
+            pending,
+            delay,
+        }
+    }
+
+    /// Records `value` and restarts the delay.
+    pub fn call(&self, value: T) {
+        *self.pending.borrow_mut() = Some(value);
+        self.timer.start(self.delay, true);
+    }
+
+    /// Discards the pending call, if any.
+    pub fn cancel(&self) {
+        self.timer.stop();
+        let value = self.pending.borrow_mut().take();
+        drop(value);
+    }
+
+    /// Returns `true` if a call is waiting for the delay to pass.
+    pub fn is_pending(&self) -> bool {
+        self.pending.borrow().is_some()
+    }
+}
+
+impl<T: 'static> Drop for Debouncer<T> {
+    fn drop(&mut self) {
+        self.timer.release();
+    }
+}
+
+/// Limits a callback to running at most once per interval.
+///
+/// The first [`call`](Self::call) runs immediately. Calls made during the following
+/// interval are collapsed into one that runs, with the last value, when the interval
+/// ends. Pending calls are discarded when the throttler is dropped.
+pub struct Throttler<T: 'static> {
+    timer: Rc<CallbackTimer>,
+    state: Rc<ThrottleState<T>>,
+    interval: Duration,
+}
+
+struct ThrottleState<T> {
+    callback: RefCell<Box<dyn FnMut(T)>>,
+    pending: RefCell<Option<T>>,
+}
+
+impl<T> ThrottleState<T> {
+    fn invoke(&self, value: T) {
+        // A call made from inside the callback itself is dropped
+        if let Ok(mut callback) = self.callback.try_borrow_mut() {
+            callback(value);
+        }
+    }
+}
+
+impl<T: 'static> Throttler<T> {
+    /// Creates a throttler that runs `callback` at most once per `interval`.
+    pub fn new<F>(interval: Duration, callback: F) -> Self
+    where
+        F: FnMut(T) + 'static,
+    {
+        let state = Rc::new(ThrottleState {
+            callback: RefCell::new(Box::new(callback) as Box<dyn FnMut(T)>),
+            pending: RefCell::new(None),
+        });
+        let timer_state = state.clone();
+        let timer = CallbackTimer::new(|weak: Weak<CallbackTimer>| {
+            Box::new(move || {
+                let value = timer_state.pending.borrow_mut().take();
+                if let Some(value) = value {
+                    // Start a new interval for the trailing call
+                    if let Some(timer) = weak.upgrade() {
+                        timer.start(interval, true);
+                    }
+                    timer_state.invoke(value);
+                }
+            })
+        });
+        Self {
+            timer,
+            state,
+            interval,
+        }
+    }
+
+    /// Runs the callback now, or with the last value once the current interval ends.
+    pub fn call(&self, value: T) {
+        if self.timer.is_running() {
+            *self.state.pending.borrow_mut() = Some(value);
+        } else {
+            self.timer.start(self.interval, true);
+            self.state.invoke(value);
+        }
+    }
+
+    /// Discards the pending call, if any, and ends the current interval.
+    pub fn cancel(&self) {
+        self.timer.stop();
+        let value = self.state.pending.borrow_mut().take();
+        drop(value);
+    }
+}
+
+impl<T: 'static> Drop for Throttler<T> {
+    fn drop(&mut self) {
+        self.timer.release();
+    }
+}
@@ -0,0 +1,401 @@
+//! Typed channels delivering values from worker threads to the UI thread.
+//!
+//! [`ui_channel`] creates a channel whose handler runs on the UI thread. Unlike
+//! [`call_after`](crate::call_after), the channel is bounded: a worker that produces
+//! faster than the UI can keep up is blocked by [`Sender::send`] instead of letting
+//! the backlog grow. In coalescing mode only the latest value is kept, which suits
+//! progress and status updates where intermediate values are worthless.
+//!
+//! ```rust,no_run
+//! use wxdragon::prelude::*;
+//!
+//! # fn start(gauge: Gauge) {
+//! let progress = ui_channel::<i32>()
+//!     .coalescing()
+//!     .build(move |percent| gauge.set_value(percent));
+//!
+//! std::thread::spawn(move || {
+//!     for percent in 0..=100 {
+//!         // ... work ...
+//!         if progress.send(percent).is_err() {
+//!             break; // the UI has gone away
+//!         }
+//!     }
+//! });
+//! # }
+//! ```
+//!
+//! Handlers run within the main thread's time budget (see
+//! [`set_main_thread_time_budget`](crate::app::set_main_thread_time_budget)); values
+//! left over when it runs out are delivered in the next event loop iteration.
+
+use std::any::Any;
+use std::cell::{Cell, RefCell};
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
+use std::rc::Rc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Condvar, Mutex, MutexGuard};
+use std::thread::ThreadId;
+use std::time::Instant;
+
+/// Capacity used when none is given to the builder.
+const DEFAULT_CAPACITY: usize = 1024;
+
+static NEXT_CHANNEL_ID: AtomicU64 = AtomicU64::new(1);
+
+thread_local! {
+    // Receivers living on this (the UI) thread, keyed by channel id
+    static RECEIVERS: RefCell<HashMap<u64, Box<dyn Any>>> = RefCell::new(HashMap::new());
+}
+
+// --- Errors ---
+
+/// Error returned by [`Sender::send`] when the receiving side has gone away.
+/// Contains the value that could not be sent.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct SendError<T>(pub T);
+
+impl<T> fmt::Debug for SendError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "SendError(..)")
+    }
+}
+
+impl<T> fmt::Display for SendError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "sending on a closed UI channel")
+    }
+}
+
+impl<T> std::error::Error for SendError<T> {}
+
+/// Error returned by [`Sender::try_send`]. Contains the value that could not be sent.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum TrySendError<T> {
+    /// The channel is at capacity.
+    Full(T),
+    /// The receiving side has gone away.
+    Closed(T),
+}
+
+impl<T> TrySendError<T> {
+    /// Returns the value that could not be sent.
+    pub fn into_inner(self) -> T {
+        match self {
+            TrySendError::Full(value) | TrySendError::Closed(value) => value,
+        }
+    }
+}
+
+impl<T> fmt::Debug for TrySendError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TrySendError::Full(_) => write!(f, "Full(..)"),
+            TrySendError::Closed(_) => write!(f, "Closed(..)"),
+        }
+    }
+}
+
+impl<T> fmt::Display for TrySendError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TrySendError::Full(_) => write!(f, "UI channel is full"),
+            TrySendError::Closed(_) => write!(f, "sending on a closed UI channel"),
+        }
+    }
+}
+
+impl<T> std::error::Error for TrySendError<T> {}
+
+// --- Shared state ---
+
+struct State<T> {
+    items: VecDeque<T>,
+    senders: usize,
+    receiver_alive: bool,
+    // A drain is queued on the UI thread
+    scheduled: bool,
+}
+
+struct Shared<T> {
+    id: u64,
+    capacity: usize,
+    coalescing: bool,
+    ui_thread: ThreadId,
+    state: Mutex<State<T>>,
+    not_full: Condvar,
+}
+
+impl<T> Shared<T> {
+    fn lock(&self) -> MutexGuard<'_, State<T>> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// Queues a drain of `shared` on the UI thread unless one is already queued.
+fn schedule<T: Send + 'static>(shared: &Arc<Shared<T>>, state: &mut State<T>) {
+    if !state.scheduled {
+        state.scheduled = true;
+        let shared = shared.clone();
+        crate::app::call_after(Box::new(move || drain(shared)));
+    }
+}
+
+// --- Builder ---
+
+/// Creates a builder for a channel delivering `T` values to the UI thread.
+///
+/// [`build`](UiChannelBuilder::build) must be called on the UI thread.
+pub fn ui_channel<T: Send + 'static>() -> UiChannelBuilder<T> {
+    UiChannelBuilder {
+        capacity: DEFAULT_CAPACITY,
+        coalescing: false,
+        _marker: std::marker::PhantomData,
+    }
+}
+
+/// Builder for a UI channel; see [`ui_channel`].
+pub struct UiChannelBuilder<T> {
+    capacity: usize,
+    coalescing: bool,
+    _marker: std::marker::PhantomData<fn(T)>,
+}
+
+impl<T: Send + 'static> UiChannelBuilder<T> {
+    /// Sets how many undelivered values may be queued before senders are blocked.
+    /// The default is 1024.
+    pub fn with_capacity(mut self, capacity: usize) -> Self {
+        self.capacity = capacity.max(1);
+        self
+    }
+
+    /// Keeps only the latest undelivered value: sending replaces whatever is queued
+    /// and never blocks.
+    pub fn coalescing(mut self) -> Self {
+        self.coalescing = true;
+        self
+    }
+
+    /// Registers `handler` to receive the values on the UI thread and returns the
+    /// sending side.
+    ///
+    /// The handler is dropped once every [`Sender`] has been dropped and the queued
+    /// values have been delivered.
+    pub fn build<F>(self, handler: F) -> Sender<T>
+    where
+        F: FnMut(T) + 'static,
+    {
+        let shared = Arc::new(Shared {
+            id: NEXT_CHANNEL_ID.fetch_add(1, Ordering::Relaxed),
+            capacity: if self.coalescing { 1 } else { self.capacity },
+            coalescing: self.coalescing,
+            ui_thread: std::thread::current().id(),
+            state: Mutex::new(State {
+                items: VecDeque::new(),
+                senders: 1,
+                receiver_alive: true,
+                scheduled: false,
+            }),
+            not_full: Condvar::new(),
+        });
+        let receiver: Rc<Receiver<T>> = Rc::new(Receiver {
+            shared: shared.clone(),
+            handler: RefCell::new(Box::new(handler)),
+            deferred: Cell::new(false),
+        });
+        RECEIVERS.with(|receivers| {
+            receivers
+                .borrow_mut()
+                .insert(shared.id, Box::new(receiver) as Box<dyn Any>)
+        });
+        Sender { shared }
+    }
+}
+
+// --- Sender ---
+
+/// The sending side of a [`ui_channel`]. Can be cloned and sent to other threads.
+pub struct Sender<T: Send + 'static> {
+    shared: Arc<Shared<T>>,
+}
+
+impl<T: Send + 'static> Sender<T> {
+    /// Sends `value` to the UI thread, blocking while the channel is full.
+    ///
+    /// Called on the UI thread itself, this never blocks: the value is queued even if
+    /// the channel is over capacity, since blocking would stop the values from being
+    /// delivered. Fails only if the receiving side has gone away.
+    pub fn send(&self, value: T) -> Result<(), SendError<T>> {
+        let on_ui_thread = std::thread::current().id() == self.shared.ui_thread;
+        let mut state = self.shared.lock();
+        loop {
+            if !state.receiver_alive {
+                return Err(SendError(value));
+            }
+            if self.shared.coalescing || on_ui_thread || state.items.len() < self.shared.capacity {
+                break;
+            }
+            state = self
+                .shared
+                .not_full
+                .wait(state)
+                .unwrap_or_else(|e| e.into_inner());
+        }
+        self.push(&mut state, value);
+        Ok(())
+    }
+
+    /// Sends `value` to the UI thread if there is room, without blocking.
+    pub fn try_send(&self, value: T) -> Result<(), TrySendError<T>> {
+        let mut state = self.shared.lock();
+        if !state.receiver_alive {
+            return Err(TrySendError::Closed(value));
+        }
+        if !self.shared.coalescing && state.items.len() >= self.shared.capacity {
+            return Err(TrySendError::Full(value));
+        }
+        self.push(&mut state, value);
+        Ok(())
+    }
+
+    /// Returns `true` if the receiving side has gone away, e.g. because the
+    /// application has exited.
+    pub fn is_closed(&self) -> bool {
+        !self.shared.lock().receiver_alive
+    }
+
+    /// Returns the number of values waiting to be delivered.
+    pub fn len(&self) -> usize {
+        self.shared.lock().items.len()
+    }
+
+    /// Returns `true` if no values are waiting to be delivered.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn push(&self, state: &mut State<T>, value: T) {
+        if self.shared.coalescing {
+            state.items.clear();
+        }
+        state.items.push_back(value);
+        schedule(&self.shared, state);
+    }
+}
+
+impl<T: Send + 'static> Clone for Sender<T> {
+    fn clone(&self) -> Self {
+        self.shared.lock().senders += 1;
+        Self {
+            shared: self.shared.clone(),
+        }
+    }
+}
+
+impl<T: Send + 'static> Drop for Sender<T> {
+    fn drop(&mut self) {
+        let mut state = self.shared.lock();
+        state.senders -= 1;
+        if state.senders == 0 && state.receiver_alive {
+            // Let the UI thread deliver what is left and drop the handler
+            schedule(&self.shared, &mut state);
+        }
+    }
+}
+
+// --- Receiver ---
+
+/// The UI-thread side of a channel, registered in `RECEIVERS`.
+struct Receiver<T> {
+    shared: Arc<Shared<T>>,
+    handler: RefCell<Box<dyn FnMut(T)>>,
+    // Set when a drain ran while the handler was busy, e.g. in a nested event loop;
+    // the drain that called the handler continues once it returns
+    deferred: Cell<bool>,
+}
+
+impl<T> Drop for Receiver<T> {
+    fn drop(&mut self) {
+        let items = {
+            let mut state = self.shared.lock();
+            state.receiver_alive = false;
+            std::mem::take(&mut state.items)
+        };
+        // Wake senders blocked on a full channel so they see it closed
+        self.shared.not_full.notify_all();
+        drop(items);
+    }
+}
+
+fn receiver<T: 'static>(id: u64) -> Option<Rc<Receiver<T>>> {
+    RECEIVERS.with(|receivers| {
+        receivers
+            .borrow()
+            .get(&id)
+            .and_then(|receiver| receiver.downcast_ref::<Rc<Receiver<T>>>())
+            .cloned()
+    })
+}
+
+/// Delivers queued values to the handler until the queue is empty or the main
+/// thread's time budget is used up.
+fn drain<T: Send + 'static>(shared: Arc<Shared<T>>) {
+    let Some(receiver) = receiver::<T>(shared.id) else {
+        shared.lock().scheduled = false;
+        return;
+    };
+    if receiver.deferred.get() {
+        return;
+    }
+    let deadline = crate::app::main_thread_deadline();
+
+    loop {
+        let value = {
+            let mut state = shared.lock();
+            match state.items.pop_front() {
+                Some(value) => value,
+                None => {
+                    state.scheduled = false;
+                    let closed = state.senders == 0;
+                    drop(state);
+                    if closed {
+                        let removed =
+                            RECEIVERS.with(|receivers| receivers.borrow_mut().remove(&shared.id));
+                        drop(removed);
+                    }
+                    return;
+                }
+            }
+        };
+        shared.not_full.notify_one();
+
+        match receiver.handler.try_borrow_mut() {
+            Ok(mut handler) => {
+                crate::panic_policy::catch("ui_channel handler", || handler(value));
+                // Values a nested drain left behind are delivered by this loop, or
+                // rescheduled below
+                receiver.deferred.set(false);
+            }
+            Err(_) => {
+                // The handler is on the stack. Rescheduling now would spin its nested
+                // event loop, so leave the value and the `scheduled` flag to the drain
+                // that called it.
+                shared.lock().items.push_front(value);
+                receiver.deferred.set(true);
+                return;
+            }
+        }
+
+        if Instant::now() >= deadline {
+            break;
+        }
+    }
+
+    // Values are left: continue behind the other queued callbacks
+    let mut state = shared.lock();
+    state.scheduled = false;
+    if !state.items.is_empty() || state.senders == 0 {
+        schedule(&shared, &mut state);
+    }
+}
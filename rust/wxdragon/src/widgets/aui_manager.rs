@@ -1,4 +1,4 @@
-use crate::event::{Event, EventType, WxEvtHandler};
+use crate::event::{Event, EventBinding, EventType, WxEvtHandler};
 use crate::prelude::*;
 use crate::window::Window;
 use std::ffi::CString;
@@ -437,6 +437,16 @@ impl AuiManager {
     }
 }
 
+impl crate::persistence::Persistent for AuiManager {
+    fn register_persistent(&self, name: &std::ffi::CStr) -> bool {
+        unsafe { ffi::wxd_AuiManager_RegisterPersistent(self.ptr, name.as_ptr()) }
+    }
+
+    fn persistent_key(&self) -> *mut std::ffi::c_void {
+        self.ptr as *mut std::ffi::c_void
+    }
+}
+
 impl Drop for AuiManager {
     fn drop(&mut self) {
         // We need to have special handling for AuiManager
@@ -504,61 +514,61 @@ impl AuiManagerEventData {
 // Implement event handling for AuiManager
 impl AuiManager {
     /// Bind a handler for the pane button event
-    pub fn on_pane_button<F>(&self, callback: F)
+    pub fn on_pane_button<F>(&self, callback: F) -> EventBinding
     where
         F: FnMut(AuiManagerEventData) + 'static,
     {
-        self.bind_aui_event(EventType::AUI_PANE_BUTTON, callback);
+        self.bind_aui_event(EventType::AUI_PANE_BUTTON, callback)
     }
 
     /// Bind a handler for the pane close event
-    pub fn on_pane_close<F>(&self, callback: F)
+    pub fn on_pane_close<F>(&self, callback: F) -> EventBinding
     where
         F: FnMut(AuiManagerEventData) + 'static,
     {
-        self.bind_aui_event(EventType::AUI_PANE_CLOSE, callback);
+        self.bind_aui_event(EventType::AUI_PANE_CLOSE, callback)
     }
 
     /// Bind a handler for the pane maximize event
-    pub fn on_pane_maximize<F>(&self, callback: F)
+    pub fn on_pane_maximize<F>(&self, callback: F) -> EventBinding
     where
         F: FnMut(AuiManagerEventData) + 'static,
     {
-        self.bind_aui_event(EventType::AUI_PANE_MAXIMIZE, callback);
+        self.bind_aui_event(EventType::AUI_PANE_MAXIMIZE, callback)
     }
 
     /// Bind a handler for the pane restore event
-    pub fn on_pane_restore<F>(&self, callback: F)
+    pub fn on_pane_restore<F>(&self, callback: F) -> EventBinding
     where
         F: FnMut(AuiManagerEventData) + 'static,
     {
-        self.bind_aui_event(EventType::AUI_PANE_RESTORE, callback);
+        self.bind_aui_event(EventType::AUI_PANE_RESTORE, callback)
     }
 
     /// Bind a handler for the pane activated event
-    pub fn on_pane_activated<F>(&self, callback: F)
+    pub fn on_pane_activated<F>(&self, callback: F) -> EventBinding
     where
         F: FnMut(AuiManagerEventData) + 'static,
     {
-        self.bind_aui_event(EventType::AUI_PANE_ACTIVATED, callback);
+        self.bind_aui_event(EventType::AUI_PANE_ACTIVATED, callback)
     }
 
     /// Bind a handler for the render event
-    pub fn on_render<F>(&self, callback: F)
+    pub fn on_render<F>(&self, callback: F) -> EventBinding
     where
         F: FnMut(AuiManagerEventData) + 'static,
     {
-        self.bind_aui_event(EventType::AUI_RENDER, callback);
+        self.bind_aui_event(EventType::AUI_RENDER, callback)
     }
 
     // Internal helper to bind AUI events
-    fn bind_aui_event<F>(&self, event_type: EventType, mut callback: F)
+    fn bind_aui_event<F>(&self, event_type: EventType, mut callback: F) -> EventBinding
     where
         F: FnMut(AuiManagerEventData) + 'static,
     {
         self.bind_internal(event_type, move |event| {
             let data = AuiManagerEventData::new(event);
             callback(data);
-        });
+        })
     }
 }
@@ -4,8 +4,8 @@
 //! DataViewCtrl, DataViewListCtrl, and DataViewTreeCtrl.
 
 use super::item::DataViewItem;
-use crate::event::Event;
 use crate::event::WxEvtHandler;
+use crate::event::{Event, EventBinding};
 use wxdragon_sys as ffi;
 
 /// Events emitted by DataView widgets
@@ -169,7 +169,7 @@ impl DataViewEventData {
 /// Trait for DataView event handling
 pub trait DataViewEventHandler: WxEvtHandler {
     /// Bind an event handler for DataView events
-    fn bind_dataview_event<F>(&self, event: DataViewEvent, mut callback: F)
+    fn bind_dataview_event<F>(&self, event: DataViewEvent, mut callback: F) -> EventBinding
     where
         F: FnMut(DataViewEventData) + 'static,
     {
@@ -220,11 +220,11 @@ pub trait DataViewEventHandler: WxEvtHandler {
         };
 
         // Use internal bind method
-        WxEvtHandler::bind_internal(self, event_type, wrapper);
+        WxEvtHandler::bind_internal(self, event_type, wrapper)
     }
 
     /// Binds a handler to the selection changed event
-    fn on_selection_changed<F>(&self, callback: F)
+    fn on_selection_changed<F>(&self, callback: F) -> EventBinding
     where
         F: FnMut(DataViewEventData) + 'static,
     {
@@ -232,7 +232,7 @@ pub trait DataViewEventHandler: WxEvtHandler {
     }
 
     /// Binds a handler to the item activated event
-    fn on_item_activated<F>(&self, callback: F)
+    fn on_item_activated<F>(&self, callback: F) -> EventBinding
     where
         F: FnMut(DataViewEventData) + 'static,
     {
@@ -240,7 +240,7 @@ pub trait DataViewEventHandler: WxEvtHandler {
     }
 
     /// Binds a handler to the item editing started event
-    fn on_item_editing_started<F>(&self, callback: F)
+    fn on_item_editing_started<F>(&self, callback: F) -> EventBinding
     where
         F: FnMut(DataViewEventData) + 'static,
     {
@@ -248,7 +248,7 @@ pub trait DataViewEventHandler: WxEvtHandler {
     }
 
     /// Binds a handler to the item editing done event
-    fn on_item_editing_done<F>(&self, callback: F)
+    fn on_item_editing_done<F>(&self, callback: F) -> EventBinding
     where
         F: FnMut(DataViewEventData) + 'static,
     {
@@ -256,7 +256,7 @@ pub trait DataViewEventHandler: WxEvtHandler {
     }
 
     /// Binds a handler to the item editing cancelled event
-    fn on_item_editing_cancelled<F>(&self, callback: F)
+    fn on_item_editing_cancelled<F>(&self, callback: F) -> EventBinding
     where
         F: FnMut(DataViewEventData) + 'static,
     {
@@ -264,7 +264,7 @@ pub trait DataViewEventHandler: WxEvtHandler {
     }
 
     /// Binds a handler to the column header click event
-    fn on_column_header_click<F>(&self, callback: F)
+    fn on_column_header_click<F>(&self, callback: F) -> EventBinding
     where
         F: FnMut(DataViewEventData) + 'static,
     {
@@ -272,7 +272,7 @@ pub trait DataViewEventHandler: WxEvtHandler {
     }
 
     /// Binds a handler to the column header right click event
-    fn on_column_header_right_click<F>(&self, callback: F)
+    fn on_column_header_right_click<F>(&self, callback: F) -> EventBinding
     where
         F: FnMut(DataViewEventData) + 'static,
     {
@@ -280,7 +280,7 @@ pub trait DataViewEventHandler: WxEvtHandler {
     }
 
     /// Binds a handler to the column sorted event
-    fn on_column_sorted<F>(&self, callback: F)
+    fn on_column_sorted<F>(&self, callback: F) -> EventBinding
     where
         F: FnMut(DataViewEventData) + 'static,
     {
@@ -288,7 +288,7 @@ pub trait DataViewEventHandler: WxEvtHandler {
     }
 
     /// Binds a handler to the column reordered event
-    fn on_column_reordered<F>(&self, callback: F)
+    fn on_column_reordered<F>(&self, callback: F) -> EventBinding
     where
         F: FnMut(DataViewEventData) + 'static,
     {
@@ -299,7 +299,7 @@ pub trait DataViewEventHandler: WxEvtHandler {
 /// Extension trait for TreeView-specific events
 pub trait TreeViewEventHandler: DataViewEventHandler {
     /// Binds a handler to the item expanded event
-    fn on_item_expanded<F>(&self, callback: F)
+    fn on_item_expanded<F>(&self, callback: F) -> EventBinding
     where
         F: FnMut(DataViewEventData) + 'static,
     {
@@ -307,7 +307,7 @@ pub trait TreeViewEventHandler: DataViewEventHandler {
     }
 
     /// Binds a handler to the item collapsed event
-    fn on_item_collapsed<F>(&self, callback: F)
+    fn on_item_collapsed<F>(&self, callback: F) -> EventBinding
     where
         F: FnMut(DataViewEventData) + 'static,
     {
@@ -315,7 +315,7 @@ pub trait TreeViewEventHandler: DataViewEventHandler {
     }
 
     /// Binds a handler to the item expanding event
-    fn on_item_expanding<F>(&self, callback: F)
+    fn on_item_expanding<F>(&self, callback: F) -> EventBinding
     where
         F: FnMut(DataViewEventData) + 'static,
     {
@@ -323,7 +323,7 @@ pub trait TreeViewEventHandler: DataViewEventHandler {
     }
 
     /// Binds a handler to the item collapsing event
-    fn on_item_collapsing<F>(&self, callback: F)
+    fn on_item_collapsing<F>(&self, callback: F) -> EventBinding
     where
         F: FnMut(DataViewEventData) + 'static,
     {
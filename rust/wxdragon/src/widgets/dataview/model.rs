@@ -471,8 +471,16 @@ impl CustomDataViewVirtualListModel {
                 // Safety: This cast should be valid if the userdata was properly created
                 let callbacks = unsafe { &*(userdata as *const CustomModelCallbacks) };
 
-                // Call the user's callback
-                let value = (callbacks.get_value)(&*callbacks.userdata, row as usize, col as usize);
+                // Call the user's callback; a panic leaves an empty string
+                let Some(value) = crate::panic_policy::catch("dataview model", || {
+                    (callbacks.get_value)(&*callbacks.userdata, row as usize, col as usize)
+                }) else {
+                    unsafe {
+                        (*variant).type_ = ffi::WXD_VARIANT_TYPE_STRING as i32;
+                        (*variant).data.string_val = std::ptr::null_mut();
+                    }
+                    return;
+                };
 
                 // Convert Variant to wxd_Variant_t
                 let raw_variant = to_raw_variant(&value);
@@ -495,7 +503,10 @@ impl CustomDataViewVirtualListModel {
                     let value = unsafe { from_raw_variant(variant) };
 
                     // Call the user's callback
-                    (set_value)(&*callbacks.userdata, row as usize, col as usize, &value)
+                    crate::panic_policy::catch("dataview model", || {
+                        (set_value)(&*callbacks.userdata, row as usize, col as usize, &value)
+                    })
+                    .unwrap_or(false)
                 } else {
                     false
                 }
@@ -509,9 +520,9 @@ impl CustomDataViewVirtualListModel {
             ) -> bool {
                 let callbacks = unsafe { &*(userdata as *const CustomModelCallbacks) };
                 if let Some(get_attr) = &callbacks.get_attr {
-                    if let Some(attrs) =
+                    if let Some(Some(attrs)) = crate::panic_policy::catch("dataview model", || {
                         (get_attr)(&*callbacks.userdata, row as usize, col as usize)
-                    {
+                    }) {
                         // Copy the attributes to the provided struct
                         unsafe {
                             *attr = attrs.to_raw();
@@ -532,7 +543,10 @@ impl CustomDataViewVirtualListModel {
             ) -> bool {
                 let callbacks = unsafe { &*(userdata as *const CustomModelCallbacks) };
                 if let Some(is_enabled) = &callbacks.is_enabled {
-                    (is_enabled)(&*callbacks.userdata, row as usize, col as usize)
+                    crate::panic_policy::catch("dataview model", || {
+                        (is_enabled)(&*callbacks.userdata, row as usize, col as usize)
+                    })
+                    .unwrap_or(true)
                 } else {
                     true
                 }
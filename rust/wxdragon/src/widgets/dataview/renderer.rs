@@ -771,16 +771,16 @@ extern "C" fn get_size_trampoline(user_data: *mut std::ffi::c_void) -> ffi::wxd_
         // derived from the widget's font metrics in a future enhancement
         let default_size = crate::geometry::Size::new(80, 20);
 
-        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        let result = crate::panic_policy::catch("dataview renderer", || {
             callback(&current_value, default_size)
-        }));
+        });
 
         match result {
-            Ok(size) => ffi::wxd_Size_t {
+            Some(size) => ffi::wxd_Size_t {
                 width: size.width,
                 height: size.height,
             },
-            Err(_) => ffi::wxd_Size_t {
+            None => ffi::wxd_Size_t {
                 width: 50,
                 height: 20,
             },
@@ -811,9 +811,9 @@ extern "C" fn render_trampoline(
         // Get the current value and pass it directly to the callback
         let current_value = callbacks.current_value.borrow();
 
-        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        let result = crate::panic_policy::catch("dataview renderer", || {
             callback(rect, &context, state, &current_value)
-        }));
+        });
 
         result.unwrap_or(false)
     } else {
@@ -833,10 +833,10 @@ extern "C" fn set_value_trampoline(
     let variant = unsafe { super::model::from_raw_variant(value) };
 
     // Store the value internally in the renderer
-    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+    let result = crate::panic_policy::catch("dataview renderer", || {
         *callbacks.current_value.borrow_mut() = variant;
         true
-    }));
+    });
 
     result.unwrap_or(false)
 }
@@ -850,15 +850,15 @@ extern "C" fn get_value_trampoline(
     }
 
     let callbacks = unsafe { &*(user_data as *const CustomRendererCallbacks) };
-    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+    let result = crate::panic_policy::catch("dataview renderer", || {
         let current_value = callbacks.current_value.borrow();
         let raw = super::model::to_raw_variant(&current_value);
         unsafe {
             *value = raw;
         }
-    }));
+    });
 
-    if result.is_err() {
+    if result.is_none() {
         // Return empty string on panic
         unsafe {
             (*value).type_ = ffi::WXD_VARIANT_TYPE_STRING as i32;
@@ -874,7 +874,7 @@ extern "C" fn has_editor_trampoline(user_data: *mut std::ffi::c_void) -> bool {
 
     let callbacks = unsafe { &*(user_data as *const CustomRendererCallbacks) };
     if let Some(ref callback) = callbacks.has_editor {
-        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(callback));
+        let result = crate::panic_policy::catch("dataview renderer", callback);
         result.unwrap_or(false)
     } else {
         false
@@ -897,8 +897,7 @@ extern "C" fn activate_cell_trampoline(
     if let Some(ref callback) = callbacks.activate_cell {
         // Convert parameters properly
         let rect = crate::geometry::Rect::new(cell.x, cell.y, cell.width, cell.height);
-        let result =
-            std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| callback(rect, col as i32)));
+        let result = crate::panic_policy::catch("dataview renderer", || callback(rect, col as i32));
         result.unwrap_or(false)
     } else {
         false
@@ -940,12 +939,12 @@ extern "C" fn create_editor_trampoline(
 
         let parent_wrapper = ParentWrapper { ptr: parent };
 
-        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        let result = crate::panic_policy::catch("dataview renderer", || {
             callback(&parent_wrapper, rect, &variant)
-        }));
+        });
 
         match result {
-            Ok(Some(editor)) => editor.handle_ptr() as *mut std::ffi::c_void,
+            Some(Some(editor)) => editor.handle_ptr() as *mut std::ffi::c_void,
             _ => std::ptr::null_mut(),
         }
     } else {
@@ -977,11 +976,10 @@ extern "C" fn get_value_from_editor_trampoline(
 
         let editor_wrapper = EditorWrapper { ptr: editor };
 
-        let result =
-            std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| callback(&editor_wrapper)));
+        let result = crate::panic_policy::catch("dataview renderer", || callback(&editor_wrapper));
 
         match result {
-            Ok(Some(variant)) => {
+            Some(Some(variant)) => {
                 let raw = super::model::to_raw_variant(&variant);
                 unsafe {
                     *value = raw;
@@ -361,20 +361,24 @@ impl WindowEvents for Frame {}
 // Add event binding methods to Frame
 impl Frame {
     /// Bind a handler to window events using the underlying window
-    pub(crate) fn bind_window_event<F>(&self, event_type: crate::event::EventType, handler: F)
+    pub(crate) fn bind_window_event<F>(
+        &self,
+        event_type: crate::event::EventType,
+        handler: F,
+    ) -> crate::event::EventBinding
     where
         F: FnMut(crate::event::Event) + 'static,
     {
         // Use the bind_internal method provided by the WxEvtHandler trait
-        <Self as crate::event::WxEvtHandler>::bind_internal(self, event_type, handler);
+        <Self as crate::event::WxEvtHandler>::bind_internal(self, event_type, handler)
     }
 
     /// Bind a handler to menu events
-    pub fn on_menu<F>(&self, handler: F)
+    pub fn on_menu<F>(&self, handler: F) -> crate::event::EventBinding
     where
         F: FnMut(crate::event::Event) + 'static,
     {
-        self.bind_window_event(crate::event::EventType::MENU, handler);
+        self.bind_window_event(crate::event::EventType::MENU, handler)
     }
 }
 
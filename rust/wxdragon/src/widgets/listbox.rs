@@ -212,17 +212,9 @@ impl ListBox {
 
     /// Pops up a menu at the specified position.
     /// If `pos` is `None`, the menu is popped up at the current cursor position.
-    /// # Returns
-    /// `true` if the menu was popped up successfully, `false` otherwise.
+    #[deprecated(note = "use `WxWidget::popup_menu`, available on every window")]
     pub fn popup_menu(&self, menu: &Menu, pos: Option<Point>) -> bool {
-        let pos = pos.unwrap_or_else(|| Point::new(-1, -1));
-        unsafe {
-            ffi::wxd_ListBox_PopupMenu(
-                self.window.as_ptr() as *mut RawListBox,
-                menu.as_ptr(),
-                pos.into(),
-            )
-        }
+        WxWidget::popup_menu(self, menu, pos)
     }
 }
 
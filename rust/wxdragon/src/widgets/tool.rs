@@ -1,6 +1,6 @@
 //! Safe wrapper for individual toolbar tools loaded from XRC.
 
-use crate::event::{Event, EventType, WxEvtHandler};
+use crate::event::{Event, EventBinding, EventType, WxEvtHandler};
 use crate::id::Id;
 use crate::window::{Window, WxWidget};
 use wxdragon_sys as ffi;
@@ -98,13 +98,13 @@ impl Tool {
 
     /// Binds a click event handler to this tool.
     /// This is a convenience method that binds a tool event on the parent toolbar for this tool's ID.
-    pub fn on_click<F>(&self, handler: F)
+    pub fn on_click<F>(&self, handler: F) -> EventBinding
     where
         F: FnMut(Event) + 'static,
     {
         // Use ID-specific binding for TOOL events
         self.toolbar_window
-            .bind_with_id_internal(EventType::TOOL, self.tool_id, handler);
+            .bind_with_id_internal(EventType::TOOL, self.tool_id, handler)
     }
 
     /// Special XRC loading method for tools.
@@ -277,6 +277,27 @@ pub trait WxWidget {
         }
     }
 
+    /// Sets the direction the window lays out its children and text in, e.g.
+    /// [`LayoutDirection::RightToLeft`](crate::locale::LayoutDirection::RightToLeft)
+    /// for Arabic or Hebrew. Call before adding children.
+    fn set_layout_direction(&self, direction: crate::locale::LayoutDirection) {
+        let window_ptr = self.handle_ptr();
+        if !window_ptr.is_null() {
+            unsafe { ffi::wxd_Window_SetLayoutDirection(window_ptr, direction.to_raw()) };
+        }
+    }
+
+    /// Gets the direction the window lays out its children and text in.
+    fn get_layout_direction(&self) -> crate::locale::LayoutDirection {
+        let window_ptr = self.handle_ptr();
+        if window_ptr.is_null() {
+            return crate::locale::LayoutDirection::Default;
+        }
+        crate::locale::LayoutDirection::from_raw(unsafe {
+            ffi::wxd_Window_GetLayoutDirection(window_ptr)
+        })
+    }
+
     /// Sets the window's minimum size.
     fn set_min_size(&self, size: crate::geometry::Size) {
         let window_ptr = self.handle_ptr();
@@ -529,6 +550,17 @@ pub trait WxWidget {
         self.center()
     }
 
+    /// Sets the keyboard shortcuts handled by this window and its children,
+    /// replacing any previous ones. An empty slice removes them.
+    ///
+    /// Pressing a shortcut sends an `EventType::MENU` event with the accelerator's id.
+    fn set_accelerator_table(&self, accelerators: &[crate::accelerator::Accelerator]) {
+        let handle = self.handle_ptr();
+        if !handle.is_null() {
+            crate::accelerator::set_table(handle, accelerators);
+        }
+    }
+
     /// Converts client coordinates to screen coordinates.
     fn client_to_screen(&self, pt: Point) -> Point {
         let handle = self.handle_ptr();
@@ -557,6 +589,41 @@ pub trait WxWidget {
         }
     }
 
+    /// Shows `menu` as a popup menu at `pos` in client coordinates, or at the
+    /// mouse position if `None`, and waits until it is dismissed.
+    ///
+    /// A chosen item sends an `EventType::MENU` event to this window as usual.
+    /// Returns `false` if the menu could not be shown. The menu still belongs to
+    /// the caller: call [`Menu::destroy`](crate::menus::Menu::destroy) once it is
+    /// no longer needed.
+    fn popup_menu(&self, menu: &crate::menus::Menu, pos: Option<Point>) -> bool {
+        let handle = self.handle_ptr();
+        if handle.is_null() {
+            return false;
+        }
+        let pos = pos.unwrap_or(crate::geometry::DEFAULT_POSITION);
+        unsafe { ffi::wxd_Window_PopupMenu(handle, menu.as_ptr(), pos.into()) }
+    }
+
+    /// Shows `menu` like [`popup_menu`](Self::popup_menu) but returns the id of the
+    /// chosen item instead of sending a menu event, or `None` if the menu was
+    /// dismissed without a selection. The menu still belongs to the caller.
+    fn get_popup_menu_selection_from_user(
+        &self,
+        menu: &crate::menus::Menu,
+        pos: Option<Point>,
+    ) -> Option<crate::id::Id> {
+        let handle = self.handle_ptr();
+        if handle.is_null() {
+            return None;
+        }
+        let pos = pos.unwrap_or(crate::geometry::DEFAULT_POSITION);
+        let id = unsafe {
+            ffi::wxd_Window_GetPopupMenuSelectionFromUser(handle, menu.as_ptr(), pos.into())
+        };
+        (id != crate::id::ID_NONE).then_some(id)
+    }
+
     /// Gets the window label (title or text).
     /// Returns `None` if the label is not set, cannot be converted to UTF-8, or an error occurs.
     fn get_label(&self) -> Option<String> {
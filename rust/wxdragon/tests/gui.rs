@@ -0,0 +1,47 @@
+//! GUI tests driven through `wxdragon::testing`, running on the main thread as
+//! required on macOS. They need a display, so they are only built with the
+//! `gui-tests` feature; on headless Linux run them under `xvfb-run`.
+
+use std::cell::Cell;
+use std::rc::Rc;
+use std::time::Duration;
+use wxdragon::prelude::*;
+use wxdragon::testing::{self, InputSimulator, MouseButton};
+
+fn button_click_reaches_handler() {
+    let frame = Frame::builder().with_title("Click test").build();
+    let button = Button::builder(&frame).with_label("Press me").build();
+    frame.show(true);
+    testing::pump_events();
+
+    let clicks = Rc::new(Cell::new(0));
+    let counter = clicks.clone();
+    button.on_click(move |_| counter.set(counter.get() + 1));
+
+    let input = InputSimulator::new().expect("input simulation is not available");
+    assert!(input.click_on(&button, MouseButton::Left));
+    assert!(
+        testing::wait_until(Duration::from_secs(2), || clicks.get() > 0),
+        "the click handler did not run"
+    );
+    assert_eq!(clicks.get(), 1);
+}
+
+fn leaves_a_window_open() {
+    let frame = Frame::builder().with_title("Left open").build();
+    frame.show(true);
+    testing::pump_events();
+}
+
+fn starts_without_windows() {
+    // The window of the previous test was destroyed after it finished
+    assert_eq!(testing::destroy_top_level_windows(), 0);
+}
+
+fn main() {
+    testing::main(&[
+        ("button_click_reaches_handler", button_click_reaches_handler),
+        ("leaves_a_window_open", leaves_a_window_open),
+        ("starts_without_windows", starts_without_windows),
+    ]);
+}
@@ -0,0 +1,35 @@
+//! `#[test]` functions run on the harness's shared GUI thread with `gui_test!`.
+//! Not available on macOS, where the GUI must use the main thread (see `gui.rs`).
+//! They need a display, so they are only built with the `gui-tests` feature; on
+//! headless Linux run them under `xvfb-run`.
+#![cfg(not(target_os = "macos"))]
+
+use wxdragon::prelude::*;
+use wxdragon::testing;
+
+wxdragon::gui_test! {
+    fn frame_keeps_its_title() {
+        // Windows of other tests are destroyed after each of them
+        assert_eq!(testing::destroy_top_level_windows(), 0);
+
+        let frame = Frame::builder().with_title("Hello").build();
+        frame.show(true);
+        testing::pump_events();
+        assert_eq!(frame.get_title(), "Hello");
+    }
+
+    #[should_panic(expected = "failed on the GUI thread")]
+    fn panics_reach_the_test() {
+        Frame::builder().with_title("Left open").build().show(true);
+        panic!("failed on the GUI thread");
+    }
+}
+
+#[test]
+fn run_returns_the_test_result() {
+    let title = testing::run(|| {
+        let frame = Frame::builder().with_title("Result").build();
+        frame.get_title()
+    });
+    assert_eq!(title, "Result");
+}